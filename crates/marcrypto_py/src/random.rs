@@ -1,16 +1,92 @@
-use marnd::{MPCfg, MPRng, MRndErr};
-use pyo3::exceptions::PyValueError;
+use marnd::{AliasTable, DynMPRng, MPCfg, MPRng, MPRngState, MRndErr};
+use pyo3::exceptions::{PyImportError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PySequence;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PySequence, PyTuple};
 use std::collections::HashMap;
 
 fn mrnderr_to_py(e: MRndErr) -> PyErr {
     PyValueError::new_err(e.to_string())
 }
 
-#[pyclass]
+// the module path lets pickle find the class again
+#[pyclass(module = "marcrypto.random")]
 pub struct PyMPRng {
-    inner: MPRng,
+    inner: DynMPRng,
+}
+
+/// A prebuilt alias table, so repeated `choice` calls skip validation and the O(n) scan.
+#[pyclass(module = "marcrypto.random", name = "AliasTable")]
+pub struct PyAliasTable {
+    inner: AliasTable,
+}
+
+#[pymethods]
+impl PyAliasTable {
+    /// Weights need not sum to 1; a list of ints builds an exact table.
+    #[new]
+    fn py_new(weights: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let inner = match weights.extract::<Vec<u64>>() {
+            Ok(ints) => AliasTable::from_int_weights(&ints),
+            Err(_) => AliasTable::new(&weights.extract::<Vec<f64>>()?),
+        }
+        .map_err(mrnderr_to_py)?;
+        Ok(Self { inner })
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn probability(&self, i: usize) -> f64 {
+        self.inner.probability(i)
+    }
+}
+
+/// `probs` of `choice`/`choice_idx`: a list of probabilities or a prebuilt `AliasTable`.
+enum Probs<'py> {
+    List(Vec<f64>),
+    Table(PyRef<'py, PyAliasTable>),
+}
+
+impl<'py> Probs<'py> {
+    fn extract(probs: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match probs.downcast::<PyAliasTable>() {
+            Ok(table) => Ok(Probs::Table(table.borrow())),
+            Err(_) => Ok(Probs::List(probs.extract()?)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Probs::List(p) => p.len(),
+            Probs::Table(t) => t.inner.len(),
+        }
+    }
+
+    fn choice_idx(&self, rng: &mut DynMPRng) -> PyResult<usize> {
+        match self {
+            Probs::List(p) => rng.choice_idx(p).map_err(mrnderr_to_py),
+            Probs::Table(t) => Ok(t.inner.sample(rng)),
+        }
+    }
+}
+
+/// Little-endian `bytes` as a numpy array of `dtype`, or as an `array.array` of `typecode`
+/// when numpy is not installed.
+fn to_array<'py>(
+    py: Python<'py>,
+    bytes: Vec<u8>,
+    dtype: &str,
+    typecode: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    let buf = PyByteArray::new_bound(py, &bytes);
+    match PyModule::import_bound(py, "numpy") {
+        Ok(np) => np.call_method1("frombuffer", (buf, dtype)),
+        Err(e) if e.is_instance_of::<PyImportError>(py) => {
+            PyModule::import_bound(py, "array")?.call_method1("array", (typecode, buf))
+        }
+        Err(e) => Err(e),
+    }
 }
 
 #[pyfunction]
@@ -51,12 +127,26 @@ pub fn rng_sv(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
     build_rng_with_schema(py, "Lcg64::SV", seed)
 }
 
+/// Legacy preset: the raw 64-bit LCG `Lcg64::PCG64` (PCG's multiplier and increment, no output
+/// permutation), not PCG64. Use `PyMPRng("Pcg64", seed)` for PCG64 itself.
 #[pyfunction]
 #[pyo3(signature=(seed=None))]
 pub fn rng_pcg64(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
     build_rng_with_schema(py, "Lcg64::PCG64", seed)
 }
 
+#[pyfunction]
+#[pyo3(signature=(seed=None))]
+pub fn rng_pcg64dxsm(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
+    build_rng_with_schema(py, "Pcg64Dxsm", seed)
+}
+
+#[pyfunction]
+#[pyo3(signature=(seed=None))]
+pub fn rng_chacha20(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
+    build_rng_with_schema(py, "ChaCha20Rng", seed)
+}
+
 #[pyfunction]
 #[pyo3(signature=(seed=None))]
 pub fn default_rng(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
@@ -65,6 +155,66 @@ pub fn default_rng(py: Python<'_>, seed: Option<u64>) -> PyResult<Py<PyMPRng>> {
 
 #[pymethods]
 impl PyMPRng {
+    #[new]
+    #[pyo3(signature=(schema="Lcg64::DK", seed=None))]
+    fn py_new(schema: &str, seed: Option<u64>) -> PyResult<Self> {
+        let mut c = HashMap::<String, String>::new();
+        c.insert("schema".into(), schema.into());
+        if let Some(s) = seed {
+            c.insert("seed".into(), s.to_string());
+        }
+        let inner = MPRng::build(&MPCfg { c }).map_err(mrnderr_to_py)?;
+        Ok(PyMPRng { inner })
+    }
+
+    /// The generator state as a dict (see `MPRngState::to_json`); assigning a dict or the
+    /// bytes of `__getstate__` restores it.
+    #[getter]
+    fn state(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let st = self.inner.get_state().map_err(mrnderr_to_py)?;
+        let json = py.import_bound("json")?;
+        Ok(json.call_method1("loads", (st.to_json(),))?.unbind())
+    }
+
+    #[setter(state)]
+    fn set_state_py(&mut self, py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let st = if let Ok(b) = value.downcast::<PyBytes>() {
+            MPRngState::from_bytes(b.as_bytes())
+        } else if value.is_instance_of::<PyDict>() {
+            let json = py.import_bound("json")?;
+            let s: String = json.call_method1("dumps", (value,))?.extract()?;
+            MPRngState::from_json(&s)
+        } else {
+            return Err(PyValueError::new_err("state must be a dict or bytes"));
+        };
+        let st = st.map_err(mrnderr_to_py)?;
+        self.inner.set_state(&st).map_err(mrnderr_to_py)
+    }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let st = self.inner.get_state().map_err(mrnderr_to_py)?;
+        Ok(PyBytes::new_bound(py, &st.to_bytes()))
+    }
+
+    fn __setstate__(&mut self, state: &Bound<'_, PyBytes>) -> PyResult<()> {
+        let st = MPRngState::from_bytes(state.as_bytes()).map_err(mrnderr_to_py)?;
+        self.inner.set_state(&st).map_err(mrnderr_to_py)
+    }
+
+    /// Rebuilt as a seeded placeholder, then overwritten by `__setstate__`.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+        let py = slf.py();
+        let state = slf.borrow().__getstate__(py)?;
+        Ok(PyTuple::new_bound(
+            py,
+            [
+                slf.get_type().into_any().unbind(),
+                ("Lcg64::DK", 0u64).into_py(py),
+                state.into_any().unbind(),
+            ],
+        ))
+    }
+
     pub fn next_u64(&mut self) -> u64 {
         self.inner.next_u64()
     }
@@ -77,16 +227,67 @@ impl PyMPRng {
         self.inner.next_f32()
     }
 
-    pub fn choice_idx(&mut self, probs: Vec<f64>) -> PyResult<usize> {
-        self.inner.choice_idx(&probs).map_err(mrnderr_to_py)
+    /// `n` raw 64-bit words as a numpy `uint64` array.
+    pub fn fill_u64<'py>(&mut self, py: Python<'py>, n: usize) -> PyResult<Bound<'py, PyAny>> {
+        let mut out = vec![0u64; n];
+        self.inner.fill_u64(&mut out);
+        let bytes = out.iter().flat_map(|x| x.to_le_bytes()).collect();
+        to_array(py, bytes, "<u8", "Q")
+    }
+
+    /// `n` uniforms in [0, 1) as a numpy `float64` array, the values of `next_f64`.
+    pub fn fill_f64<'py>(&mut self, py: Python<'py>, n: usize) -> PyResult<Bound<'py, PyAny>> {
+        let mut out = vec![0f64; n];
+        self.inner.fill_f64(&mut out);
+        let bytes = out.iter().flat_map(|x| x.to_le_bytes()).collect();
+        to_array(py, bytes, "<f8", "d")
+    }
+
+    /// `n` uniforms in [0, 1) as a numpy `float32` array, the values of `next_f32`.
+    pub fn fill_f32<'py>(&mut self, py: Python<'py>, n: usize) -> PyResult<Bound<'py, PyAny>> {
+        let mut out = vec![0f32; n];
+        self.inner.fill_f32(&mut out);
+        let bytes = out.iter().flat_map(|x| x.to_le_bytes()).collect();
+        to_array(py, bytes, "<f4", "f")
+    }
+
+    /// `n` standard normals (ziggurat) as a numpy `float64` array.
+    pub fn fill_normal<'py>(&mut self, py: Python<'py>, n: usize) -> PyResult<Bound<'py, PyAny>> {
+        let mut out = vec![0f64; n];
+        self.inner.fill_normal(&mut out);
+        let bytes = out.iter().flat_map(|x| x.to_le_bytes()).collect();
+        to_array(py, bytes, "<f8", "d")
     }
 
+    /// `n` uniform integers in [low, high) as a numpy `int64` array.
+    pub fn fill_range<'py>(
+        &mut self,
+        py: Python<'py>,
+        low: i64,
+        high: i64,
+        n: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut out = vec![0i64; n];
+        self.inner
+            .fill_range_i64(low..high, &mut out)
+            .map_err(mrnderr_to_py)?;
+        let bytes = out.iter().flat_map(|x| x.to_le_bytes()).collect();
+        to_array(py, bytes, "<i8", "q")
+    }
+
+    /// `probs` is a list of probabilities or a prebuilt `AliasTable`.
+    pub fn choice_idx(&mut self, probs: &Bound<'_, PyAny>) -> PyResult<usize> {
+        Probs::extract(probs)?.choice_idx(&mut self.inner)
+    }
+
+    /// `probs` is a list of probabilities or a prebuilt `AliasTable` over `elements`.
     pub fn choice<'py>(
         &mut self,
         py: Python<'py>,
         elements: &Bound<'py, PyAny>,
-        probs: Vec<f64>,
+        probs: &Bound<'py, PyAny>,
     ) -> PyResult<PyObject> {
+        let probs = Probs::extract(probs)?;
         let seq = elements.downcast::<PySequence>().map_err(|_| {
             PyValueError::new_err("elements must be a python sequence (e.g., list/tuple)")
         })?;
@@ -100,13 +301,63 @@ impl PyMPRng {
         }
 
         // 3) use Rust RNG logic
-        let idx = self.inner.choice_idx(&probs).map_err(mrnderr_to_py)?;
+        let idx = probs.choice_idx(&mut self.inner)?;
 
         // 4) fetch Python element at that index
         let item = seq.get_item(idx as usize)?;
         Ok(item.into_py(py))
     }
 
+    /// Shuffle a mutable sequence (e.g. a list) in place.
+    pub fn shuffle(&mut self, x: &Bound<'_, PyAny>) -> PyResult<()> {
+        let seq = x.downcast::<PySequence>().map_err(|_| {
+            PyValueError::new_err("x must be a mutable python sequence (e.g., list)")
+        })?;
+        let mut items = Vec::with_capacity(seq.len()?);
+        for i in 0..seq.len()? {
+            items.push(seq.get_item(i)?);
+        }
+        self.inner.shuffle(&mut items);
+        for (i, item) in items.into_iter().enumerate() {
+            seq.set_item(i, item)?;
+        }
+        Ok(())
+    }
+
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        self.inner.permutation(n)
+    }
+
+    pub fn sample_indices(&mut self, n: usize, k: usize) -> PyResult<Vec<usize>> {
+        self.inner.sample_indices(n, k).map_err(mrnderr_to_py)
+    }
+
+    /// `k` distinct elements of `population`, without replacement; with `weights`, drawn
+    /// with probability proportional to them.
+    #[pyo3(signature=(population, k, weights=None))]
+    pub fn sample<'py>(
+        &mut self,
+        population: &Bound<'py, PyAny>,
+        k: usize,
+        weights: Option<Vec<f64>>,
+    ) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        let seq = population.downcast::<PySequence>().map_err(|_| {
+            PyValueError::new_err("population must be a python sequence (e.g., list/tuple)")
+        })?;
+        let n = seq.len()?;
+        let idx = match weights {
+            Some(w) if w.len() != n => {
+                return Err(PyValueError::new_err(
+                    "population and weights must have same length",
+                ));
+            }
+            Some(w) => self.inner.sample_weighted(&w, k),
+            None => self.inner.sample_indices(n, k),
+        }
+        .map_err(mrnderr_to_py)?;
+        idx.into_iter().map(|i| seq.get_item(i)).collect()
+    }
+
     pub fn norm(&mut self) -> f64 {
         self.inner.norm_box_muller()
     }
@@ -122,6 +373,14 @@ impl PyMPRng {
     pub fn next_f32_fast(&mut self) -> f32 {
         self.inner.next_f32_fast()
     }
+
+    pub fn advance(&mut self, delta: u128) -> PyResult<()> {
+        self.inner.advance(delta).map_err(mrnderr_to_py)
+    }
+
+    pub fn jump(&mut self) -> PyResult<()> {
+        self.inner.jump().map_err(mrnderr_to_py)
+    }
 }
 
 pub fn bind_submodule(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -130,9 +389,17 @@ pub fn bind_submodule(m: &Bound<'_, PyModule>) -> PyResult<()> {
     sm.add_function(wrap_pyfunction!(rng_dk, &sm)?)?;
     sm.add_function(wrap_pyfunction!(rng_sv, &sm)?)?;
     sm.add_function(wrap_pyfunction!(rng_pcg64, &sm)?)?;
+    sm.add_function(wrap_pyfunction!(rng_pcg64dxsm, &sm)?)?;
+    sm.add_function(wrap_pyfunction!(rng_chacha20, &sm)?)?;
     sm.add_function(wrap_pyfunction!(default_rng, &sm)?)?;
     sm.add_class::<PyMPRng>()?;
+    sm.add_class::<PyAliasTable>()?;
     m.add_submodule(&sm)?;
     m.add("random", &sm)?;
+    // make `import marcrypto.random` (and so unpickling) work
+    m.py()
+        .import_bound("sys")?
+        .getattr("modules")?
+        .set_item("marcrypto.random", &sm)?;
     Ok(())
 }
@@ -0,0 +1,66 @@
+//! Batch GCD over a set of moduli (D. J. Bernstein's product/remainder trees).
+//!
+//! For moduli `n_0, ..., n_{k-1}` with product `P`, the i-th output is
+//! `gcd(n_i, (P mod n_i^2) / n_i)`, i.e. the gcd of `n_i` with the product of all the
+//! other moduli. Any output other than 1 means `n_i` shares a factor with another modulus.
+use crate::MarInt;
+
+/// Build the product tree bottom-up.
+/// `tree[0]` holds the inputs, the last level holds the single product of all of them.
+pub fn product_tree(moduli: &[MarInt]) -> Vec<Vec<MarInt>> {
+    if moduli.is_empty() {
+        return Vec::new();
+    }
+
+    let mut tree = vec![moduli.to_vec()];
+    while tree[tree.len() - 1].len() > 1 {
+        let level = &tree[tree.len() - 1];
+        let next = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a * b,
+                // odd one out moves up unchanged
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        tree.push(next);
+    }
+
+    tree
+}
+
+/// Walk a product tree top-down, reducing the root modulo the square of every node.
+/// Returns `P mod n_i^2` for every leaf `n_i`.
+pub fn remainder_tree(tree: &[Vec<MarInt>]) -> Vec<MarInt> {
+    let Some(root) = tree.last() else {
+        return Vec::new();
+    };
+
+    let mut rems = root.clone();
+    for level in tree[..tree.len() - 1].iter().rev() {
+        rems = level
+            .iter()
+            .enumerate()
+            .map(|(i, node)| &rems[i / 2] % &(node * node))
+            .collect();
+    }
+
+    rems
+}
+
+/// For every modulus, the gcd with the product of all the others.
+///
+/// The result is 1 for a modulus that shares no factor with the rest of the set, a proper
+/// factor when exactly one of its primes is shared, and the modulus itself when both primes
+/// are shared (e.g. the same modulus appears twice). All moduli must be non-zero.
+pub fn batch_gcd(moduli: &[MarInt]) -> Vec<MarInt> {
+    let tree = product_tree(moduli);
+    let rems = remainder_tree(&tree);
+
+    moduli
+        .iter()
+        .zip(rems.iter())
+        .map(|(n, r)| (r / n).gcd(n))
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Report RSA moduli that share a prime with another modulus of the same set.
+//!
+//! Usage: `batchgcd <FILE>` (or `-` for stdin). The input holds one hex modulus per line;
+//! blank lines and lines starting with `#` are skipped.
+use marint::{MarInt, batch_gcd};
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s)?;
+        Ok(s)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 2 {
+        eprintln!("usage: {} <FILE | ->", args[0]);
+        return ExitCode::from(2);
+    }
+
+    let text = match read_input(&args[1]) {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("error: cannot read '{}': {}", args[1], e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // (line number, modulus)
+    let mut keys: Vec<(usize, MarInt)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match MarInt::from_hex(line) {
+            Ok(n) if !n.is_zero() => keys.push((i + 1, n)),
+            Ok(_) => {
+                eprintln!("error: line {}: modulus must be non-zero", i + 1);
+                return ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("error: line {}: invalid hex modulus: {:?}", i + 1, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let moduli = keys.iter().map(|(_, n)| n.clone()).collect::<Vec<_>>();
+    let gcds = batch_gcd(&moduli);
+
+    let mut n_vulnerable = 0usize;
+    for ((line, n), g) in keys.iter().zip(gcds.iter()) {
+        if g.is_one() {
+            continue;
+        }
+        n_vulnerable += 1;
+        if g == n {
+            println!(
+                "line {}: {:x} shares all its factors (duplicate modulus?)",
+                line, n
+            );
+        } else {
+            println!("line {}: {:x} shares factor {:x}", line, n, g);
+        }
+    }
+
+    println!("{} moduli checked, {} vulnerable", keys.len(), n_vulnerable);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,109 @@
+//! Chinese Remainder Theorem on MarInt.
+//!
+//! `MarInt::crt` solves a one-off system of congruences with arbitrary (also non-coprime)
+//! moduli. `CrtGarner` precomputes Garner's constants for pairwise coprime moduli that are
+//! reused many times, e.g. the primes of an RSA-CRT private key.
+use crate::MarInt;
+
+impl MarInt {
+    /// Solve `x ≡ r_i (mod m_i)` for every `(r_i, m_i)`.
+    ///
+    /// Returns `(x, m)` with `m = lcm(m_i)` and `0 <= x < m`, so every solution is `x + k*m`.
+    /// Returns `None` if the congruences are inconsistent or a modulus is not positive.
+    /// The moduli do not need to be pairwise coprime. An empty system gives `(0, 1)`.
+    pub fn crt(congruences: &[(MarInt, MarInt)]) -> Option<(MarInt, MarInt)> {
+        let mut x = MarInt::zero();
+        let mut m = MarInt::one();
+
+        for (r, n) in congruences {
+            if n.sign != crate::MPos {
+                return None;
+            }
+
+            // x + m*k ≡ r (mod n)  <=>  m*k ≡ r - x (mod n)
+            let (g, p, _) = MarInt::ext_gcd(&m, n);
+            let diff = r - &x;
+            let (k, rem) = (&diff / &g, &diff % &g);
+            if !rem.is_zero() {
+                return None;
+            }
+
+            let n_g = n / &g;
+            let k = (&k * &p).rem_euclid(&n_g);
+            let lcm = &m * &n_g;
+            x = (&x + &(&m * &k)).rem_euclid(&lcm);
+            m = lcm;
+        }
+
+        Some((x, m))
+    }
+}
+
+/// Garner's form of the CRT for a fixed set of pairwise coprime moduli.
+#[derive(Debug, Clone)]
+pub struct CrtGarner {
+    moduli: Vec<MarInt>,
+    // prefix[i] = m_0 * ... * m_{i-1}
+    prefix: Vec<MarInt>,
+    // inv[i] = prefix[i]^-1 mod m_i
+    inv: Vec<MarInt>,
+    modulus: MarInt,
+}
+
+impl CrtGarner {
+    /// Precompute the constants. `None` if a modulus is not positive or two moduli share a factor.
+    pub fn new(moduli: &[MarInt]) -> Option<Self> {
+        let mut prefix = Vec::with_capacity(moduli.len());
+        let mut inv = Vec::with_capacity(moduli.len());
+        let mut acc = MarInt::one();
+
+        for m in moduli {
+            if m.sign != crate::MPos {
+                return None;
+            }
+            inv.push(acc.mod_inverse(m)?);
+            prefix.push(acc.clone());
+            acc = &acc * m;
+        }
+
+        Some(Self {
+            moduli: moduli.to_vec(),
+            prefix,
+            inv,
+            modulus: acc,
+        })
+    }
+
+    pub fn moduli(&self) -> &[MarInt] {
+        &self.moduli
+    }
+
+    /// The product of all moduli.
+    pub fn modulus(&self) -> &MarInt {
+        &self.modulus
+    }
+
+    /// The unique `x` in `[0, modulus)` with `x ≡ residues[i] (mod m_i)`.
+    ///
+    /// # Panics
+    /// If `residues` and the moduli differ in length.
+    pub fn combine(&self, residues: &[MarInt]) -> MarInt {
+        assert_eq!(
+            residues.len(),
+            self.moduli.len(),
+            "CrtGarner::combine: expected {} residues, got {}",
+            self.moduli.len(),
+            residues.len()
+        );
+
+        // x_i = x_{i-1} + prefix[i] * ((r_i - x_{i-1}) * inv[i] mod m_i)
+        let mut x = MarInt::zero();
+        for (i, r) in residues.iter().enumerate() {
+            let m = &self.moduli[i];
+            let digit = MarInt::mul_mod(&(r - &x).rem_euclid(m), &self.inv[i], m);
+            x += &self.prefix[i] * &digit;
+        }
+
+        x
+    }
+}
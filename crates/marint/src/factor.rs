@@ -0,0 +1,372 @@
+//! Integer factorization on MarInt: trial division, Pollard's rho (Brent variant),
+//! Pollard p−1, Williams p+1 and Lenstra ECM on Montgomery curves.
+//!
+//! The individual methods return `Some(d)` with a proper divisor `1 < d < n` or `None` when
+//! they give up. `factorize` chains them into a complete factorization.
+use crate::MarInt;
+use crate::prime::{is_probable_prime, small_primes};
+
+/// Primes up to this bound are removed by trial division in `factorize`.
+pub const TRIAL_DIVISION_BOUND: u64 = 4_096;
+
+/// Number of steps between two gcd checks in the rho, p−1 and p+1 loops.
+const GCD_BATCH: usize = 64;
+
+#[inline]
+fn sub_mod(a: &MarInt, b: &MarInt, n: &MarInt) -> MarInt {
+    (a - b).rem_euclid(n)
+}
+
+#[inline]
+fn proper_divisor(g: MarInt, n: &MarInt) -> Option<MarInt> {
+    if g.is_one() || g.is_zero() || &g == n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+/// The largest power of the prime `p` not exceeding `bound`.
+#[inline]
+fn max_prime_power(p: u64, bound: u64) -> u64 {
+    let mut pk = p;
+    while pk <= bound / p {
+        pk *= p;
+    }
+    pk
+}
+
+/// Remove every prime factor `<= bound` from `n`.
+/// Returns the small prime factors with their multiplicity and the remaining cofactor.
+pub fn trial_division(n: &MarInt, bound: u64) -> (Vec<(MarInt, u32)>, MarInt) {
+    let mut factors = Vec::new();
+    let mut limbs = n.abs().limbs;
+
+    for p in small_primes(bound) {
+        let mut e = 0u32;
+        loop {
+            let (q, r) = MarInt::shortdiv_limbs_by_u64(&limbs, p);
+            if r != 0 || MarInt::is_limbs_zero(&limbs) {
+                break;
+            }
+            limbs = q;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((MarInt::from_u64(p), e));
+        }
+        if MarInt::is_limbs_one(&limbs) {
+            break;
+        }
+    }
+
+    let mut rest = MarInt {
+        sign: crate::MPos,
+        limbs,
+    };
+    rest.normalize();
+    (factors, rest)
+}
+
+/// Pollard's rho with Brent's cycle detection on `x -> x^2 + c mod n`.
+/// Gives up after about `max_iters` evaluations of the map.
+pub fn pollard_rho_brent(n: &MarInt, c: u64, max_iters: u64) -> Option<MarInt> {
+    if !n.bit(0) {
+        return proper_divisor(MarInt::from_u64(2), n);
+    }
+
+    let c = MarInt::from_u64(c);
+    let f = |x: &MarInt| &(&(x * x) + &c) % n;
+
+    let mut y = MarInt::from_u64(2);
+    let mut q = MarInt::one();
+    let mut r = 1u64;
+    let mut iters = 0u64;
+
+    let (x, mut ys, mut g) = loop {
+        let x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        iters += r;
+
+        let mut ys = y.clone();
+        let mut g = MarInt::one();
+        let mut k = 0u64;
+        while k < r && g.is_one() {
+            ys = y.clone();
+            let steps = (GCD_BATCH as u64).min(r - k);
+            for _ in 0..steps {
+                y = f(&y);
+                q = MarInt::mul_mod(&q, &sub_mod(&x, &y, n), n);
+            }
+            g = q.gcd(n);
+            k += steps;
+        }
+        iters += k;
+        r *= 2;
+
+        if !g.is_one() {
+            break (x, ys, g);
+        }
+        if iters >= max_iters {
+            return None;
+        }
+    };
+
+    if &g == n {
+        // the batched product hit 0 mod n: redo the last batch one step at a time
+        loop {
+            ys = f(&ys);
+            g = sub_mod(&x, &ys, n).gcd(n);
+            if !g.is_one() {
+                break;
+            }
+        }
+    }
+
+    proper_divisor(g, n)
+}
+
+/// Pollard's p−1 (stage 1): finds p | n when p − 1 is `b1`-smooth.
+pub fn pollard_pm1(n: &MarInt, b1: u64) -> Option<MarInt> {
+    let one = MarInt::one();
+    let primes = small_primes(b1);
+    let mut a = MarInt::from_u64(2);
+
+    for chunk in primes.chunks(GCD_BATCH) {
+        let checkpoint = a.clone();
+        for &p in chunk {
+            a = a.pow_mod(&MarInt::from_u64(max_prime_power(p, b1)), n);
+        }
+
+        let g = sub_mod(&a, &one, n).gcd(n);
+        if g.is_one() {
+            continue;
+        }
+        if &g != n {
+            return Some(g);
+        }
+
+        // every prime of n was found inside this chunk: retry it prime by prime
+        a = checkpoint;
+        for &p in chunk {
+            a = a.pow_mod(&MarInt::from_u64(max_prime_power(p, b1)), n);
+            let g = sub_mod(&a, &one, n).gcd(n);
+            if !g.is_one() {
+                return proper_divisor(g, n);
+            }
+        }
+    }
+
+    None
+}
+
+/// `V_m(P) mod n` of the Lucas sequence `V_0 = 2, V_1 = P, V_k = P V_{k-1} - V_{k-2}`.
+fn lucas_v(v: &MarInt, m: u64, n: &MarInt) -> MarInt {
+    let two = MarInt::from_u64(2);
+    let mut x = v.clone();
+    let mut y = sub_mod(&MarInt::mul_mod(v, v, n), &two, n);
+
+    for i in (0..63 - m.leading_zeros()).rev() {
+        if (m >> i) & 1 == 1 {
+            x = sub_mod(&MarInt::mul_mod(&x, &y, n), v, n);
+            y = sub_mod(&MarInt::mul_mod(&y, &y, n), &two, n);
+        } else {
+            y = sub_mod(&MarInt::mul_mod(&x, &y, n), v, n);
+            x = sub_mod(&MarInt::mul_mod(&x, &x, n), &two, n);
+        }
+    }
+
+    x
+}
+
+/// Williams' p+1 (stage 1) with Lucas seed `a`: finds p | n when p + 1 is `b1`-smooth
+/// and `a^2 - 4` is a quadratic non-residue mod p. Try a few seeds to cover the other case.
+pub fn williams_pp1(n: &MarInt, a: u64, b1: u64) -> Option<MarInt> {
+    let two = MarInt::from_u64(2);
+    let primes = small_primes(b1);
+    let mut v = &MarInt::from_u64(a) % n;
+
+    for chunk in primes.chunks(GCD_BATCH) {
+        let checkpoint = v.clone();
+        for &p in chunk {
+            v = lucas_v(&v, max_prime_power(p, b1), n);
+        }
+
+        let g = sub_mod(&v, &two, n).gcd(n);
+        if g.is_one() {
+            continue;
+        }
+        if &g != n {
+            return Some(g);
+        }
+
+        v = checkpoint;
+        for &p in chunk {
+            v = lucas_v(&v, max_prime_power(p, b1), n);
+            let g = sub_mod(&v, &two, n).gcd(n);
+            if !g.is_one() {
+                return proper_divisor(g, n);
+            }
+        }
+    }
+
+    None
+}
+
+/// A Montgomery curve `B y^2 = x^3 + A x^2 + x` in the `(A + 2C : 4C)` form used by
+/// x-only arithmetic, with points in projective `(X : Z)` coordinates.
+struct MontCurve<'a> {
+    n: &'a MarInt,
+    a24: MarInt,
+    c24: MarInt,
+}
+
+impl MontCurve<'_> {
+    fn double(&self, x: &MarInt, z: &MarInt) -> (MarInt, MarInt) {
+        let n = self.n;
+        let s = &(x + z) % n;
+        let d = sub_mod(x, z, n);
+        let s2 = MarInt::mul_mod(&s, &s, n);
+        let d2 = MarInt::mul_mod(&d, &d, n);
+        let cd2 = MarInt::mul_mod(&self.c24, &d2, n);
+        let x2 = MarInt::mul_mod(&cd2, &s2, n);
+        // 4xz = (x+z)^2 - (x-z)^2
+        let t = sub_mod(&s2, &d2, n);
+        let z2 = MarInt::mul_mod(&(&(&cd2 + &MarInt::mul_mod(&self.a24, &t, n)) % n), &t, n);
+        (x2, z2)
+    }
+
+    /// Differential addition: P + Q from P, Q and P − Q.
+    fn add(
+        &self,
+        p: &(MarInt, MarInt),
+        q: &(MarInt, MarInt),
+        diff: &(MarInt, MarInt),
+    ) -> (MarInt, MarInt) {
+        let n = self.n;
+        let u = MarInt::mul_mod(&sub_mod(&p.0, &p.1, n), &(&(&q.0 + &q.1) % n), n);
+        let v = MarInt::mul_mod(&(&(&p.0 + &p.1) % n), &sub_mod(&q.0, &q.1, n), n);
+        let s = &(&u + &v) % n;
+        let d = sub_mod(&u, &v, n);
+        let x = MarInt::mul_mod(&diff.1, &MarInt::mul_mod(&s, &s, n), n);
+        let z = MarInt::mul_mod(&diff.0, &MarInt::mul_mod(&d, &d, n), n);
+        (x, z)
+    }
+
+    /// Montgomery ladder: `[k]P`.
+    fn mul(&self, p: &(MarInt, MarInt), k: u64) -> (MarInt, MarInt) {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(&p.0, &p.1);
+
+        for i in (0..63 - k.leading_zeros()).rev() {
+            if (k >> i) & 1 == 1 {
+                r0 = self.add(&r1, &r0, p);
+                r1 = self.double(&r1.0, &r1.1);
+            } else {
+                r1 = self.add(&r0, &r1, p);
+                r0 = self.double(&r0.0, &r0.1);
+            }
+        }
+
+        r0
+    }
+}
+
+/// One ECM curve (stage 1) with Suyama's parametrization for `sigma >= 6`.
+pub fn ecm_curve(n: &MarInt, sigma: u64, b1: u64) -> Option<MarInt> {
+    let sigma = MarInt::from_u64(sigma);
+    let u = sub_mod(&MarInt::mul_mod(&sigma, &sigma, n), &MarInt::from_u64(5), n);
+    let v = &(&sigma * &MarInt::from_u64(4)) % n;
+    let u3 = MarInt::mul_mod(&MarInt::mul_mod(&u, &u, n), &u, n);
+    let v3 = MarInt::mul_mod(&MarInt::mul_mod(&v, &v, n), &v, n);
+
+    // (A + 2C : 4C) = ((v - u)^3 (3u + v) : 16 u^3 v)
+    let vu = sub_mod(&v, &u, n);
+    let vu3 = MarInt::mul_mod(&MarInt::mul_mod(&vu, &vu, n), &vu, n);
+    let a24 = MarInt::mul_mod(&vu3, &(&(&(&u * &MarInt::from_u64(3)) + &v) % n), n);
+    let c24 = MarInt::mul_mod(&(&(&u3 * &MarInt::from_u64(16)) % n), &v, n);
+
+    let g = c24.gcd(n);
+    if !g.is_one() {
+        return proper_divisor(g, n);
+    }
+
+    let curve = MontCurve { n, a24, c24 };
+    let mut pt = (u3, v3);
+    for p in small_primes(b1) {
+        pt = curve.mul(&pt, max_prime_power(p, b1));
+    }
+
+    proper_divisor(pt.1.gcd(n), n)
+}
+
+/// Lenstra ECM: up to `curves` stage-1 curves with `sigma = sigma0, sigma0 + 1, ...`.
+pub fn ecm(n: &MarInt, b1: u64, curves: u32, sigma0: u64) -> Option<MarInt> {
+    (0..curves as u64).find_map(|i| ecm_curve(n, sigma0 + i, b1))
+}
+
+/// Find a proper divisor of a composite `n` without small factors.
+fn find_divisor(n: &MarInt) -> MarInt {
+    if let Some(d) = pollard_rho_brent(n, 1, 1 << 16) {
+        return d;
+    }
+    if let Some(d) = pollard_pm1(n, 20_000) {
+        return d;
+    }
+    if let Some(d) = williams_pp1(n, 7, 20_000) {
+        return d;
+    }
+
+    // B1 and number of curves from the usual GMP-ECM table for 15-, 20- and 25-digit factors
+    let mut sigma = 6;
+    for (b1, curves) in [(2_000u64, 25u32), (11_000, 90), (50_000, 300)] {
+        if let Some(d) = ecm(n, b1, curves, sigma) {
+            return d;
+        }
+        sigma += curves as u64;
+    }
+
+    // last resort: rho never gives up
+    let mut c = 2;
+    loop {
+        if let Some(d) = pollard_rho_brent(n, c, u64::MAX) {
+            return d;
+        }
+        c += 1;
+    }
+}
+
+/// Complete factorization of `|n|` into primes, sorted ascending with multiplicities.
+/// 0 and ±1 have no prime factors.
+pub fn factorize(n: &MarInt) -> Vec<(MarInt, u32)> {
+    let n = n.abs();
+    if n.is_zero() || n.is_one() {
+        return Vec::new();
+    }
+
+    let (mut factors, rest) = trial_division(&n, TRIAL_DIVISION_BOUND);
+
+    let mut primes = Vec::new();
+    let mut pending = if rest.is_one() { vec![] } else { vec![rest] };
+    while let Some(m) = pending.pop() {
+        if is_probable_prime(&m) {
+            primes.push(m);
+            continue;
+        }
+        let d = find_divisor(&m);
+        pending.push(&m / &d);
+        pending.push(d);
+    }
+
+    primes.sort();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, e)) if *last == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+
+    factors
+}
@@ -1,5 +1,6 @@
 use crate::marint::MarInt;
 use crate::sign::MSgn::*;
+use core::fmt;
 use core::str::FromStr;
 
 const POW10: [u64; 20] = [
@@ -95,3 +96,69 @@ impl FromStr for MarInt {
         Ok(result)
     }
 }
+
+impl MarInt {
+    /// Parse a hexadecimal string with an optional sign and an optional `0x`/`0X` prefix.
+    pub fn from_hex(s: &str) -> Result<Self, ParseMarIntError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseMarIntError::Empty);
+        }
+
+        let (sign, digits) = match s.as_bytes()[0] {
+            b'+' => (MPos, &s[1..]),
+            b'-' => (MNeg, &s[1..]),
+            _ => (MPos, s),
+        };
+        let digits = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+            .unwrap_or(digits);
+
+        if digits.is_empty() {
+            return Err(ParseMarIntError::Empty);
+        }
+
+        for ch in digits.chars() {
+            if !ch.is_ascii_hexdigit() {
+                return Err(ParseMarIntError::InvalidChar(ch));
+            }
+        }
+
+        // 16 hex digits per limb, least significant chunk at the end of the string
+        const CHUNK: usize = 16;
+
+        let mut limbs = Vec::with_capacity(digits.len().div_ceil(CHUNK));
+        let mut end = digits.len();
+        while end > 0 {
+            let start = end.saturating_sub(CHUNK);
+            let limb = u64::from_str_radix(&digits[start..end], 16).unwrap(); // safe: hex-only and len<=16
+            limbs.push(limb);
+            end = start;
+        }
+
+        let mut result = MarInt { sign, limbs };
+        result.normalize();
+        Ok(result)
+    }
+}
+
+impl fmt::LowerHex for MarInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = format!("{:x}", self.limbs[self.limbs.len() - 1]);
+        for limb in self.limbs[..self.limbs.len() - 1].iter().rev() {
+            s.push_str(&format!("{:016x}", limb));
+        }
+        f.pad_integral(self.sign != MNeg || self.is_zero(), "0x", &s)
+    }
+}
+
+impl fmt::UpperHex for MarInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = format!("{:X}", self.limbs[self.limbs.len() - 1]);
+        for limb in self.limbs[..self.limbs.len() - 1].iter().rev() {
+            s.push_str(&format!("{:016X}", limb));
+        }
+        f.pad_integral(self.sign != MNeg || self.is_zero(), "0x", &s)
+    }
+}
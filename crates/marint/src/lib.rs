@@ -3,9 +3,12 @@ pub mod sign;
 
 pub(crate) mod ops {
     mod op_add;
+    mod op_cmp;
     mod op_div;
+    mod op_gcd;
     mod op_mul;
     mod op_neg;
+    mod op_pow;
     mod op_sub;
     // pub mod complex {
     //     pub mod complex_add;
@@ -13,8 +16,17 @@ pub(crate) mod ops {
     // }
 }
 
+pub mod batchgcd;
+pub mod crt;
+pub mod factor;
 pub mod io;
+pub mod prime;
+pub mod secret;
 
+pub use crate::batchgcd::batch_gcd;
+pub use crate::crt::CrtGarner;
+pub use crate::factor::factorize;
 pub use crate::marint::MarInt;
+pub use crate::secret::SecretMarInt;
 pub use crate::sign::MSgn;
 pub use crate::sign::MSgn::{MNeg, MPos, MZero};
@@ -107,6 +107,31 @@ impl MarInt {
         self.sign == MZero || Self::is_limbs_zero(&self.limbs)
     }
 
+    pub fn is_one(&self) -> bool {
+        self.sign == MPos && Self::is_limbs_one(&self.limbs)
+    }
+
+    /// Number of significant bits of the magnitude (0 for zero).
+    pub fn bit_length(&self) -> u64 {
+        let top = self.limbs[self.limbs.len() - 1];
+        (self.limbs.len() as u64 - 1) * Self::LIMB_BITS as u64 + (64 - top.leading_zeros() as u64)
+    }
+
+    /// The i-th bit (LSB = bit 0) of the magnitude.
+    pub fn bit(&self, i: u64) -> bool {
+        let idx = (i / Self::LIMB_BITS as u64) as usize;
+        idx < self.limbs.len() && (self.limbs[idx] >> (i % Self::LIMB_BITS as u64)) & 1 == 1
+    }
+
+    /// The magnitude as u64, if it fits.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.limbs.len() == 1 {
+            Some(self.limbs[0])
+        } else {
+            None
+        }
+    }
+
     pub fn abs(&self) -> Self {
         if self.sign == MPos || self.sign == MZero {
             self.clone()
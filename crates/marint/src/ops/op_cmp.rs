@@ -0,0 +1,43 @@
+use crate::MSgn::*;
+use crate::MarInt;
+use std::cmp::Ordering;
+
+/* -----------------------------
+ * PartialEq / Eq
+ * ----------------------------- */
+
+impl PartialEq for MarInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MarInt {}
+
+/* -----------------------------
+ * PartialOrd / Ord
+ * ----------------------------- */
+
+impl PartialOrd for MarInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MarInt {
+    /// Signed comparison. Zero compares equal to zero regardless of the limbs' length.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sa = if self.is_zero() { MZero } else { self.sign };
+        let sb = if other.is_zero() { MZero } else { other.sign };
+
+        match (sa as i8).cmp(&(sb as i8)) {
+            Ordering::Equal => match sa {
+                MZero => Ordering::Equal,
+                MPos => Self::cmp_limbs(&self.limbs, &other.limbs),
+                // both negative: the larger magnitude is the smaller value
+                MNeg => Self::cmp_limbs(&other.limbs, &self.limbs),
+            },
+            ord => ord,
+        }
+    }
+}
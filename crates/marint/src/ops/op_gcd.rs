@@ -0,0 +1,66 @@
+use crate::MSgn::*;
+use crate::MarInt;
+
+impl MarInt {
+    /// Greatest common divisor of two magnitudes (Euclid on limbs).
+    /// `a` and `b` must be normalized magnitudes; gcd(0, 0) is [0].
+    pub fn gcd_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut x = a.to_vec();
+        let mut y = b.to_vec();
+
+        while !Self::is_limbs_zero(&y) {
+            let (_, r) = Self::longdiv_limbs(&x, &y);
+            x = y;
+            y = r;
+        }
+
+        Self::normalize_limbs(&mut x);
+        x
+    }
+
+    /// Greatest common divisor, always non-negative.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut out = Self {
+            sign: MPos,
+            limbs: Self::gcd_limbs(&self.limbs, &other.limbs),
+        };
+        out.normalize();
+        out
+    }
+}
+
+impl MarInt {
+    /// Extended Euclid: returns `(g, x, y)` with `a*x + b*y = g = gcd(a, b) >= 0`.
+    pub fn ext_gcd(a: &Self, b: &Self) -> (Self, Self, Self) {
+        // invariants: old_r = a*old_s + b*old_t, r = a*s + b*t
+        let (mut old_r, mut r) = (a.clone(), b.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while !r.is_zero() {
+            let q = &old_r / &r;
+            let next_r = &old_r - &(&q * &r);
+            let next_s = &old_s - &(&q * &s);
+            let next_t = &old_t - &(&q * &t);
+            old_r = std::mem::replace(&mut r, next_r);
+            old_s = std::mem::replace(&mut s, next_s);
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+
+        if old_r.sign == MNeg {
+            (-old_r, -old_s, -old_t)
+        } else {
+            (old_r, old_s, old_t)
+        }
+    }
+
+    /// Inverse of `self` modulo a positive `m`, in `[0, m)`; `None` if gcd(self, m) != 1.
+    pub fn mod_inverse(&self, m: &Self) -> Option<Self> {
+        let (g, x, _) = Self::ext_gcd(&self.rem_euclid(m), m);
+        if g.is_one() {
+            Some(x.rem_euclid(m))
+        } else {
+            None
+        }
+    }
+}
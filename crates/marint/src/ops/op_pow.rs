@@ -0,0 +1,29 @@
+use crate::MarInt;
+
+impl MarInt {
+    /// Modular exponentiation `self^exp mod m` (left-to-right square-and-multiply).
+    /// `exp` must be non-negative and `m` positive; the result is in `[0, m)`.
+    pub fn pow_mod(&self, exp: &MarInt, m: &MarInt) -> MarInt {
+        if m.is_one() {
+            return MarInt::zero();
+        }
+
+        let base = self.rem_euclid(m);
+        let mut acc = MarInt::one();
+
+        for i in (0..exp.bit_length()).rev() {
+            acc = &(&acc * &acc) % m;
+            if exp.bit(i) {
+                acc = &(&acc * &base) % m;
+            }
+        }
+
+        acc
+    }
+
+    /// `(a * b) mod m` for operands already reduced into `[0, m)`.
+    #[inline]
+    pub fn mul_mod(a: &MarInt, b: &MarInt, m: &MarInt) -> MarInt {
+        &(a * b) % m
+    }
+}
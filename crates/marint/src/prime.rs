@@ -0,0 +1,89 @@
+//! Primality testing (trial division + Miller–Rabin).
+use crate::MarInt;
+
+/// Miller–Rabin bases: the first 13 primes. Deterministic for n < 3.3 * 10^24 (~81 bits),
+/// a strong probable-prime test beyond that.
+const MR_BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// All primes `<= limit` (sieve of Eratosthenes).
+pub fn small_primes(limit: u64) -> Vec<u64> {
+    if limit < 2 {
+        return Vec::new();
+    }
+
+    let n = limit as usize;
+    let mut is_composite = vec![false; n + 1];
+    let mut primes = Vec::new();
+
+    for i in 2..=n {
+        if is_composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        let mut j = i * i;
+        while j <= n {
+            is_composite[j] = true;
+            j += i;
+        }
+    }
+
+    primes
+}
+
+/// Strong probable-prime test of an odd `n > 3` to each of `bases`.
+pub fn miller_rabin(n: &MarInt, bases: &[u64]) -> bool {
+    let one = MarInt::one();
+    let n_minus_1 = n - &one;
+
+    // n - 1 = d * 2^s with d odd
+    let mut s = 0u64;
+    while !n_minus_1.bit(s) {
+        s += 1;
+    }
+    let mut d = n_minus_1.clone();
+    let mut shift = s;
+    while shift > 0 {
+        let k = shift.min(63);
+        d.limbs = MarInt::shortdiv_limbs_by_u64(&d.limbs, 1u64 << k).0;
+        shift -= k;
+    }
+
+    'bases: for &b in bases {
+        let a = &MarInt::from_u64(b) % n;
+        if a.is_zero() || a.is_one() || a == n_minus_1 {
+            continue;
+        }
+
+        let mut x = a.pow_mod(&d, n);
+        if x.is_one() || x == n_minus_1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = MarInt::mul_mod(&x, &x, n);
+            if x == n_minus_1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// Primality test for any MarInt (negative numbers, 0 and 1 are not prime).
+pub fn is_probable_prime(n: &MarInt) -> bool {
+    if n.sign != crate::MPos || n.is_one() {
+        return false;
+    }
+
+    for &p in MR_BASES.iter() {
+        if n.to_u64() == Some(p) {
+            return true;
+        }
+        if MarInt::shortdiv_limbs_by_u64(&n.limbs, p).1 == 0 {
+            return false;
+        }
+    }
+
+    miller_rabin(n, &MR_BASES)
+}
@@ -0,0 +1,99 @@
+//! `SecretMarInt`: a MarInt for key material (private exponents, primes, CRT exponents).
+//!
+//! The limbs, including the spare capacity of the Vec, are overwritten with zeros when the
+//! value is dropped or normalized, and `Debug` prints a redacted placeholder. There is
+//! deliberately no `Display`, so a secret can't end up in logs through `{}` or `{:?}`.
+//! Private key types built on MarInt should hold their secret fields as `SecretMarInt`.
+use crate::MarInt;
+use core::fmt;
+use std::sync::atomic::{Ordering, compiler_fence};
+
+/// Overwrite the slots `start..end` of the allocation of `limbs` with zeros.
+/// `end` may go up to the capacity: the slots past `len` are owned by the Vec.
+fn wipe_range(limbs: &mut Vec<u64>, start: usize, end: usize) {
+    debug_assert!(end <= limbs.capacity());
+    let ptr = limbs.as_mut_ptr();
+    for i in start..end {
+        // SAFETY: i < capacity, so the slot is inside the allocation owned by `limbs`;
+        // writing a u64 there is valid whether or not it is initialized.
+        unsafe { ptr.add(i).write_volatile(0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+impl MarInt {
+    /// Overwrite every limb and the spare capacity with zeros. The length is unchanged.
+    pub fn wipe_limbs(limbs: &mut Vec<u64>) {
+        let cap = limbs.capacity();
+        wipe_range(limbs, 0, cap);
+    }
+
+    /// Overwrite the spare capacity (the slots between `len` and `capacity`) with zeros.
+    pub fn wipe_spare_limbs(limbs: &mut Vec<u64>) {
+        let (len, cap) = (limbs.len(), limbs.capacity());
+        wipe_range(limbs, len, cap);
+    }
+}
+
+pub struct SecretMarInt {
+    inner: MarInt,
+}
+
+impl SecretMarInt {
+    /// Take ownership of `value` without copying its limbs.
+    pub fn new(value: MarInt) -> Self {
+        let mut s = Self { inner: value };
+        s.normalize();
+        s
+    }
+
+    /// Parse a hexadecimal secret; the input string is not wiped.
+    pub fn from_hex(s: &str) -> Result<Self, crate::io::ParseMarIntError> {
+        MarInt::from_hex(s).map(Self::new)
+    }
+
+    /// Borrow the secret value for arithmetic.
+    pub fn expose_secret(&self) -> &MarInt {
+        &self.inner
+    }
+
+    /// Trim high zero limbs and wipe the slots freed by the truncation.
+    pub fn normalize(&mut self) {
+        self.inner.normalize();
+        MarInt::wipe_spare_limbs(&mut self.inner.limbs);
+    }
+
+    /// Wipe the secret now and leave zero behind.
+    pub fn zeroize(&mut self) {
+        MarInt::wipe_limbs(&mut self.inner.limbs);
+        self.inner.limbs.truncate(1);
+        self.inner.sign = crate::MZero;
+    }
+}
+
+impl From<MarInt> for SecretMarInt {
+    fn from(value: MarInt) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Clone for SecretMarInt {
+    /// The copy is a `SecretMarInt` too, so it is wiped on drop as well.
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl Drop for SecretMarInt {
+    fn drop(&mut self) {
+        MarInt::wipe_limbs(&mut self.inner.limbs);
+    }
+}
+
+impl fmt::Debug for SecretMarInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretMarInt([REDACTED])")
+    }
+}
@@ -1,9 +1,13 @@
 mod unittest {
     mod test_basic_ops;
+    mod test_batch_gcd;
+    mod test_crt;
+    mod test_factor;
     mod test_large_number_ops;
     mod test_longdiv_limbs;
     mod test_longdiv_ops;
     mod test_ops_ref_add_sub;
+    mod test_secret;
     mod test_shortdiv_limbs;
     mod test_small_number_ops;
 }
@@ -0,0 +1,157 @@
+use std::process::Command;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use marint::batchgcd::{product_tree, remainder_tree};
+use marint::{MarInt, batch_gcd};
+
+const MODULI_FIXTURE: &str = include_str!("../data/batchgcd_moduli.txt");
+
+// Primes deliberately shared inside the fixture.
+const P0: &str = "f5ff4a29f8f0f0a01c8dc94785d25577";
+const P8: &str = "f7014182430bd04274e45efbd765b60b";
+
+fn load_fixture() -> Vec<MarInt> {
+    MODULI_FIXTURE
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| MarInt::from_hex(l).expect("fixture must be valid hex"))
+        .collect()
+}
+
+fn rand_positive_marint(rng: &mut StdRng, n_limbs: usize) -> MarInt {
+    let mut limbs = (0..n_limbs).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    limbs[0] |= 1;
+    let mut x = MarInt {
+        sign: marint::MPos,
+        limbs,
+    };
+    x.normalize();
+    x
+}
+
+#[test]
+fn hex_roundtrip() {
+    for s in ["0", "1", "ff", "10000000000000000", "-deadbeef", P0, P8] {
+        let x = MarInt::from_hex(s).unwrap();
+        assert_eq!(format!("{:x}", x), s);
+    }
+
+    let x = MarInt::from_hex("0xFFFF_FFFF".replace('_', "").as_str()).unwrap();
+    assert_eq!(x, MarInt::from_u64(0xffff_ffff));
+    assert_eq!(format!("{:#X}", x), "0xFFFFFFFF");
+    assert_eq!(
+        MarInt::from_hex("0000000000000000000000ab").unwrap(),
+        MarInt::from_u64(0xab)
+    );
+    assert!(MarInt::from_hex("").is_err());
+    assert!(MarInt::from_hex("0x").is_err());
+    assert!(MarInt::from_hex("12g4").is_err());
+}
+
+#[test]
+fn ordering_is_signed() {
+    let a = MarInt::from_i128(-5);
+    let b = MarInt::from_i128(3);
+    let c = MarInt::from_i128(-700);
+    assert!(c < a && a < MarInt::zero() && MarInt::zero() < b);
+    assert_eq!(MarInt::from_i128(-5), a);
+    assert_ne!(a, a.abs());
+}
+
+#[test]
+fn gcd_small_known_values() {
+    let cases: &[(i128, i128, i128)] = &[
+        (0, 0, 0),
+        (0, 7, 7),
+        (7, 0, 7),
+        (12, 18, 6),
+        (-12, 18, 6),
+        (17, 31, 1),
+        (1 << 100, 1 << 70, 1 << 70),
+        (7 << 100, 21, 7),
+        (i128::MAX, 7, 1),
+    ];
+    for &(a, b, g) in cases {
+        let got = MarInt::from_i128(a).gcd(&MarInt::from_i128(b));
+        assert_eq!(got, MarInt::from_i128(g), "gcd({a}, {b})");
+    }
+}
+
+#[test]
+fn gcd_many_limb_common_factor() {
+    let mut rng = StdRng::seed_from_u64(0x6CD0_0026);
+    for &n in &[1usize, 2, 3, 5, 8] {
+        for _ in 0..20 {
+            let a = rand_positive_marint(&mut rng, n);
+            let b = rand_positive_marint(&mut rng, n + 1);
+            let c = rand_positive_marint(&mut rng, 2);
+            let g = (&a * &c).gcd(&(&b * &c));
+            assert!((&g % &c).is_zero(), "common factor must divide the gcd");
+            assert!((&(&a * &c) % &g).is_zero());
+            assert!((&(&b * &c) % &g).is_zero());
+        }
+    }
+}
+
+#[test]
+fn product_and_remainder_trees() {
+    let moduli = (3u64..10).map(MarInt::from_u64).collect::<Vec<_>>();
+    let tree = product_tree(&moduli);
+    assert_eq!(tree[0].len(), moduli.len());
+    assert_eq!(tree.last().unwrap(), &vec![MarInt::from_u64(181_440)]);
+
+    let rems = remainder_tree(&tree);
+    for (n, r) in moduli.iter().zip(rems.iter()) {
+        assert_eq!(r, &(&MarInt::from_u64(181_440) % &(n * n)));
+    }
+
+    assert!(product_tree(&[]).is_empty());
+    assert!(batch_gcd(&[]).is_empty());
+}
+
+#[test]
+fn batch_gcd_finds_shared_primes_in_fixture() {
+    let moduli = load_fixture();
+    assert_eq!(moduli.len(), 9);
+
+    let gcds = batch_gcd(&moduli);
+    let p0 = MarInt::from_hex(P0).unwrap();
+    let p8 = MarInt::from_hex(P8).unwrap();
+
+    for (i, g) in gcds.iter().enumerate() {
+        match i {
+            0 | 2 => assert_eq!(g, &p0, "key {i}"),
+            4 | 6 => assert_eq!(g, &p8, "key {i}"),
+            7 | 8 => assert_eq!(g, &moduli[i], "key {i}"),
+            _ => assert!(g.is_one(), "key {i} should not be vulnerable"),
+        }
+    }
+}
+
+#[test]
+fn batch_gcd_single_modulus_is_safe() {
+    let n = MarInt::from_hex(P0).unwrap();
+    assert!(batch_gcd(&[n])[0].is_one());
+}
+
+#[test]
+fn batchgcd_binary_reports_vulnerable_keys() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/batchgcd_moduli.txt"
+    );
+    let out = Command::new(env!("CARGO_BIN_EXE_batchgcd"))
+        .arg(path)
+        .output()
+        .expect("batchgcd should run");
+    assert!(out.status.success());
+
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains(&format!("shares factor {}", P0)));
+    assert!(stdout.contains(&format!("shares factor {}", P8)));
+    assert!(stdout.contains("duplicate modulus"));
+    assert!(stdout.contains("9 moduli checked, 6 vulnerable"));
+}
@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+
+use marint::crt::CrtGarner;
+use marint::{MPos, MarInt};
+
+fn mi(v: i128) -> MarInt {
+    MarInt::from_i128(v)
+}
+
+fn congruences(pairs: &[(i128, i128)]) -> Vec<(MarInt, MarInt)> {
+    pairs.iter().map(|&(r, m)| (mi(r), mi(m))).collect()
+}
+
+fn rand_positive_marint(rng: &mut StdRng, n_limbs: usize) -> MarInt {
+    let mut x = MarInt {
+        sign: MPos,
+        limbs: (0..n_limbs).map(|_| rng.next_u64()).collect(),
+    };
+    x.limbs[0] |= 1;
+    x.normalize();
+    x
+}
+
+#[test]
+fn ext_gcd_bezout_identity() {
+    let mut rng = StdRng::seed_from_u64(0xC127_0028);
+    let mut cases = vec![
+        (mi(0), mi(0)),
+        (mi(0), mi(5)),
+        (mi(240), mi(46)),
+        (mi(-240), mi(46)),
+        (mi(240), mi(-46)),
+    ];
+    for _ in 0..50 {
+        let na = rng.gen_range(1..5);
+        let nb = rng.gen_range(1..5);
+        cases.push((
+            rand_positive_marint(&mut rng, na),
+            rand_positive_marint(&mut rng, nb),
+        ));
+    }
+
+    for (a, b) in cases {
+        let (g, x, y) = MarInt::ext_gcd(&a, &b);
+        assert_eq!(g, a.gcd(&b));
+        assert_eq!(&(&a * &x) + &(&b * &y), g, "a = {a:?}, b = {b:?}");
+    }
+}
+
+#[test]
+fn mod_inverse_known_values() {
+    assert_eq!(mi(3).mod_inverse(&mi(11)), Some(mi(4)));
+    assert_eq!(mi(-3).mod_inverse(&mi(11)), Some(mi(7)));
+    assert_eq!(mi(10).mod_inverse(&mi(17)), Some(mi(12)));
+    assert_eq!(mi(6).mod_inverse(&mi(9)), None);
+
+    let m = MarInt::from_u128((1 << 127) - 1);
+    let a = mi(0x1234_5678_9abc_def0_1122_3344);
+    let inv = a.mod_inverse(&m).unwrap();
+    assert!(MarInt::mul_mod(&a, &inv, &m).is_one());
+}
+
+#[test]
+fn crt_coprime_moduli() {
+    let (x, m) = MarInt::crt(&congruences(&[(2, 3), (3, 5), (2, 7)])).unwrap();
+    assert_eq!((x, m), (mi(23), mi(105)));
+
+    // residues outside [0, m) and negative residues are reduced
+    let (x, m) = MarInt::crt(&congruences(&[(-1, 4), (10, 9)])).unwrap();
+    assert_eq!((x, m), (mi(19), mi(36)));
+}
+
+#[test]
+fn crt_non_coprime_moduli() {
+    let (x, m) = MarInt::crt(&congruences(&[(2, 6), (8, 10)])).unwrap();
+    assert_eq!((x, m), (mi(8), mi(30)));
+
+    let (x, m) = MarInt::crt(&congruences(&[(3, 4), (3, 6), (3, 8)])).unwrap();
+    assert_eq!((x, m), (mi(3), mi(24)));
+}
+
+#[test]
+fn crt_reports_inconsistency_and_bad_moduli() {
+    assert!(MarInt::crt(&congruences(&[(1, 4), (2, 6)])).is_none());
+    assert!(MarInt::crt(&congruences(&[(1, 0)])).is_none());
+    assert!(MarInt::crt(&congruences(&[(1, -5)])).is_none());
+    assert_eq!(MarInt::crt(&[]), Some((mi(0), mi(1))));
+}
+
+#[test]
+fn crt_hastad_broadcast_recovers_cube() {
+    // the same message sent to three receivers with e = 3
+    let moduli = [
+        mi(0xf5ff_4a29_f8f0_f0a1) * mi(0xc5ce_5417_fc92_e5e9),
+        mi(0x8eb5_fe66_cbf7_e68f) * mi(0xb004_9d18_dd77_d2f3),
+        mi(0xbab3_df54_dc67_db35) * mi(0x8af3_e759_f824_6569),
+    ];
+    let msg = mi(0x5365_6372_6574_2121); // "Secret!!"
+    let cube = &(&msg * &msg) * &msg;
+
+    let system = moduli
+        .iter()
+        .map(|n| (&cube % n, n.clone()))
+        .collect::<Vec<_>>();
+    let (x, _) = MarInt::crt(&system).unwrap();
+    assert_eq!(x, cube, "m^3 < n1*n2*n3, so CRT returns it exactly");
+}
+
+#[test]
+fn garner_matches_crt() {
+    let mut rng = StdRng::seed_from_u64(0x6A41_0028);
+    let moduli = [
+        mi(1_000_003),
+        mi(998_244_353),
+        mi((1 << 61) - 1),
+        mi(65_537),
+    ];
+    let garner = CrtGarner::new(&moduli).unwrap();
+    assert_eq!(
+        garner.modulus(),
+        &moduli.iter().fold(MarInt::one(), |acc, m| &acc * m)
+    );
+
+    for _ in 0..50 {
+        let residues = moduli
+            .iter()
+            .map(|m| &rand_positive_marint(&mut rng, 2) % m)
+            .collect::<Vec<_>>();
+        let system = residues
+            .iter()
+            .cloned()
+            .zip(moduli.iter().cloned())
+            .collect::<Vec<_>>();
+
+        let x = garner.combine(&residues);
+        assert_eq!(
+            Some((x.clone(), garner.modulus().clone())),
+            MarInt::crt(&system)
+        );
+        for (r, m) in residues.iter().zip(moduli.iter()) {
+            assert_eq!(&x % m, *r);
+        }
+    }
+}
+
+#[test]
+fn garner_rejects_non_coprime_moduli() {
+    assert!(CrtGarner::new(&[mi(6), mi(35), mi(10)]).is_none());
+    assert!(CrtGarner::new(&[mi(6), mi(0)]).is_none());
+}
+
+#[test]
+fn garner_rsa_crt_decryption() {
+    let p = mi(0xe56d_90d7_c2f9_cfc5);
+    let q = mi(0xa8c5_abff_6d30_ef09);
+    let n = &p * &q;
+    let e = mi(65_537);
+    let phi = &(&p - &MarInt::one()) * &(&q - &MarInt::one());
+    let d = e.mod_inverse(&phi).unwrap();
+
+    let m = mi(0x0123_4567_89ab_cdef_0011);
+    let c = m.pow_mod(&e, &n);
+
+    let dp = d.rem_euclid(&(&p - &MarInt::one()));
+    let dq = d.rem_euclid(&(&q - &MarInt::one()));
+    let garner = CrtGarner::new(&[p.clone(), q.clone()]).unwrap();
+    let decrypted = garner.combine(&[c.pow_mod(&dp, &p), c.pow_mod(&dq, &q)]);
+
+    assert_eq!(decrypted, m);
+    assert_eq!(decrypted, c.pow_mod(&d, &n));
+}
+
+#[test]
+#[should_panic(expected = "expected 2 residues")]
+fn garner_combine_panics_on_len_mismatch() {
+    let garner = CrtGarner::new(&[mi(3), mi(5)]).unwrap();
+    let _ = garner.combine(&[mi(1)]);
+}
@@ -0,0 +1,163 @@
+use marint::MarInt;
+use marint::factor::{
+    ecm, ecm_curve, factorize, pollard_pm1, pollard_rho_brent, trial_division, williams_pp1,
+};
+use marint::prime::{is_probable_prime, small_primes};
+
+// Semiprimes of 98-100 bits, each built for one method:
+// - RHO: a 28-bit prime times a 70-bit prime
+// - PM1: p - 1 is 1000-smooth
+// - PP1: p + 1 is 1000-smooth and 7^2 - 4 is a non-residue mod p
+// - ECM: a 36-bit prime times a 64-bit prime; sigma = 11 finds it with B1 = 2000
+// Apart from the special prime, p - 1 and p + 1 of every factor have a prime factor > 2^24.
+const RHO: (u128, u128) = (167_966_317, 988_999_464_248_520_187_037);
+const PM1: (u128, u128) = (886_519_857_392_363, 1_090_504_007_271_517);
+const PP1: (u128, u128) = (686_293_128_271_193, 564_202_417_061_219);
+const ECM: (u128, u128) = (62_634_016_013, 12_794_334_253_724_660_569);
+
+fn semiprime((p, q): (u128, u128)) -> MarInt {
+    MarInt::from_u128(p * q)
+}
+
+fn assert_divides_into(d: Option<MarInt>, (p, q): (u128, u128)) {
+    let d = d.expect("a divisor should be found");
+    assert!(
+        d == MarInt::from_u128(p) || d == MarInt::from_u128(q),
+        "unexpected divisor {d:?}"
+    );
+}
+
+fn factors_u128(n: &MarInt) -> Vec<(u128, u32)> {
+    factorize(n)
+        .into_iter()
+        .map(|(p, e)| {
+            let limbs = &p.limbs;
+            let v = limbs[0] as u128 | (limbs.get(1).copied().unwrap_or(0) as u128) << 64;
+            (v, e)
+        })
+        .collect()
+}
+
+#[test]
+fn small_primes_sieve() {
+    assert!(small_primes(1).is_empty());
+    assert_eq!(small_primes(2), vec![2]);
+    assert_eq!(small_primes(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(small_primes(10_000).len(), 1229);
+}
+
+#[test]
+fn primality_known_values() {
+    let primes: &[u128] = &[
+        2,
+        3,
+        41,
+        43,
+        65_537,
+        (1 << 61) - 1,
+        (1 << 89) - 1,
+        (1 << 127) - 1,
+        PM1.0,
+        ECM.1,
+    ];
+    for &p in primes {
+        assert!(is_probable_prime(&MarInt::from_u128(p)), "{p} is prime");
+    }
+
+    // Carmichael numbers and strong pseudoprimes to small bases
+    let composites: &[u128] = &[
+        0,
+        1,
+        4,
+        1_849,
+        561,
+        41_041,
+        3_215_031_751,
+        3_825_123_056_546_413_051,
+        (1 << 67) - 1,
+        RHO.0 * RHO.1,
+    ];
+    for &n in composites {
+        assert!(
+            !is_probable_prime(&MarInt::from_u128(n)),
+            "{n} is composite"
+        );
+    }
+    assert!(!is_probable_prime(&MarInt::from_i128(-7)));
+}
+
+#[test]
+fn trial_division_strips_small_factors() {
+    let n = MarInt::from_u128(2u128.pow(5) * 3u128.pow(2) * 4_093 * RHO.0);
+    let (small, rest) = trial_division(&n, 4_096);
+    let small = small
+        .into_iter()
+        .map(|(p, e)| (p.to_u64().unwrap(), e))
+        .collect::<Vec<_>>();
+    assert_eq!(small, vec![(2, 5), (3, 2), (4_093, 1)]);
+    assert_eq!(rest, MarInt::from_u128(RHO.0));
+
+    let (small, rest) = trial_division(&MarInt::from_u64(1), 100);
+    assert!(small.is_empty());
+    assert!(rest.is_one());
+}
+
+#[test]
+fn pollard_rho_brent_finds_28_bit_factor() {
+    let n = semiprime(RHO);
+    assert_divides_into(pollard_rho_brent(&n, 1, 1 << 20), RHO);
+    assert_eq!(pollard_rho_brent(&n, 1, 16), None);
+}
+
+#[test]
+fn pollard_pm1_finds_smooth_factor() {
+    let n = semiprime(PM1);
+    assert_eq!(pollard_pm1(&n, 1_000), Some(MarInt::from_u128(PM1.0)));
+    assert_eq!(pollard_pm1(&semiprime(ECM), 1_000), None);
+}
+
+#[test]
+fn williams_pp1_finds_smooth_factor() {
+    let n = semiprime(PP1);
+    assert_eq!(williams_pp1(&n, 7, 1_000), Some(MarInt::from_u128(PP1.0)));
+    assert_eq!(pollard_pm1(&n, 1_000), None);
+}
+
+#[test]
+fn ecm_finds_36_bit_factor() {
+    let n = semiprime(ECM);
+    assert_eq!(ecm_curve(&n, 11, 2_000), Some(MarInt::from_u128(ECM.0)));
+    assert_divides_into(ecm(&n, 2_000, 10, 6), ECM);
+}
+
+#[test]
+fn factorize_small_values() {
+    assert!(factorize(&MarInt::zero()).is_empty());
+    assert!(factorize(&MarInt::one()).is_empty());
+    assert_eq!(factors_u128(&MarInt::from_u64(97)), vec![(97, 1)]);
+    assert_eq!(
+        factors_u128(&MarInt::from_i128(-360)),
+        vec![(2, 3), (3, 2), (5, 1)]
+    );
+    assert_eq!(
+        factors_u128(&MarInt::from_u128(2u128.pow(64))),
+        vec![(2, 64)]
+    );
+}
+
+#[test]
+fn factorize_semiprimes_up_to_100_bits() {
+    for case in [RHO, PM1, PP1, ECM] {
+        let n = semiprime(case);
+        let (p, q) = (case.0.min(case.1), case.0.max(case.1));
+        assert_eq!(factors_u128(&n), vec![(p, 1), (q, 1)], "n = {}", p * q);
+    }
+}
+
+#[test]
+fn factorize_with_repeated_large_primes() {
+    // p^2 * q with 28-bit p and 36-bit q, times a few small primes
+    let (p, q) = (RHO.0, ECM.0);
+    let n = MarInt::from_u128(12 * p * p) * MarInt::from_u128(q);
+    assert_eq!(factors_u128(&n), vec![(2, 2), (3, 1), (p, 2), (q, 1)]);
+}
@@ -325,7 +325,7 @@ fn div_rem_euclid_small_known_values() {
 
 #[test]
 fn div_rem_euclid_many_limb_random_stress() {
-    let mut rng = StdRng::seed_from_u64(0xEUC11D_7777_1234);
+    let mut rng = StdRng::seed_from_u64(0xE0C11D_7777_1234);
     let limb_sizes = [1usize, 2, 3, 4, 8, 16, 32];
 
     for &na in &limb_sizes {
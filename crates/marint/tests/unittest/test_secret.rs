@@ -0,0 +1,89 @@
+use marint::{MarInt, SecretMarInt};
+
+// A private key shaped like the ones that should hold their fields as SecretMarInt.
+#[derive(Debug)]
+struct ToyRsaPrivateKey {
+    #[allow(dead_code)]
+    n: MarInt,
+    d: SecretMarInt,
+    p: SecretMarInt,
+    q: SecretMarInt,
+}
+
+fn toy_key() -> ToyRsaPrivateKey {
+    let p = MarInt::from_u64(0xe56d_90d7_c2f9_cfc5);
+    let q = MarInt::from_u64(0xa8c5_abff_6d30_ef09);
+    let phi = &(&p - &MarInt::one()) * &(&q - &MarInt::one());
+    let d = MarInt::from_u64(65_537).mod_inverse(&phi).unwrap();
+    ToyRsaPrivateKey {
+        n: &p * &q,
+        d: d.into(),
+        p: p.into(),
+        q: q.into(),
+    }
+}
+
+#[test]
+fn debug_output_is_redacted() {
+    let secret = SecretMarInt::from_hex("deadbeefcafebabe").unwrap();
+    assert_eq!(format!("{:?}", secret), "SecretMarInt([REDACTED])");
+
+    let key = toy_key();
+    let dbg = format!("{:?}", key);
+    assert!(dbg.contains("d: SecretMarInt([REDACTED])"));
+    assert!(!dbg.contains(&format!("{:?}", key.p.expose_secret().limbs)));
+}
+
+#[test]
+fn secret_arithmetic_through_expose() {
+    let key = toy_key();
+    let n = key.p.expose_secret() * key.q.expose_secret();
+    let m = MarInt::from_u64(0x1234_5678);
+    let c = m.pow_mod(&MarInt::from_u64(65_537), &n);
+    assert_eq!(c.pow_mod(key.d.expose_secret(), &n), m);
+
+    let copy = key.d.clone();
+    assert_eq!(copy.expose_secret(), key.d.expose_secret());
+}
+
+#[test]
+fn new_normalizes_and_wipes_spare_limbs() {
+    let mut limbs = Vec::with_capacity(8);
+    limbs.extend_from_slice(&[7, 9, 0, 0]);
+    let ptr = limbs.as_ptr();
+    let secret = SecretMarInt::new(MarInt {
+        sign: marint::MPos,
+        limbs,
+    });
+
+    let value = secret.expose_secret();
+    assert_eq!(value.limbs, vec![7, 9]);
+    assert_eq!(value.limbs.as_ptr(), ptr, "limbs must be moved, not copied");
+    // SAFETY: new wiped, and so initialized, every slot past len
+    let all = unsafe { std::slice::from_raw_parts(ptr, value.limbs.capacity()) };
+    assert_eq!(all.len(), 8);
+    assert!(all[2..].iter().all(|&x| x == 0));
+}
+
+#[test]
+fn wipe_limbs_clears_len_and_spare_capacity() {
+    let mut limbs: Vec<u64> = vec![u64::MAX; 6];
+    limbs.truncate(2);
+
+    MarInt::wipe_spare_limbs(&mut limbs);
+    assert_eq!(limbs, vec![u64::MAX, u64::MAX]);
+    // SAFETY: wipe_spare_limbs initialized every spare slot
+    let spare = unsafe { std::slice::from_raw_parts(limbs.as_ptr(), limbs.capacity()) };
+    assert!(spare[2..].iter().all(|&x| x == 0));
+
+    MarInt::wipe_limbs(&mut limbs);
+    assert_eq!(limbs, vec![0, 0]);
+}
+
+#[test]
+fn zeroize_leaves_zero() {
+    let mut secret = SecretMarInt::from_hex("0123456789abcdef0123456789abcdef").unwrap();
+    secret.zeroize();
+    assert!(secret.expose_secret().is_zero());
+    assert_eq!(secret.expose_secret().limbs, vec![0]);
+}
@@ -1,7 +1,10 @@
 use std::hint::black_box;
 
-use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
-use marnd::{Lcg64, MPCfg, MPRng};
+use criterion::measurement::WallTime;
+use criterion::{
+    BenchmarkGroup, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main,
+};
+use marnd::{Lcg64, MBitGen, MPCfg, MPRng, Xoshiro256PlusPlus};
 
 fn build_rng() -> MPRng {
     let mut cfg = MPCfg::new();
@@ -95,6 +98,186 @@ fn bench_u64_to_f64_convert_only(c: &mut Criterion) {
     group.finish();
 }
 
+/// One deviate per call, widened to f64 so all methods share a signature.
+type Sampler = fn(&mut MPRng) -> f64;
+
+fn bench_normal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mprng_normal");
+    let methods: [(&str, Sampler); 4] = [
+        ("box_muller", MPRng::norm_box_muller),
+        ("marsaglia_polar", MPRng::norm_marsaglia_polar),
+        ("ziggurat", MPRng::standard_normal),
+        ("ziggurat_f32", |rng| rng.standard_normal_f32() as f64),
+    ];
+    for &batch in &[1_024usize, 16_384, 262_144] {
+        group.throughput(Throughput::Elements(batch as u64));
+        for (name, sample) in methods {
+            group.bench_with_input(BenchmarkId::new(name, batch), &batch, |b, &n| {
+                let mut rng = build_rng();
+                b.iter(|| {
+                    let mut acc = 0.0f64;
+                    for _ in 0..n {
+                        acc += sample(&mut rng);
+                    }
+                    black_box(acc);
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_exponential(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mprng_exponential");
+    let methods: [(&str, Sampler); 3] = [
+        ("inversion", |rng| -(-rng.next_f64()).ln_1p()),
+        ("ziggurat", MPRng::standard_exponential),
+        ("ziggurat_f32", |rng| rng.standard_exponential_f32() as f64),
+    ];
+    for &batch in &[1_024usize, 16_384, 262_144] {
+        group.throughput(Throughput::Elements(batch as u64));
+        for (name, sample) in methods {
+            group.bench_with_input(BenchmarkId::new(name, batch), &batch, |b, &n| {
+                let mut rng = build_rng();
+                b.iter(|| {
+                    let mut acc = 0.0f64;
+                    for _ in 0..n {
+                        acc += sample(&mut rng);
+                    }
+                    black_box(acc);
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Single calls against the `fill_*` bulk calls, which make one virtual call per block.
+fn bench_fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mprng_fill");
+    let n = 16_384usize;
+    group.throughput(Throughput::Elements(n as u64));
+    for schema in ["Lcg64::DK", "Xoshiro256PlusPlus", "Xoshiro256PlusPlusX4"] {
+        let mut cfg = MPCfg::new();
+        cfg.insert("schema", schema);
+        cfg.insert("seed", "42");
+        let mut rng = MPRng::build(&cfg).expect("MPRng::build should succeed");
+        let mut words = vec![0u64; n];
+        let mut floats = vec![0f64; n];
+        let mut ints = vec![0u32; n];
+
+        group.bench_function(BenchmarkId::new(format!("{schema}/u64_loop"), n), |b| {
+            b.iter(|| words.iter_mut().for_each(|x| *x = rng.next_u64()))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/fill_u64"), n), |b| {
+            b.iter(|| rng.fill_u64(black_box(&mut words)))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/f64_loop"), n), |b| {
+            b.iter(|| floats.iter_mut().for_each(|x| *x = rng.next_f64()))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/fill_f64"), n), |b| {
+            b.iter(|| rng.fill_f64(black_box(&mut floats)))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/normal_loop"), n), |b| {
+            b.iter(|| floats.iter_mut().for_each(|x| *x = rng.standard_normal()))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/fill_normal"), n), |b| {
+            b.iter(|| rng.fill_normal(black_box(&mut floats)))
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/range_loop"), n), |b| {
+            b.iter(|| {
+                ints.iter_mut()
+                    .for_each(|x| *x = rng.gen_range_u32(0..1000).unwrap())
+            })
+        });
+        group.bench_function(BenchmarkId::new(format!("{schema}/fill_range"), n), |b| {
+            b.iter(|| rng.fill_range_u32(0..1000, black_box(&mut ints)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// The same draws through `MPRng<G>` for `G = Box<dyn MBitGen>` and for the engine itself.
+fn bench_draws<G: MBitGen>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    label: &str,
+    rng: &mut MPRng<G>,
+    n: usize,
+) {
+    group.bench_function(BenchmarkId::new(format!("{label}/u64"), n), |b| {
+        b.iter(|| {
+            let mut acc = 0u64;
+            for _ in 0..n {
+                acc ^= rng.next_u64();
+            }
+            black_box(acc);
+        })
+    });
+    group.bench_function(BenchmarkId::new(format!("{label}/f64"), n), |b| {
+        b.iter(|| {
+            let mut acc = 0.0f64;
+            for _ in 0..n {
+                acc += rng.next_f64();
+            }
+            black_box(acc);
+        })
+    });
+    group.bench_function(BenchmarkId::new(format!("{label}/normal"), n), |b| {
+        b.iter(|| {
+            let mut acc = 0.0f64;
+            for _ in 0..n {
+                acc += rng.standard_normal();
+            }
+            black_box(acc);
+        })
+    });
+    group.bench_function(BenchmarkId::new(format!("{label}/range"), n), |b| {
+        b.iter(|| {
+            let mut acc = 0u32;
+            for _ in 0..n {
+                acc ^= rng.gen_range_u32(0..1000).unwrap();
+            }
+            black_box(acc);
+        })
+    });
+}
+
+/// `DynMPRng` against `MPRng<Engine>`, where the engine calls inline into the samplers.
+fn bench_dyn_vs_static(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mprng_dispatch");
+    let n = 16_384usize;
+    group.throughput(Throughput::Elements(n as u64));
+
+    let lcg = Lcg64::from_preset("DK", 42).expect("Lcg64 preset should exist");
+    bench_draws(
+        &mut group,
+        "Lcg64::DK/dyn",
+        &mut MPRng::new(lcg.clone(), "Lcg64").into_dyn(),
+        n,
+    );
+    bench_draws(
+        &mut group,
+        "Lcg64::DK/static",
+        &mut MPRng::new(lcg, "Lcg64"),
+        n,
+    );
+
+    let xoshiro = Xoshiro256PlusPlus::new(42);
+    bench_draws(
+        &mut group,
+        "Xoshiro256PlusPlus/dyn",
+        &mut MPRng::new(xoshiro.clone(), "Xoshiro256PlusPlus").into_dyn(),
+        n,
+    );
+    bench_draws(
+        &mut group,
+        "Xoshiro256PlusPlus/static",
+        &mut MPRng::new(xoshiro, "Xoshiro256PlusPlus"),
+        n,
+    );
+    group.finish();
+}
+
 fn config() -> Criterion {
     Criterion::default().sample_size(30)
 }
@@ -102,6 +285,7 @@ fn config() -> Criterion {
 criterion_group! {
     name = benches;
     config = config();
-    targets = bench_u64, bench_lcg64_u64_direct, bench_f64, bench_u64_to_f64_convert_only
+    targets = bench_u64, bench_lcg64_u64_direct, bench_f64, bench_u64_to_f64_convert_only,
+        bench_normal, bench_exponential, bench_fill, bench_dyn_vs_static
 }
 criterion_main!(benches);
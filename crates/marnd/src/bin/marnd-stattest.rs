@@ -0,0 +1,77 @@
+//! Run the `marnd::stattest` battery on a generator and report p-values.
+//!
+//! Usage: `marnd-stattest --schema <SCHEMA> [--seed N] [--shift R] [--width S] [--scale K]
+//! [--alpha A]`. `--shift`/`--width` test only bits `R..R+S` of each output, `--scale`
+//! multiplies the test sizes and `--alpha` (default 0.001) sets the two-sided failure
+//! threshold. Any other `--key value` pair goes to the engine config, e.g. `--stream 3`.
+//! Exits with status 1 when a test fails.
+use marnd::stattest::{BitStream, battery};
+use marnd::{MPCfg, MPRng};
+use std::process::ExitCode;
+
+const USAGE: &str = "--schema <SCHEMA> [--seed N] [--shift R] [--width S] [--scale K] [--alpha A]";
+
+fn parse<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{value}' for --{key}"))
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    let mut cfg = MPCfg::new();
+    let (mut shift, mut width, mut scale, mut alpha) = (0u32, 64u32, 1usize, 1e-3);
+    for pair in args.chunks(2) {
+        let key = pair[0]
+            .strip_prefix("--")
+            .ok_or_else(|| format!("unexpected argument '{}'", pair[0]))?;
+        let value = pair
+            .get(1)
+            .ok_or_else(|| format!("missing value for --{key}"))?;
+        match key {
+            "shift" => shift = parse(key, value)?,
+            "width" => width = parse(key, value)?,
+            "scale" => scale = parse(key, value)?,
+            "alpha" => alpha = parse(key, value)?,
+            _ => {
+                cfg.insert(key, value);
+            }
+        }
+    }
+    if cfg.get("schema").is_none() {
+        return Err("missing --schema".into());
+    }
+
+    let mut rng = MPRng::build(&cfg).map_err(|e| e.to_string())?;
+    let name = rng.bitgen.to_string();
+    let mut bits =
+        BitStream::with_slice(rng.bitgen.as_mut(), shift, width).map_err(|e| e.to_string())?;
+    println!("{} (bits {}..{})", name, shift, shift + width);
+    let results = battery(&mut bits, scale).map_err(|e| e.to_string())?;
+
+    let mut n_failed = 0;
+    for r in &results {
+        let passed = r.passed(alpha);
+        n_failed += usize::from(!passed);
+        println!("{}  {}", r, if passed { "ok" } else { "FAIL" });
+    }
+    println!(
+        "{} tests, {} failed at alpha = {}",
+        results.len(),
+        n_failed,
+        alpha
+    );
+    Ok(n_failed == 0)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    match run(&args[1..]) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            eprintln!("usage: {} {}", args[0], USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
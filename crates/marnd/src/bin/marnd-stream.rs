@@ -0,0 +1,87 @@
+//! Write raw generator output to stdout, for PractRand (`RNG_test stdin64`) or dieharder
+//! (`-g 200`).
+//!
+//! Usage: `marnd-stream --schema <SCHEMA> [--seed N] [--bytes N[K|M|G]]`. Output is
+//! little-endian `next_u64` words in 1 MiB blocks, until `--bytes` have been written or the
+//! reader closes the pipe. Any other `--key value` pair goes to the engine config, e.g.
+//! `--stream 3`.
+use marnd::{MPCfg, MPRng};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "--schema <SCHEMA> [--seed N] [--bytes N[K|M|G]]";
+const BLOCK_WORDS: usize = 1 << 17;
+
+/// A byte count with an optional binary suffix, `64K` = 65536.
+fn parse_bytes(value: &str) -> Result<u64, String> {
+    let (digits, shift) = match value.as_bytes().last() {
+        Some(b'K' | b'k') => (&value[..value.len() - 1], 10),
+        Some(b'M' | b'm') => (&value[..value.len() - 1], 20),
+        Some(b'G' | b'g') => (&value[..value.len() - 1], 30),
+        _ => (value, 0),
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(1 << shift))
+        .ok_or_else(|| format!("invalid value '{value}' for --bytes"))
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut cfg = MPCfg::new();
+    let mut limit = None;
+    for pair in args.chunks(2) {
+        let key = pair[0]
+            .strip_prefix("--")
+            .ok_or_else(|| format!("unexpected argument '{}'", pair[0]))?;
+        let value = pair
+            .get(1)
+            .ok_or_else(|| format!("missing value for --{key}"))?;
+        if key == "bytes" {
+            limit = Some(parse_bytes(value)?);
+        } else {
+            cfg.insert(key, value);
+        }
+    }
+    if cfg.get("schema").is_none() {
+        return Err("missing --schema".into());
+    }
+    let mut rng = MPRng::build(&cfg).map_err(|e| e.to_string())?;
+
+    let mut words = vec![0u64; BLOCK_WORDS];
+    let mut bytes = vec![0u8; 8 * BLOCK_WORDS];
+    let mut out = io::stdout().lock();
+    let mut left = limit.unwrap_or(u64::MAX);
+    while left > 0 {
+        let n = left.min(bytes.len() as u64) as usize;
+        let words = &mut words[..n.div_ceil(8)];
+        rng.bitgen.fill_u64(words);
+        for (chunk, w) in bytes.chunks_exact_mut(8).zip(words.iter()) {
+            chunk.copy_from_slice(&w.to_le_bytes());
+        }
+        match out.write_all(&bytes[..n]) {
+            Ok(()) => left -= n as u64,
+            // the reader has seen enough
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+            Err(e) => return Err(format!("cannot write to stdout: {e}")),
+        }
+    }
+    match out.flush() {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("cannot write to stdout: {e}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    match run(&args[1..]) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            eprintln!("usage: {} {}", args[0], USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! Counter-based generators from Random123 (Salmon et al., "Parallel Random Numbers: As Easy
+//! as 1, 2, 3"): Philox4x64-10 and Threefry4x64-20.
+//!
+//! Each output block is a keyed bijection of a 256-bit counter, so the block at any position
+//! is computed directly. The state layout follows numpy's `Philox`: the counter is
+//! incremented *before* a block is generated, and `buffer[buffer_pos..]` holds the outputs of
+//! the current block that were not handed out yet.
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use crate::{BitGenState, MBitGen, MRndErr, SeedSequence};
+use core::fmt;
+
+const PHILOX_M4X64_0: u64 = 0xd2e7_470e_e14c_6c93;
+const PHILOX_M4X64_1: u64 = 0xca5a_8263_9512_1157;
+const PHILOX_W64_0: u64 = 0x9e37_79b9_7f4a_7c15;
+const PHILOX_W64_1: u64 = 0xbb67_ae85_84ca_a73b;
+const PHILOX_ROUNDS: usize = 10;
+
+const THREEFRY_PARITY64: u64 = 0x1bd1_1bda_a9fc_1a22;
+const THREEFRY_ROTATIONS_4X64: [(u32, u32); 8] = [
+    (14, 16),
+    (52, 57),
+    (23, 40),
+    (5, 37),
+    (25, 33),
+    (46, 12),
+    (58, 22),
+    (32, 32),
+];
+const THREEFRY_ROUNDS: usize = 20;
+
+/// Outputs per block.
+const BLOCK_LEN: usize = 4;
+
+/* ---------------------------------------------------------------- */
+/* shared counter and buffer handling                               */
+/* ---------------------------------------------------------------- */
+
+/// `ctr += delta` on the 256-bit little-endian counter.
+fn ctr_add(ctr: &mut [u64; 4], delta: u128) {
+    // the low word of `carry` is added to the current counter word
+    let mut carry = delta;
+    for word in ctr.iter_mut() {
+        let sum = *word as u128 + (carry as u64) as u128;
+        *word = sum as u64;
+        carry = (carry >> 64) + (sum >> 64);
+    }
+}
+
+/// Skip `delta` outputs: use up the buffer, jump the counter over whole blocks and
+/// regenerate the block the new position falls in.
+fn advance_draws(
+    ctr: &mut [u64; 4],
+    buffer: &mut [u64; 4],
+    buffer_pos: &mut usize,
+    delta: u128,
+    block: impl Fn(&[u64; 4]) -> [u64; 4],
+) {
+    let left = (BLOCK_LEN - *buffer_pos) as u128;
+    if delta <= left {
+        *buffer_pos += delta as usize;
+        return;
+    }
+    let delta = delta - left;
+    let (blocks, rem) = (
+        delta / BLOCK_LEN as u128,
+        (delta % BLOCK_LEN as u128) as usize,
+    );
+    if rem == 0 {
+        ctr_add(ctr, blocks);
+        *buffer_pos = BLOCK_LEN;
+    } else {
+        ctr_add(ctr, blocks + 1);
+        *buffer = block(ctr);
+        *buffer_pos = rem;
+    }
+}
+
+#[inline(always)]
+fn next_from_block(
+    ctr: &mut [u64; 4],
+    buffer: &mut [u64; 4],
+    buffer_pos: &mut usize,
+    block: impl Fn(&[u64; 4]) -> [u64; 4],
+) -> u64 {
+    if *buffer_pos >= BLOCK_LEN {
+        ctr_add(ctr, 1);
+        *buffer = block(ctr);
+        *buffer_pos = 0;
+    }
+    let out = buffer[*buffer_pos];
+    *buffer_pos += 1;
+    out
+}
+
+/// Drain the buffer, then write whole blocks straight into `out`.
+fn fill_from_blocks(
+    ctr: &mut [u64; 4],
+    buffer: &mut [u64; 4],
+    buffer_pos: &mut usize,
+    out: &mut [u64],
+    block: impl Fn(&[u64; 4]) -> [u64; 4],
+) {
+    let head = (BLOCK_LEN - *buffer_pos).min(out.len());
+    out[..head].copy_from_slice(&buffer[*buffer_pos..*buffer_pos + head]);
+    *buffer_pos += head;
+
+    let mut chunks = out[head..].chunks_exact_mut(BLOCK_LEN);
+    for chunk in &mut chunks {
+        ctr_add(ctr, 1);
+        chunk.copy_from_slice(&block(ctr));
+    }
+    for x in chunks.into_remainder() {
+        *x = next_from_block(ctr, buffer, buffer_pos, &block);
+    }
+}
+
+/// `ctr += 2^128`, dropping the buffered outputs.
+fn jump_ctr(ctr: &mut [u64; 4], buffer_pos: &mut usize) {
+    ctr[2] = ctr[2].wrapping_add(1);
+    if ctr[2] == 0 {
+        ctr[3] = ctr[3].wrapping_add(1);
+    }
+    *buffer_pos = BLOCK_LEN;
+}
+
+fn fmt_cbrng(f: &mut fmt::Formatter<'_>, type_name: &str, ctr: &[u64], key: &[u64]) -> fmt::Result {
+    write!(f, "struct type: '{}' {{ ", type_name)?;
+    for (i, &c) in ctr.iter().enumerate() {
+        fmt_u64_dual(f, &format!("ctr{i}"), c)?;
+        write!(f, ", ")?;
+    }
+    for (i, &k) in key.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt_u64_dual(f, &format!("key{i}"), k)?;
+    }
+    write!(f, " }}")
+}
+
+/* ---------------------------------------------------------------- */
+/* Philox4x64-10                                                    */
+/* ---------------------------------------------------------------- */
+
+/// Philox4x64 with 10 rounds (numpy's `Philox`).
+#[derive(Debug, Clone)]
+pub struct Philox4x64 {
+    pub ctr: [u64; 4],
+    pub key: [u64; 2],
+    pub buffer: [u64; 4],
+    // BLOCK_LEN when the buffer is used up
+    pub buffer_pos: usize,
+}
+
+impl Philox4x64 {
+    /// Start at `ctr` with `key`; the first block generated is the one for `ctr + 1`.
+    pub fn new(ctr: [u64; 4], key: [u64; 2]) -> Self {
+        Self {
+            ctr,
+            key,
+            buffer: [0; 4],
+            buffer_pos: BLOCK_LEN,
+        }
+    }
+
+    /// Key from two u64 words of a `SeedSequence`, counter zero, like numpy's
+    /// `Philox(seed_seq)`.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let key = seq.generate_state_u64(2);
+        Self::new([0; 4], core::array::from_fn(|i| key[i]))
+    }
+
+    /// The Philox4x64-10 bijection.
+    pub fn block(ctr: &[u64; 4], key: &[u64; 2]) -> [u64; 4] {
+        let (mut c, mut k) = (*ctr, *key);
+        for round in 0..PHILOX_ROUNDS {
+            if round > 0 {
+                k[0] = k[0].wrapping_add(PHILOX_W64_0);
+                k[1] = k[1].wrapping_add(PHILOX_W64_1);
+            }
+            let p0 = PHILOX_M4X64_0 as u128 * c[0] as u128;
+            let p1 = PHILOX_M4X64_1 as u128 * c[2] as u128;
+            c = [
+                (p1 >> 64) as u64 ^ c[1] ^ k[0],
+                p1 as u64,
+                (p0 >> 64) as u64 ^ c[3] ^ k[1],
+                p0 as u64,
+            ];
+        }
+        c
+    }
+
+    /// Skip `delta` outputs in O(1).
+    pub fn advance(&mut self, delta: u128) {
+        let key = self.key;
+        advance_draws(
+            &mut self.ctr,
+            &mut self.buffer,
+            &mut self.buffer_pos,
+            delta,
+            |c| Self::block(c, &key),
+        );
+    }
+
+    /// Add `delta` to the counter and drop the buffered outputs, like numpy's
+    /// `Philox.advance(delta)`.
+    pub fn advance_counter(&mut self, delta: u128) {
+        ctr_add(&mut self.ctr, delta);
+        self.buffer_pos = BLOCK_LEN;
+    }
+
+    /// Add 2^128 to the counter (2^130 outputs) and drop the buffered outputs, like numpy's
+    /// `Philox.jumped()`.
+    pub fn jump(&mut self) {
+        jump_ctr(&mut self.ctr, &mut self.buffer_pos);
+    }
+}
+
+impl MBitGen for Philox4x64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let key = self.key;
+        next_from_block(&mut self.ctr, &mut self.buffer, &mut self.buffer_pos, |c| {
+            Self::block(c, &key)
+        })
+    }
+
+    /// Key `[seed, key[1]]` from counter zero; `key[1]` selects the stream.
+    fn reseed(&mut self, seed: u64) {
+        *self = Self::new([0; 4], [seed, self.key[1]]);
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        Philox4x64::advance(self, delta);
+        Ok(())
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Philox4x64::jump(self);
+        Ok(())
+    }
+
+    fn fill_u64(&mut self, out: &mut [u64]) {
+        let key = self.key;
+        fill_from_blocks(
+            &mut self.ctr,
+            &mut self.buffer,
+            &mut self.buffer_pos,
+            out,
+            |c| Self::block(c, &key),
+        );
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Philox4x64 {
+            ctr: self.ctr,
+            key: self.key,
+            buffer: self.buffer,
+            buffer_pos: self.buffer_pos,
+        })
+    }
+}
+
+/* ---------------------------------------------------------------- */
+/* Threefry4x64-20                                                  */
+/* ---------------------------------------------------------------- */
+
+/// Threefry4x64 with 20 rounds.
+#[derive(Debug, Clone)]
+pub struct Threefry4x64 {
+    pub ctr: [u64; 4],
+    pub key: [u64; 4],
+    pub buffer: [u64; 4],
+    // BLOCK_LEN when the buffer is used up
+    pub buffer_pos: usize,
+}
+
+impl Threefry4x64 {
+    /// Start at `ctr` with `key`; the first block generated is the one for `ctr + 1`.
+    pub fn new(ctr: [u64; 4], key: [u64; 4]) -> Self {
+        Self {
+            ctr,
+            key,
+            buffer: [0; 4],
+            buffer_pos: BLOCK_LEN,
+        }
+    }
+
+    /// Key from four u64 words of a `SeedSequence`, counter zero.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let key = seq.generate_state_u64(4);
+        Self::new([0; 4], core::array::from_fn(|i| key[i]))
+    }
+
+    /// The Threefry4x64-20 bijection.
+    pub fn block(ctr: &[u64; 4], key: &[u64; 4]) -> [u64; 4] {
+        let ks = [
+            key[0],
+            key[1],
+            key[2],
+            key[3],
+            THREEFRY_PARITY64 ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+        ];
+        let mut x: [u64; 4] = core::array::from_fn(|i| ctr[i].wrapping_add(ks[i]));
+
+        for round in 0..THREEFRY_ROUNDS {
+            let (r0, r1) = THREEFRY_ROTATIONS_4X64[round % 8];
+            // the word pairs alternate between (0,1)(2,3) and (0,3)(2,1)
+            let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+            x[0] = x[0].wrapping_add(x[a]);
+            x[a] = x[a].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[b]);
+            x[b] = x[b].rotate_left(r1) ^ x[2];
+
+            if round % 4 == 3 {
+                let s = (round + 1) / 4;
+                for (i, xi) in x.iter_mut().enumerate() {
+                    *xi = xi.wrapping_add(ks[(s + i) % 5]);
+                }
+                x[3] = x[3].wrapping_add(s as u64);
+            }
+        }
+        x
+    }
+
+    /// Skip `delta` outputs in O(1).
+    pub fn advance(&mut self, delta: u128) {
+        let key = self.key;
+        advance_draws(
+            &mut self.ctr,
+            &mut self.buffer,
+            &mut self.buffer_pos,
+            delta,
+            |c| Self::block(c, &key),
+        );
+    }
+
+    /// Add `delta` to the counter and drop the buffered outputs.
+    pub fn advance_counter(&mut self, delta: u128) {
+        ctr_add(&mut self.ctr, delta);
+        self.buffer_pos = BLOCK_LEN;
+    }
+
+    /// Add 2^128 to the counter (2^130 outputs) and drop the buffered outputs.
+    pub fn jump(&mut self) {
+        jump_ctr(&mut self.ctr, &mut self.buffer_pos);
+    }
+}
+
+impl MBitGen for Threefry4x64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let key = self.key;
+        next_from_block(&mut self.ctr, &mut self.buffer, &mut self.buffer_pos, |c| {
+            Self::block(c, &key)
+        })
+    }
+
+    /// Key `[seed, key[1], key[2], key[3]]` from counter zero; `key[1]` selects the stream.
+    fn reseed(&mut self, seed: u64) {
+        let key = self.key;
+        *self = Self::new([0; 4], [seed, key[1], key[2], key[3]]);
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        Threefry4x64::advance(self, delta);
+        Ok(())
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Threefry4x64::jump(self);
+        Ok(())
+    }
+
+    fn fill_u64(&mut self, out: &mut [u64]) {
+        let key = self.key;
+        fill_from_blocks(
+            &mut self.ctr,
+            &mut self.buffer,
+            &mut self.buffer_pos,
+            out,
+            |c| Self::block(c, &key),
+        );
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Threefry4x64 {
+            ctr: self.ctr,
+            key: self.key,
+            buffer: self.buffer,
+            buffer_pos: self.buffer_pos,
+        })
+    }
+}
+
+impl fmt::Display for Philox4x64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_cbrng(f, core::any::type_name::<Self>(), &self.ctr, &self.key)
+    }
+}
+
+impl fmt::Display for Threefry4x64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_cbrng(f, core::any::type_name::<Self>(), &self.ctr, &self.key)
+    }
+}
@@ -0,0 +1,263 @@
+//! ChaCha stream cipher (Bernstein) as a cryptographically secure bit generator.
+//!
+//! The layout follows rand_chacha: a 256-bit key, a 64-bit block counter in words 12-13 and a
+//! 64-bit stream id in words 14-15, and the keystream is read as little-endian u32 words.
+//! Given the same key, stream and word position, the outputs are identical to
+//! `rand_chacha::ChaCha{8,12,20}Rng`.
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use crate::{BitGenState, MBitGen, MOSEntropy, MRndErr, SeedSequence};
+use core::fmt;
+
+const BLOCK_WORDS: usize = 16;
+/// Blocks computed per refill of the output buffer.
+const BUF_BLOCKS: usize = 4;
+const BUF_WORDS: usize = BLOCK_WORDS * BUF_BLOCKS;
+
+/// "expand 32-byte k"
+const SIGMA: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// ChaCha with `ROUNDS` rounds; use the `ChaCha8Rng`, `ChaCha12Rng` and `ChaCha20Rng` aliases.
+///
+/// The key is not shown by `Debug` or `Display`.
+#[derive(Clone)]
+pub struct ChaChaRng<const ROUNDS: usize> {
+    key: [u32; 8],
+    stream: u64,
+    // block counter of buf[0]
+    buf_block: u64,
+    buf: [u32; BUF_WORDS],
+    // next word of buf to hand out; BUF_WORDS when the buffer is used up
+    index: usize,
+}
+
+pub type ChaCha8Rng = ChaChaRng<8>;
+pub type ChaCha12Rng = ChaChaRng<12>;
+pub type ChaCha20Rng = ChaChaRng<20>;
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+impl<const ROUNDS: usize> ChaChaRng<ROUNDS> {
+    /// Build from a 256-bit key (read as 8 little-endian words), stream 0, word position 0.
+    pub fn from_seed(key: [u8; 32]) -> Self {
+        assert!(
+            ROUNDS > 0 && ROUNDS.is_multiple_of(2),
+            "ChaChaRng: the number of rounds must be even"
+        );
+        Self {
+            key: Self::key_words(&key),
+            stream: 0,
+            buf_block: 0u64.wrapping_sub(BUF_BLOCKS as u64),
+            buf: [0; BUF_WORDS],
+            index: BUF_WORDS,
+        }
+    }
+
+    /// Expand a u64 into a key the way `rand_core::SeedableRng::seed_from_u64` does (PCG32),
+    /// so `seed_from_u64(s)` matches rand_chacha for the same `s`.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Self::expand_u64(seed))
+    }
+
+    /// Key from eight u32 words of a `SeedSequence`, read as little-endian.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let mut key = [0u8; 32];
+        for (chunk, w) in key.chunks_exact_mut(4).zip(seq.generate_state(8)) {
+            chunk.copy_from_slice(&w.to_le_bytes());
+        }
+        Self::from_seed(key)
+    }
+
+    /// Build with a fresh 256-bit key from `MOSEntropy::seed256`.
+    pub fn from_entropy() -> Result<Self, MRndErr> {
+        Ok(Self::from_seed(MOSEntropy::seed256()?))
+    }
+
+    fn key_words(key: &[u8; 32]) -> [u32; 8] {
+        core::array::from_fn(|i| u32::from_le_bytes(key[4 * i..4 * i + 4].try_into().unwrap()))
+    }
+
+    fn expand_u64(mut state: u64) -> [u8; 32] {
+        const MUL: u64 = 6_364_136_223_846_793_005;
+        const INC: u64 = 11_634_580_027_462_260_723;
+        let mut key = [0u8; 32];
+        for chunk in key.chunks_exact_mut(4) {
+            state = state.wrapping_mul(MUL).wrapping_add(INC);
+            let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+            let rot = (state >> 59) as u32;
+            chunk.copy_from_slice(&xorshifted.rotate_right(rot).to_le_bytes());
+        }
+        key
+    }
+
+    /// The 256-bit key, as passed to `from_seed`.
+    pub fn get_seed(&self) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        for (chunk, w) in key.chunks_exact_mut(4).zip(self.key) {
+            chunk.copy_from_slice(&w.to_le_bytes());
+        }
+        key
+    }
+
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Switch to another stream, keeping the word position.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        if self.index < BUF_WORDS {
+            self.set_word_pos(self.word_pos());
+        }
+    }
+
+    /// Position of the next keystream word (in u32 words) from the start of the stream.
+    pub fn word_pos(&self) -> u128 {
+        let block = self
+            .buf_block
+            .wrapping_add((self.index / BLOCK_WORDS) as u64);
+        (block as u128) * BLOCK_WORDS as u128 + (self.index % BLOCK_WORDS) as u128
+    }
+
+    /// Seek to a word position; only the low 68 bits are used (2^64 blocks of 16 words).
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.buf_block = (word_pos / BLOCK_WORDS as u128) as u64;
+        self.refill();
+        self.index = (word_pos % BLOCK_WORDS as u128) as usize;
+    }
+
+    /// Skip `delta` outputs of `next_u64` (two words each).
+    pub fn advance(&mut self, delta: u128) {
+        self.set_word_pos(self.word_pos().wrapping_add(delta.wrapping_mul(2)));
+    }
+
+    fn block(&self, counter: u64, out: &mut [u32]) {
+        let mut init = [0u32; BLOCK_WORDS];
+        init[..4].copy_from_slice(&SIGMA);
+        init[4..12].copy_from_slice(&self.key);
+        init[12] = counter as u32;
+        init[13] = (counter >> 32) as u32;
+        init[14] = self.stream as u32;
+        init[15] = (self.stream >> 32) as u32;
+
+        let mut x = init;
+        for _ in 0..ROUNDS / 2 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (o, (a, b)) in out.iter_mut().zip(x.iter().zip(init.iter())) {
+            *o = a.wrapping_add(*b);
+        }
+    }
+
+    /// Compute the buffer starting at block `buf_block`.
+    fn refill(&mut self) {
+        let mut buf = [0u32; BUF_WORDS];
+        for (i, chunk) in buf.chunks_exact_mut(BLOCK_WORDS).enumerate() {
+            self.block(self.buf_block.wrapping_add(i as u64), chunk);
+        }
+        self.buf = buf;
+        self.index = 0;
+    }
+
+    /// Move to the next buffer of blocks.
+    fn advance_buf(&mut self) {
+        self.buf_block = self.buf_block.wrapping_add(BUF_BLOCKS as u64);
+        self.refill();
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= BUF_WORDS {
+            self.advance_buf();
+        }
+        let v = self.buf[self.index];
+        self.index += 1;
+        v
+    }
+
+    /// Two consecutive words, the first one in the low half.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        if self.index + 1 < BUF_WORDS {
+            let v = (self.buf[self.index] as u64) | ((self.buf[self.index + 1] as u64) << 32);
+            self.index += 2;
+            v
+        } else {
+            let lo = self.next_u32() as u64;
+            let hi = self.next_u32() as u64;
+            lo | (hi << 32)
+        }
+    }
+
+    /// Fill `out` with keystream bytes. A trailing partial word is consumed whole.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(4) {
+            let w = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&w[..chunk.len()]);
+        }
+    }
+}
+
+impl<const ROUNDS: usize> MBitGen for ChaChaRng<ROUNDS> {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ChaChaRng::next_u64(self)
+    }
+
+    /// New key from `seed` as in `seed_from_u64`; the stream is kept and the position reset.
+    fn reseed(&mut self, seed: u64) {
+        let stream = self.stream;
+        *self = Self::seed_from_u64(seed);
+        self.stream = stream;
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        ChaChaRng::advance(self, delta);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::ChaCha {
+            rounds: ROUNDS as u32,
+            key: self.get_seed(),
+            stream: self.stream,
+            word_pos: self.word_pos(),
+        })
+    }
+}
+
+impl<const ROUNDS: usize> fmt::Debug for ChaChaRng<ROUNDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaChaRng")
+            .field("rounds", &ROUNDS)
+            .field("key", &"[REDACTED]")
+            .field("stream", &self.stream)
+            .field("word_pos", &self.word_pos())
+            .finish()
+    }
+}
+
+impl<const ROUNDS: usize> fmt::Display for ChaChaRng<ROUNDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct type: '{}' {{ ", core::any::type_name::<Self>())?;
+        write!(f, "rounds: {}, key: [REDACTED], ", ROUNDS)?;
+        fmt_u64_dual(f, "stream", self.stream)?;
+        write!(f, ", word_pos: {} }}", self.word_pos())
+    }
+}
@@ -1,8 +1,11 @@
-use crate::MBitGen;
+use crate::{BitGenState, MBitGen, MRndErr};
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
-#[derive(Debug)]
+/// Outputs skipped by `Lcg64::jump`.
+pub const LCG64_JUMP: u128 = 1 << 48;
+
+#[derive(Debug, Clone)]
 pub struct Lcg64 {
     pub a: u64,
     pub c: u64,
@@ -48,31 +51,94 @@ impl Lcg64 {
         Some(lcg)
     }
 
-    /// Fast path for direct (non-trait-object) benchmarks and callers.
-    #[inline]
-    pub fn next_u64(&mut self) -> u64 {
-        self.state = self.state.wrapping_mul(self.a).wrapping_add(self.c);
-        self.state
+    /// Skip `delta` steps in O(log delta) (Brown, "Random Number Generation with Arbitrary
+    /// Strides", 1994). The state repeats after at most 2^64 steps, so only `delta mod 2^64`
+    /// matters.
+    pub fn advance(&mut self, delta: u128) {
+        let (mult, plus) = lcg64_advance_coeffs(self.a, self.c, delta as u64);
+        self.state = self.state.wrapping_mul(mult).wrapping_add(plus);
+    }
+
+    /// Advance by `LCG64_JUMP` (2^48) steps.
+    pub fn jump(&mut self) {
+        self.advance(LCG64_JUMP);
+    }
+
+    /// A copy advanced by `LCG64_JUMP` steps; `self` is unchanged.
+    pub fn jumped(&self) -> Self {
+        let mut lcg = self.clone();
+        lcg.jump();
+        lcg
+    }
+
+    /// `n_streams` generators, stream `i` starting `i * floor(2^64 / n_streams)` steps after
+    /// `self`. With a full-period multiplier and increment (`c` odd, `a % 4 == 1`, as for all
+    /// presets) the streams don't overlap within that many outputs.
+    pub fn split(&self, n_streams: usize) -> Vec<Self> {
+        if n_streams == 0 {
+            return vec![];
+        }
+        let stride = (1u128 << 64) / n_streams as u128;
+        let mut streams = Vec::with_capacity(n_streams);
+        let mut lcg = self.clone();
+        for _ in 0..n_streams {
+            streams.push(lcg.clone());
+            lcg.advance(stride);
+        }
+        streams
     }
 }
 
 impl MBitGen for Lcg64 {
     #[inline]
     fn next_u64(&mut self) -> u64 {
-        Lcg64::next_u64(self)
+        self.state = self.state.wrapping_mul(self.a).wrapping_add(self.c);
+        self.state
     }
 
     fn reseed(&mut self, seed: u64) {
         self.state = seed;
     }
-}
 
-use core::fmt;
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        Lcg64::advance(self, delta);
+        Ok(())
+    }
 
-fn fmt_u64_dual(f: &mut fmt::Formatter<'_>, name: &str, v: u64) -> fmt::Result {
-    write!(f, "{}: {} (0x{:016x})", name, v, v)
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Lcg64::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Lcg64 {
+            a: self.a,
+            c: self.c,
+            state: self.state,
+            preset: self.preset.clone(),
+        })
+    }
 }
 
+/// `(A, C)` such that `delta` steps of `x -> a*x + c` equal `x -> A*x + C` (mod 2^64).
+pub(crate) fn lcg64_advance_coeffs(a: u64, c: u64, mut delta: u64) -> (u64, u64) {
+    let (mut acc_mult, mut acc_plus) = (1u64, 0u64);
+    let (mut cur_mult, mut cur_plus) = (a, c);
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    (acc_mult, acc_plus)
+}
+
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use core::fmt;
+
 impl fmt::Display for Lcg64 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use core::any::type_name;
@@ -1,12 +1,76 @@
+use crate::{BitGenState, MRndErr};
 use core::fmt;
 
 pub trait MBitGen: Send + Sync + fmt::Debug + fmt::Display {
     fn next_u64(&mut self) -> u64;
     fn reseed(&mut self, seed: u64);
 
+    /// Skip `delta` outputs, as if `next_u64` had been called `delta` times.
+    /// Engines without a fast jump-ahead return `MRndErr::Unsupported`.
+    fn advance(&mut self, _delta: u128) -> Result<(), MRndErr> {
+        Err(unsupported::<Self>("advance"))
+    }
+
+    /// Move to the next of the engine's non-overlapping streams (see the engine's `jump`).
+    /// Engines without one return `MRndErr::Unsupported`.
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Err(unsupported::<Self>("jump"))
+    }
+
+    /// Snapshot of the engine state, restored with `BitGenState::to_bitgen`.
+    /// Engines without one return `MRndErr::Unsupported`.
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Err(unsupported::<Self>("get_state"))
+    }
+
     fn fill_u64(&mut self, out: &mut [u64]) {
         for x in out {
             *x = self.next_u64();
         }
     }
 }
+
+/// A boxed engine is an engine, so `MPRng<Box<dyn MBitGen>>` shares the generic code; every
+/// call, `fill_u64` included, goes to the boxed engine's own implementation.
+impl<G: MBitGen + ?Sized> MBitGen for Box<G> {
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        (**self).reseed(seed)
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        (**self).advance(delta)
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        (**self).jump()
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        (**self).get_state()
+    }
+
+    #[inline(always)]
+    fn fill_u64(&mut self, out: &mut [u64]) {
+        (**self).fill_u64(out)
+    }
+}
+
+pub(crate) fn fmt_u64_dual(f: &mut fmt::Formatter<'_>, name: &str, v: u64) -> fmt::Result {
+    write!(f, "{}: {} (0x{:016x})", name, v, v)
+}
+
+pub(crate) fn fmt_u128_dual(f: &mut fmt::Formatter<'_>, name: &str, v: u128) -> fmt::Result {
+    write!(f, "{}: {} (0x{:032x})", name, v, v)
+}
+
+fn unsupported<T: ?Sized>(op: &str) -> MRndErr {
+    MRndErr::Unsupported(format!(
+        "{} does not implement {op}",
+        core::any::type_name::<T>()
+    ))
+}
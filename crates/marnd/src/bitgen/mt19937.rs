@@ -0,0 +1,355 @@
+//! Mersenne Twister MT19937 and MT19937-64 (Matsumoto & Nishimura), ported from the reference
+//! `mt19937ar.c` and `mt19937-64.c`.
+//!
+//! `Mt19937` seeded with `init_genrand(s)` reproduces numpy's `RandomState(s)` and C++
+//! `std::mt19937(s)`; `Mt19937_64` reproduces `std::mt19937_64(s)`.
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use crate::{BitGenState, MBitGen, MRndErr, SeedSequence};
+use core::fmt;
+
+/// Number of 32-bit words in the MT19937 state.
+pub const MT19937_N: usize = 624;
+const MT19937_M: usize = 397;
+/// Number of 64-bit words in the MT19937-64 state.
+pub const MT19937_64_NN: usize = 312;
+const MT19937_64_MM: usize = 156;
+
+/* ---------------------------------------------------------------- */
+/* MT19937                                                          */
+/* ---------------------------------------------------------------- */
+
+/// 32-bit Mersenne Twister.
+///
+/// `next_u64` joins two outputs with the first one in the high half, as numpy's
+/// `MT19937` `next_uint64` (`mt19937_next64`) does; `MT19937.random_raw` gives the single
+/// 32-bit outputs. With `legacy_doubles` set, `next_u64` instead returns the 53-bit
+/// `genrand_res53` double of two outputs shifted to the top of the word, so `MPRng::next_f64`
+/// reproduces numpy's legacy `RandomState.random()`.
+#[derive(Debug, Clone)]
+pub struct Mt19937 {
+    pub mt: [u32; MT19937_N],
+    // index of the next word of `mt` to temper; MT19937_N when a twist is due
+    pub mti: usize,
+    pub legacy_doubles: bool,
+}
+
+impl Mt19937 {
+    /// Seed like `init_genrand(seed)`.
+    pub fn new(seed: u32) -> Self {
+        let mut mt = Self {
+            mt: [0; MT19937_N],
+            mti: MT19937_N,
+            legacy_doubles: false,
+        };
+        mt.init_genrand(seed);
+        mt
+    }
+
+    /// Seed like `init_by_array(key)`.
+    pub fn from_key(key: &[u32]) -> Self {
+        let mut mt = Self::new(0);
+        mt.init_by_array(key);
+        mt
+    }
+
+    /// Seed from a `SeedSequence` like numpy's `MT19937(seed_seq)`: the key is 624 generated
+    /// words with the first one replaced by 0x80000000 (never all zero), and the position is
+    /// left at 623 as numpy does.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let mut mt: [u32; MT19937_N] = seq.generate_state(MT19937_N).try_into().unwrap();
+        mt[0] = 0x8000_0000;
+        Self::from_state(mt, MT19937_N - 1)
+    }
+
+    /// Import a state, e.g. numpy's `get_state()[1:3]` (`key`, `pos`).
+    ///
+    /// # Panics
+    /// If `mti > MT19937_N`.
+    pub fn from_state(mt: [u32; MT19937_N], mti: usize) -> Self {
+        assert!(mti <= MT19937_N, "Mt19937: position {mti} out of range");
+        Self {
+            mt,
+            mti,
+            legacy_doubles: false,
+        }
+    }
+
+    /// Export the state as `(key, pos)`.
+    pub fn state(&self) -> ([u32; MT19937_N], usize) {
+        (self.mt, self.mti)
+    }
+
+    pub fn init_genrand(&mut self, seed: u32) {
+        self.mt[0] = seed;
+        for i in 1..MT19937_N {
+            let prev = self.mt[i - 1];
+            self.mt[i] = 1_812_433_253_u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(i as u32);
+        }
+        self.mti = MT19937_N;
+    }
+
+    pub fn init_by_array(&mut self, key: &[u32]) {
+        self.init_genrand(19_650_218);
+        let (mut i, mut j) = (1, 0);
+        for _ in 0..MT19937_N.max(key.len()) {
+            let prev = self.mt[i - 1];
+            self.mt[i] = (self.mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_664_525))
+                .wrapping_add(key.get(j).copied().unwrap_or(0))
+                .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= MT19937_N {
+                self.mt[0] = self.mt[MT19937_N - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..MT19937_N - 1 {
+            let prev = self.mt[i - 1];
+            self.mt[i] = (self.mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_566_083_941))
+                .wrapping_sub(i as u32);
+            i += 1;
+            if i >= MT19937_N {
+                self.mt[0] = self.mt[MT19937_N - 1];
+                i = 1;
+            }
+        }
+        // MSB is 1, assuring a non-zero initial state
+        self.mt[0] = 0x8000_0000;
+        self.mti = MT19937_N;
+    }
+
+    fn twist(&mut self) {
+        const UPPER: u32 = 0x8000_0000;
+        const LOWER: u32 = 0x7fff_ffff;
+        for k in 0..MT19937_N {
+            let y = (self.mt[k] & UPPER) | (self.mt[(k + 1) % MT19937_N] & LOWER);
+            let mag = if y & 1 == 0 { 0 } else { 0x9908_b0df };
+            self.mt[k] = self.mt[(k + MT19937_M) % MT19937_N] ^ (y >> 1) ^ mag;
+        }
+        self.mti = 0;
+    }
+
+    /// The reference `genrand_int32`.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        if self.mti >= MT19937_N {
+            self.twist();
+        }
+        let mut y = self.mt[self.mti];
+        self.mti += 1;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }
+
+    /// The reference `genrand_res53`: numpy's legacy `RandomState.random()` and Python's
+    /// `random.random()`.
+    pub fn next_f64_legacy(&mut self) -> f64 {
+        (self.next_res53() as f64) * (1.0 / 9_007_199_254_740_992.0)
+    }
+
+    fn next_res53(&mut self) -> u64 {
+        let a = (self.next_u32() >> 5) as u64;
+        let b = (self.next_u32() >> 6) as u64;
+        (a << 26) | b
+    }
+}
+
+impl MBitGen for Mt19937 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.legacy_doubles {
+            return self.next_res53() << 11;
+        }
+        let hi = self.next_u32() as u64;
+        (hi << 32) | self.next_u32() as u64
+    }
+
+    /// `init_genrand` for seeds that fit in 32 bits (numpy's `RandomState(seed)`), otherwise
+    /// `init_by_array` with the low and high words (`RandomState([lo, hi])`).
+    fn reseed(&mut self, seed: u64) {
+        match u32::try_from(seed) {
+            Ok(s) => self.init_genrand(s),
+            Err(_) => self.init_by_array(&[seed as u32, (seed >> 32) as u32]),
+        }
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Mt19937 {
+            mt: self.mt.to_vec(),
+            mti: self.mti,
+            legacy_doubles: self.legacy_doubles,
+        })
+    }
+}
+
+/* ---------------------------------------------------------------- */
+/* MT19937-64                                                       */
+/* ---------------------------------------------------------------- */
+
+/// 64-bit Mersenne Twister.
+#[derive(Debug, Clone)]
+pub struct Mt19937_64 {
+    pub mt: [u64; MT19937_64_NN],
+    // index of the next word of `mt` to temper; MT19937_64_NN when a twist is due
+    pub mti: usize,
+}
+
+impl Mt19937_64 {
+    /// Seed like `init_genrand64(seed)`.
+    pub fn new(seed: u64) -> Self {
+        let mut mt = Self {
+            mt: [0; MT19937_64_NN],
+            mti: MT19937_64_NN,
+        };
+        mt.init_genrand(seed);
+        mt
+    }
+
+    /// Seed like `init_by_array64(key)`.
+    pub fn from_key(key: &[u64]) -> Self {
+        let mut mt = Self::new(0);
+        mt.init_by_array(key);
+        mt
+    }
+
+    /// Seed from 312 u64 words of a `SeedSequence`, the first one replaced by 2^63 (never all
+    /// zero).
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let mut mt: [u64; MT19937_64_NN] =
+            seq.generate_state_u64(MT19937_64_NN).try_into().unwrap();
+        mt[0] = 1 << 63;
+        Self::from_state(mt, MT19937_64_NN)
+    }
+
+    /// Import a state.
+    ///
+    /// # Panics
+    /// If `mti > MT19937_64_NN`.
+    pub fn from_state(mt: [u64; MT19937_64_NN], mti: usize) -> Self {
+        assert!(
+            mti <= MT19937_64_NN,
+            "Mt19937_64: position {mti} out of range"
+        );
+        Self { mt, mti }
+    }
+
+    /// Export the state as `(key, pos)`.
+    pub fn state(&self) -> ([u64; MT19937_64_NN], usize) {
+        (self.mt, self.mti)
+    }
+
+    pub fn init_genrand(&mut self, seed: u64) {
+        self.mt[0] = seed;
+        for i in 1..MT19937_64_NN {
+            let prev = self.mt[i - 1];
+            self.mt[i] = 6_364_136_223_846_793_005_u64
+                .wrapping_mul(prev ^ (prev >> 62))
+                .wrapping_add(i as u64);
+        }
+        self.mti = MT19937_64_NN;
+    }
+
+    pub fn init_by_array(&mut self, key: &[u64]) {
+        self.init_genrand(19_650_218);
+        let (mut i, mut j) = (1, 0);
+        for _ in 0..MT19937_64_NN.max(key.len()) {
+            let prev = self.mt[i - 1];
+            self.mt[i] = (self.mt[i]
+                ^ (prev ^ (prev >> 62)).wrapping_mul(3_935_559_000_370_003_845))
+            .wrapping_add(key.get(j).copied().unwrap_or(0))
+            .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= MT19937_64_NN {
+                self.mt[0] = self.mt[MT19937_64_NN - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..MT19937_64_NN - 1 {
+            let prev = self.mt[i - 1];
+            self.mt[i] = (self.mt[i]
+                ^ (prev ^ (prev >> 62)).wrapping_mul(2_862_933_555_777_941_757))
+            .wrapping_sub(i as u64);
+            i += 1;
+            if i >= MT19937_64_NN {
+                self.mt[0] = self.mt[MT19937_64_NN - 1];
+                i = 1;
+            }
+        }
+        // MSB is 1, assuring a non-zero initial state
+        self.mt[0] = 1 << 63;
+        self.mti = MT19937_64_NN;
+    }
+
+    fn twist(&mut self) {
+        const UPPER: u64 = 0xffff_ffff_8000_0000;
+        const LOWER: u64 = 0x7fff_ffff;
+        for k in 0..MT19937_64_NN {
+            let x = (self.mt[k] & UPPER) | (self.mt[(k + 1) % MT19937_64_NN] & LOWER);
+            let mag = if x & 1 == 0 { 0 } else { 0xb502_6f5a_a966_19e9 };
+            self.mt[k] = self.mt[(k + MT19937_64_MM) % MT19937_64_NN] ^ (x >> 1) ^ mag;
+        }
+        self.mti = 0;
+    }
+
+    /// The reference `genrand64_int64`.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        if self.mti >= MT19937_64_NN {
+            self.twist();
+        }
+        let mut x = self.mt[self.mti];
+        self.mti += 1;
+        x ^= (x >> 29) & 0x5555_5555_5555_5555;
+        x ^= (x << 17) & 0x71d6_7fff_eda6_0000;
+        x ^= (x << 37) & 0xfff7_eee0_0000_0000;
+        x ^ (x >> 43)
+    }
+}
+
+impl MBitGen for Mt19937_64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        Mt19937_64::next_u64(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.init_genrand(seed);
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Mt19937_64 {
+            mt: self.mt.to_vec(),
+            mti: self.mti,
+        })
+    }
+}
+
+// The 624/312-word arrays are too long to print; show the position and the first word.
+impl fmt::Display for Mt19937 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct type: '{}' {{ ", core::any::type_name::<Self>())?;
+        write!(f, "mti: {}, ", self.mti)?;
+        fmt_u64_dual(f, "mt[0]", self.mt[0] as u64)?;
+        write!(f, ", legacy_doubles: {} }}", self.legacy_doubles)
+    }
+}
+
+impl fmt::Display for Mt19937_64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct type: '{}' {{ ", core::any::type_name::<Self>())?;
+        write!(f, "mti: {}, ", self.mti)?;
+        fmt_u64_dual(f, "mt[0]", self.mt[0])?;
+        write!(f, " }}")
+    }
+}
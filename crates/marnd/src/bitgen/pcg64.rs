@@ -0,0 +1,250 @@
+use crate::bitgen::mbitgen::fmt_u128_dual;
+use crate::{BitGenState, MBitGen, MRndErr, SeedSequence};
+use core::fmt;
+
+/// 128-bit LCG multiplier of PCG64 (XSL-RR).
+pub const PCG_DEFAULT_MULTIPLIER_128: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+/// 64-bit "cheap" multiplier of PCG64DXSM, used both by the LCG step and the DXSM output.
+pub const PCG_CHEAP_MULTIPLIER_128: u128 = 0xda94_2042_e4dd_58b5;
+/// Stream (increment) used when none is given, as in the PCG reference implementation.
+pub const PCG_DEFAULT_INCREMENT_128: u128 = 0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f;
+/// Steps taken by `jump`: (phi - 1) * 2^128 rounded to odd, the step of numpy's `jumped`.
+pub const PCG_JUMP_STEP_128: u128 = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835;
+
+/// `(A, C)` such that `delta` steps of `x -> a*x + c` equal `x -> A*x + C` (mod 2^128).
+fn lcg128_advance_coeffs(a: u128, c: u128, mut delta: u128) -> (u128, u128) {
+    let (mut acc_mult, mut acc_plus) = (1u128, 0u128);
+    let (mut cur_mult, mut cur_plus) = (a, c);
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+        }
+        cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta >>= 1;
+    }
+    (acc_mult, acc_plus)
+}
+
+/// PCG64 with the XSL-RR output permutation (numpy's `PCG64`).
+///
+/// `state` and `inc` are the raw 128-bit LCG state and (odd) increment, the same values
+/// numpy exposes as `bit_generator.state["state"]`.
+#[derive(Debug, Clone)]
+pub struct Pcg64 {
+    pub state: u128,
+    pub inc: u128,
+}
+
+impl Pcg64 {
+    /// Build from a raw state and increment (the increment is forced odd).
+    pub fn new(state: u128, inc: u128) -> Self {
+        Self {
+            state,
+            inc: inc | 1,
+        }
+    }
+
+    /// Seed like the reference `pcg64_srandom_r(initstate, initseq)`; `initseq` selects the stream.
+    pub fn from_seed(initstate: u128, initseq: u128) -> Self {
+        let mut pcg = Self::new(0, initseq << 1);
+        pcg.srandom(initstate);
+        pcg
+    }
+
+    /// Seed from a `SeedSequence` like numpy's `PCG64(seed_seq)`: four u64 words give
+    /// `initstate` and `initseq`, high word first.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let v = seq.generate_state_u64(4);
+        Self::from_seed(
+            ((v[0] as u128) << 64) | v[1] as u128,
+            ((v[2] as u128) << 64) | v[3] as u128,
+        )
+    }
+
+    fn srandom(&mut self, initstate: u128) {
+        self.state = 0;
+        self.step();
+        self.state = self.state.wrapping_add(initstate);
+        self.step();
+    }
+
+    /// Skip `delta` outputs in O(log delta), like numpy's `advance`.
+    pub fn advance(&mut self, delta: u128) {
+        let (mult, plus) = lcg128_advance_coeffs(PCG_DEFAULT_MULTIPLIER_128, self.inc, delta);
+        self.state = self.state.wrapping_mul(mult).wrapping_add(plus);
+    }
+
+    /// Advance by `PCG_JUMP_STEP_128` outputs, like numpy's `jumped()`.
+    pub fn jump(&mut self) {
+        self.advance(PCG_JUMP_STEP_128);
+    }
+
+    #[inline(always)]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+            .wrapping_add(self.inc);
+    }
+
+    #[inline(always)]
+    fn output(state: u128) -> u64 {
+        let rot = (state >> 122) as u32;
+        (((state >> 64) as u64) ^ (state as u64)).rotate_right(rot)
+    }
+}
+
+impl MBitGen for Pcg64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        // numpy and the reference implementation step first, then permute the new state
+        self.step();
+        Self::output(self.state)
+    }
+
+    /// Re-seed in the current stream.
+    fn reseed(&mut self, seed: u64) {
+        self.srandom(seed as u128);
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        Pcg64::advance(self, delta);
+        Ok(())
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Pcg64::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Pcg64 {
+            state: self.state,
+            inc: self.inc,
+        })
+    }
+}
+
+/// PCG64 with the DXSM output permutation and the cheap multiplier (numpy's `PCG64DXSM`).
+#[derive(Debug, Clone)]
+pub struct Pcg64Dxsm {
+    pub state: u128,
+    pub inc: u128,
+}
+
+impl Pcg64Dxsm {
+    /// Build from a raw state and increment (the increment is forced odd).
+    pub fn new(state: u128, inc: u128) -> Self {
+        Self {
+            state,
+            inc: inc | 1,
+        }
+    }
+
+    /// Seed like numpy's `pcg_cm_srandom_r(initstate, initseq)`; `initseq` selects the stream.
+    pub fn from_seed(initstate: u128, initseq: u128) -> Self {
+        let mut pcg = Self::new(0, initseq << 1);
+        pcg.srandom(initstate);
+        pcg
+    }
+
+    /// Seed from a `SeedSequence` like numpy's `PCG64DXSM(seed_seq)`: four u64 words give
+    /// `initstate` and `initseq`, high word first.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let v = seq.generate_state_u64(4);
+        Self::from_seed(
+            ((v[0] as u128) << 64) | v[1] as u128,
+            ((v[2] as u128) << 64) | v[3] as u128,
+        )
+    }
+
+    fn srandom(&mut self, initstate: u128) {
+        self.state = 0;
+        self.step();
+        self.state = self.state.wrapping_add(initstate);
+        self.step();
+    }
+
+    /// Skip `delta` outputs in O(log delta), like numpy's `advance`.
+    pub fn advance(&mut self, delta: u128) {
+        let (mult, plus) = lcg128_advance_coeffs(PCG_CHEAP_MULTIPLIER_128, self.inc, delta);
+        self.state = self.state.wrapping_mul(mult).wrapping_add(plus);
+    }
+
+    /// Advance by `PCG_JUMP_STEP_128` outputs, like numpy's `jumped()`.
+    pub fn jump(&mut self) {
+        self.advance(PCG_JUMP_STEP_128);
+    }
+
+    #[inline(always)]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG_CHEAP_MULTIPLIER_128)
+            .wrapping_add(self.inc);
+    }
+
+    #[inline(always)]
+    fn output(state: u128) -> u64 {
+        let mut hi = (state >> 64) as u64;
+        let lo = (state as u64) | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(PCG_CHEAP_MULTIPLIER_128 as u64);
+        hi ^= hi >> 48;
+        hi.wrapping_mul(lo)
+    }
+}
+
+impl MBitGen for Pcg64Dxsm {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        // DXSM permutes the state *before* the step
+        let out = Self::output(self.state);
+        self.step();
+        out
+    }
+
+    /// Re-seed in the current stream.
+    fn reseed(&mut self, seed: u64) {
+        self.srandom(seed as u128);
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        Pcg64Dxsm::advance(self, delta);
+        Ok(())
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Pcg64Dxsm::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Pcg64Dxsm {
+            state: self.state,
+            inc: self.inc,
+        })
+    }
+}
+
+fn fmt_pcg(f: &mut fmt::Formatter<'_>, type_name: &str, state: u128, inc: u128) -> fmt::Result {
+    write!(f, "struct type: '{}' {{ ", type_name)?;
+    fmt_u128_dual(f, "state", state)?;
+    write!(f, ", ")?;
+    fmt_u128_dual(f, "inc", inc)?;
+    write!(f, " }}")
+}
+
+impl fmt::Display for Pcg64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_pcg(f, core::any::type_name::<Self>(), self.state, self.inc)
+    }
+}
+
+impl fmt::Display for Pcg64Dxsm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_pcg(f, core::any::type_name::<Self>(), self.state, self.inc)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use crate::{BitGenState, MBitGen, MRndErr};
+use core::fmt;
+
+/// Weyl increment of SplitMix64 (the odd integer closest to 2^64 / phi).
+pub const SPLITMIX64_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// SplitMix64 (Steele, Lea & Flood), as in Vigna's `splitmix64.c`.
+///
+/// Also used to expand a single `u64` seed into the state of the xoshiro/xoroshiro engines,
+/// as recommended by their authors.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    pub state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Skip `delta` outputs; the state is a Weyl sequence, so this is one multiply.
+    pub fn advance(&mut self, delta: u128) {
+        self.state = self
+            .state
+            .wrapping_add(SPLITMIX64_GAMMA.wrapping_mul(delta as u64));
+    }
+
+    /// Advance the state by 2^32 outputs.
+    pub fn jump(&mut self) {
+        self.state = self.state.wrapping_add(SPLITMIX64_GAMMA << 32);
+    }
+
+    /// Advance the state by 2^48 outputs.
+    pub fn long_jump(&mut self) {
+        self.state = self.state.wrapping_add(SPLITMIX64_GAMMA << 48);
+    }
+
+    /// Fast path for direct (non-trait-object) callers.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(SPLITMIX64_GAMMA);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// The first `N` outputs for `seed`: the state of a xoshiro/xoroshiro engine seeded with it.
+    pub(crate) fn expand<const N: usize>(seed: u64) -> [u64; N] {
+        let mut sm = Self::new(seed);
+        core::array::from_fn(|_| sm.next_u64())
+    }
+}
+
+impl MBitGen for SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        SplitMix64::next_u64(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+    }
+
+    fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        SplitMix64::advance(self, delta);
+        Ok(())
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        SplitMix64::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::SplitMix64 { state: self.state })
+    }
+}
+
+impl fmt::Display for SplitMix64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct type: '{}' {{ ", core::any::type_name::<Self>())?;
+        fmt_u64_dual(f, "state", self.state)?;
+        write!(f, " }}")
+    }
+}
@@ -0,0 +1,749 @@
+//! xoshiro256**, xoshiro256++ and xoroshiro128+ (Blackman & Vigna), ported from the authors'
+//! reference C code at <https://prng.di.unimi.it/>.
+//!
+//! `jump()` advances a generator by 2^128 (xoshiro256) or 2^64 (xoroshiro128) outputs and
+//! `long_jump()` by 2^192 or 2^96, so a seeded generator can be split into non-overlapping
+//! streams for parallel work.
+//!
+//! The `...X4` engines interleave `XOSHIRO_LANES` such streams: output `i` is output
+//! `i / XOSHIRO_LANES` of lane `i % XOSHIRO_LANES`, and lane `k` starts `k` jumps after the
+//! seeded generator. `fill_u64` steps all lanes together, as SSE2 vectors on x86_64 and as
+//! lane-wise loops elsewhere; the streams are the same either way. They are new streams, not
+//! a faster way to produce the scalar engines' output.
+use crate::bitgen::mbitgen::fmt_u64_dual;
+use crate::bitgen::splitmix64::SplitMix64;
+use crate::{BitGenState, MBitGen, MRndErr, SeedSequence};
+use core::fmt;
+use core::ops::{BitXor, BitXorAssign, Shl};
+
+const XOSHIRO256_JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+const XOSHIRO256_LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+const XOROSHIRO128_JUMP: [u64; 2] = [0xdf90_0294_d8f5_54a5, 0x1708_65df_4b32_01fc];
+const XOROSHIRO128_LONG_JUMP: [u64; 2] = [0xd2a9_8b26_625e_ee7b, 0xdddf_9b10_90aa_7ac1];
+
+/// Number of interleaved lanes of the `...X4` engines.
+pub const XOSHIRO_LANES: usize = 4;
+
+/* ---------------------------------------------------------------- */
+/* shared linear engines                                            */
+/* ---------------------------------------------------------------- */
+
+/// A 64-bit word, or one word of every lane side by side, so that a single definition of
+/// each engine serves both the scalar and the lane-parallel paths.
+trait Word: Copy + BitXor<Output = Self> + BitXorAssign + Shl<u32, Output = Self> {
+    fn rotl(self, n: u32) -> Self;
+    fn add(self, rhs: Self) -> Self;
+}
+
+impl Word for u64 {
+    #[inline(always)]
+    fn rotl(self, n: u32) -> Self {
+        self.rotate_left(n)
+    }
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+}
+
+#[inline(always)]
+fn xoshiro256_step<W: Word>(s: &mut [W; 4]) {
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotl(45);
+}
+
+#[inline(always)]
+fn xoroshiro128_step<W: Word>(s: &mut [W; 2]) {
+    let s0 = s[0];
+    let s1 = s[1] ^ s0;
+    s[0] = s0.rotl(24) ^ s1 ^ (s1 << 16);
+    s[1] = s1.rotl(37);
+}
+
+#[inline(always)]
+fn starstar<W: Word>(s: &[W; 4]) -> W {
+    // `rotl(s1 * 5, 7) * 9`, with the products as shifts and adds: SSE2 has no 64-bit multiply
+    let x = s[1].add(s[1] << 2).rotl(7);
+    x.add(x << 3)
+}
+
+#[inline(always)]
+fn plusplus<W: Word>(s: &[W; 4]) -> W {
+    s[0].add(s[3]).rotl(23).add(s[0])
+}
+
+#[inline(always)]
+fn plus<W: Word>(s: &[W; 2]) -> W {
+    s[0].add(s[1])
+}
+
+/* ---------------------------------------------------------------- */
+/* interleaved lanes                                                */
+/* ---------------------------------------------------------------- */
+
+/// Lane states as `s[word][lane]`, so that each word of all lanes is one SIMD vector.
+type Lanes<const N: usize> = [[u64; XOSHIRO_LANES]; N];
+
+use lane_words::LaneWords;
+
+/// One word of every lane, as SSE2 vectors, which every x86_64 CPU has. The compiler does not
+/// vectorize 64-bit rotates by itself, so this is spelled out.
+#[cfg(target_arch = "x86_64")]
+mod lane_words {
+    use super::{Word, XOSHIRO_LANES};
+    use core::arch::x86_64::{
+        __m128i, _mm_add_epi64, _mm_cvtsi64_si128, _mm_cvtsi128_si64, _mm_or_si128, _mm_set_epi64x,
+        _mm_sll_epi64, _mm_srl_epi64, _mm_unpackhi_epi64, _mm_xor_si128,
+    };
+    use core::ops::{BitXor, BitXorAssign, Shl};
+
+    #[derive(Clone, Copy)]
+    pub(super) struct LaneWords([__m128i; 2]);
+
+    impl LaneWords {
+        #[inline(always)]
+        pub(super) fn from_lanes(x: [u64; XOSHIRO_LANES]) -> Self {
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe {
+                Self([
+                    _mm_set_epi64x(x[1] as i64, x[0] as i64),
+                    _mm_set_epi64x(x[3] as i64, x[2] as i64),
+                ])
+            }
+        }
+
+        #[inline(always)]
+        pub(super) fn to_lanes(self) -> [u64; XOSHIRO_LANES] {
+            let [a, b] = self.0;
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe {
+                [
+                    _mm_cvtsi128_si64(a) as u64,
+                    _mm_cvtsi128_si64(_mm_unpackhi_epi64(a, a)) as u64,
+                    _mm_cvtsi128_si64(b) as u64,
+                    _mm_cvtsi128_si64(_mm_unpackhi_epi64(b, b)) as u64,
+                ]
+            }
+        }
+    }
+
+    impl BitXor for LaneWords {
+        type Output = Self;
+
+        #[inline(always)]
+        fn bitxor(self, rhs: Self) -> Self {
+            let ([a, b], [c, d]) = (self.0, rhs.0);
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe { Self([_mm_xor_si128(a, c), _mm_xor_si128(b, d)]) }
+        }
+    }
+
+    impl BitXorAssign for LaneWords {
+        #[inline(always)]
+        fn bitxor_assign(&mut self, rhs: Self) {
+            *self = *self ^ rhs;
+        }
+    }
+
+    impl Shl<u32> for LaneWords {
+        type Output = Self;
+
+        #[inline(always)]
+        fn shl(self, n: u32) -> Self {
+            let [a, b] = self.0;
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe {
+                let n = _mm_cvtsi64_si128(n.into());
+                Self([_mm_sll_epi64(a, n), _mm_sll_epi64(b, n)])
+            }
+        }
+    }
+
+    impl Word for LaneWords {
+        #[inline(always)]
+        fn rotl(self, n: u32) -> Self {
+            let [a, b] = self.0;
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe {
+                let (l, r) = (
+                    _mm_cvtsi64_si128(n.into()),
+                    _mm_cvtsi64_si128((64 - n).into()),
+                );
+                Self([
+                    _mm_or_si128(_mm_sll_epi64(a, l), _mm_srl_epi64(a, r)),
+                    _mm_or_si128(_mm_sll_epi64(b, l), _mm_srl_epi64(b, r)),
+                ])
+            }
+        }
+
+        #[inline(always)]
+        fn add(self, rhs: Self) -> Self {
+            let ([a, b], [c, d]) = (self.0, rhs.0);
+            // SAFETY: the intrinsics only need SSE2, which every x86_64 CPU has.
+            unsafe { Self([_mm_add_epi64(a, c), _mm_add_epi64(b, d)]) }
+        }
+    }
+}
+
+/// One word of every lane, as plain lane-wise loops.
+#[cfg(not(target_arch = "x86_64"))]
+mod lane_words {
+    use super::{Word, XOSHIRO_LANES};
+    use core::ops::{BitXor, BitXorAssign, Shl};
+
+    #[derive(Clone, Copy)]
+    pub(super) struct LaneWords([u64; XOSHIRO_LANES]);
+
+    impl LaneWords {
+        #[inline(always)]
+        pub(super) fn from_lanes(x: [u64; XOSHIRO_LANES]) -> Self {
+            Self(x)
+        }
+
+        #[inline(always)]
+        pub(super) fn to_lanes(self) -> [u64; XOSHIRO_LANES] {
+            self.0
+        }
+
+        #[inline(always)]
+        fn zip(self, rhs: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+            Self(core::array::from_fn(|k| f(self.0[k], rhs.0[k])))
+        }
+    }
+
+    impl BitXor for LaneWords {
+        type Output = Self;
+
+        #[inline(always)]
+        fn bitxor(self, rhs: Self) -> Self {
+            self.zip(rhs, |a, b| a ^ b)
+        }
+    }
+
+    impl BitXorAssign for LaneWords {
+        #[inline(always)]
+        fn bitxor_assign(&mut self, rhs: Self) {
+            *self = *self ^ rhs;
+        }
+    }
+
+    impl Shl<u32> for LaneWords {
+        type Output = Self;
+
+        #[inline(always)]
+        fn shl(self, n: u32) -> Self {
+            Self(self.0.map(|x| x << n))
+        }
+    }
+
+    impl Word for LaneWords {
+        #[inline(always)]
+        fn rotl(self, n: u32) -> Self {
+            Self(self.0.map(|x| x.rotate_left(n)))
+        }
+
+        #[inline(always)]
+        fn add(self, rhs: Self) -> Self {
+            self.zip(rhs, u64::wrapping_add)
+        }
+    }
+}
+
+#[inline(always)]
+fn get_lane<const N: usize>(s: &Lanes<N>, k: usize) -> [u64; N] {
+    core::array::from_fn(|w| s[w][k])
+}
+
+#[inline(always)]
+fn set_lane<const N: usize>(s: &mut Lanes<N>, k: usize, x: [u64; N]) {
+    for (row, v) in s.iter_mut().zip(x) {
+        row[k] = v;
+    }
+}
+
+/// One output of lane `*lane`, then move on to the next lane.
+#[inline(always)]
+fn lanes_next<const N: usize>(
+    s: &mut Lanes<N>,
+    lane: &mut usize,
+    output: impl Fn(&[u64; N]) -> u64,
+    step: impl Fn(&mut [u64; N]),
+) -> u64 {
+    let mut x = get_lane(s, *lane);
+    let out = output(&x);
+    step(&mut x);
+    set_lane(s, *lane, x);
+    *lane = (*lane + 1) % XOSHIRO_LANES;
+    out
+}
+
+/// `out` as repeated `lanes_next`: one output of every lane per block, with all lanes
+/// stepped together as `LaneWords`. Words before the first whole block and after the last
+/// one take the single-lane path.
+#[inline(always)]
+fn lanes_fill<const N: usize>(
+    s: &mut Lanes<N>,
+    lane: &mut usize,
+    out: &mut [u64],
+    output: impl Fn(&[u64; N]) -> u64 + Copy,
+    step: impl Fn(&mut [u64; N]) + Copy,
+    output_lanes: impl Fn(&[LaneWords; N]) -> LaneWords,
+    step_lanes: impl Fn(&mut [LaneWords; N]),
+) {
+    let head = ((XOSHIRO_LANES - *lane) % XOSHIRO_LANES).min(out.len());
+    let (head, body) = out.split_at_mut(head);
+    for x in head {
+        *x = lanes_next(s, lane, output, step);
+    }
+
+    // a local copy stays in registers
+    let mut st = s.map(LaneWords::from_lanes);
+    let mut blocks = body.chunks_exact_mut(XOSHIRO_LANES);
+    for block in &mut blocks {
+        block.copy_from_slice(&output_lanes(&st).to_lanes());
+        step_lanes(&mut st);
+    }
+    *s = st.map(LaneWords::to_lanes);
+
+    for x in blocks.into_remainder() {
+        *x = lanes_next(s, lane, output, step);
+    }
+}
+
+/// Replace `s` by `p(T) s`, where `T` is the state transition and `p` the jump polynomial.
+fn apply_jump<const N: usize>(s: &mut [u64; N], poly: &[u64; N], step: fn(&mut [u64; N])) {
+    let mut acc = [0u64; N];
+    for &word in poly {
+        for b in 0..64 {
+            if word & (1 << b) != 0 {
+                for (a, x) in acc.iter_mut().zip(s.iter()) {
+                    *a ^= x;
+                }
+            }
+            step(s);
+        }
+    }
+    *s = acc;
+}
+
+fn check_state(s: &[u64], type_name: &str) {
+    assert!(
+        s.iter().any(|&x| x != 0),
+        "{type_name}: the all-zero state is a fixed point"
+    );
+}
+
+fn fmt_state(f: &mut fmt::Formatter<'_>, type_name: &str, s: &[u64]) -> fmt::Result {
+    write!(f, "struct type: '{}' {{ ", type_name)?;
+    for (i, &x) in s.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt_u64_dual(f, &format!("s{i}"), x)?;
+    }
+    write!(f, " }}")
+}
+
+/* ---------------------------------------------------------------- */
+/* xoshiro256**                                                     */
+/* ---------------------------------------------------------------- */
+
+/// xoshiro256** 1.0, the authors' all-purpose 64-bit generator.
+#[derive(Debug, Clone)]
+pub struct Xoshiro256StarStar {
+    pub s: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Seed by expanding `seed` with SplitMix64, as the authors recommend.
+    pub fn new(seed: u64) -> Self {
+        Self::from_state(SplitMix64::expand(seed))
+    }
+
+    /// Seed from `4` u64 words of a `SeedSequence`.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let s = seq.generate_state_u64(4);
+        Self::from_state(core::array::from_fn(|i| s[i]))
+    }
+
+    /// Build from a raw state.
+    ///
+    /// # Panics
+    /// If the state is all zeros.
+    pub fn from_state(s: [u64; 4]) -> Self {
+        check_state(&s, "Xoshiro256StarStar");
+        Self { s }
+    }
+
+    /// Advance by 2^128 outputs.
+    pub fn jump(&mut self) {
+        apply_jump(&mut self.s, &XOSHIRO256_JUMP, xoshiro256_step);
+    }
+
+    /// Advance by 2^192 outputs.
+    pub fn long_jump(&mut self) {
+        apply_jump(&mut self.s, &XOSHIRO256_LONG_JUMP, xoshiro256_step);
+    }
+
+    /// Fast path for direct (non-trait-object) callers.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let out = starstar(&self.s);
+        xoshiro256_step(&mut self.s);
+        out
+    }
+}
+
+impl MBitGen for Xoshiro256StarStar {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        Xoshiro256StarStar::next_u64(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.s = SplitMix64::expand(seed);
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Xoshiro256StarStar::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Xoshiro256StarStar { s: self.s })
+    }
+}
+
+/* ---------------------------------------------------------------- */
+/* xoshiro256++                                                     */
+/* ---------------------------------------------------------------- */
+
+/// xoshiro256++ 1.0; same state and jumps as xoshiro256**, different output scrambler.
+#[derive(Debug, Clone)]
+pub struct Xoshiro256PlusPlus {
+    pub s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Seed by expanding `seed` with SplitMix64, as the authors recommend.
+    pub fn new(seed: u64) -> Self {
+        Self::from_state(SplitMix64::expand(seed))
+    }
+
+    /// Seed from `4` u64 words of a `SeedSequence`.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let s = seq.generate_state_u64(4);
+        Self::from_state(core::array::from_fn(|i| s[i]))
+    }
+
+    /// Build from a raw state.
+    ///
+    /// # Panics
+    /// If the state is all zeros.
+    pub fn from_state(s: [u64; 4]) -> Self {
+        check_state(&s, "Xoshiro256PlusPlus");
+        Self { s }
+    }
+
+    /// Advance by 2^128 outputs.
+    pub fn jump(&mut self) {
+        apply_jump(&mut self.s, &XOSHIRO256_JUMP, xoshiro256_step);
+    }
+
+    /// Advance by 2^192 outputs.
+    pub fn long_jump(&mut self) {
+        apply_jump(&mut self.s, &XOSHIRO256_LONG_JUMP, xoshiro256_step);
+    }
+
+    /// Fast path for direct (non-trait-object) callers.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let out = plusplus(&self.s);
+        xoshiro256_step(&mut self.s);
+        out
+    }
+}
+
+impl MBitGen for Xoshiro256PlusPlus {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        Xoshiro256PlusPlus::next_u64(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.s = SplitMix64::expand(seed);
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Xoshiro256PlusPlus::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Xoshiro256PlusPlus { s: self.s })
+    }
+}
+
+/* ---------------------------------------------------------------- */
+/* xoroshiro128+                                                    */
+/* ---------------------------------------------------------------- */
+
+/// xoroshiro128+ 1.0 (a = 24, b = 16, c = 37). The lowest bits are weak (linear); prefer the
+/// upper bits, as `MPRng::next_f64` does.
+#[derive(Debug, Clone)]
+pub struct Xoroshiro128Plus {
+    pub s: [u64; 2],
+}
+
+impl Xoroshiro128Plus {
+    /// Seed by expanding `seed` with SplitMix64, as the authors recommend.
+    pub fn new(seed: u64) -> Self {
+        Self::from_state(SplitMix64::expand(seed))
+    }
+
+    /// Seed from `2` u64 words of a `SeedSequence`.
+    pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+        let s = seq.generate_state_u64(2);
+        Self::from_state(core::array::from_fn(|i| s[i]))
+    }
+
+    /// Build from a raw state.
+    ///
+    /// # Panics
+    /// If the state is all zeros.
+    pub fn from_state(s: [u64; 2]) -> Self {
+        check_state(&s, "Xoroshiro128Plus");
+        Self { s }
+    }
+
+    /// Advance by 2^64 outputs.
+    pub fn jump(&mut self) {
+        apply_jump(&mut self.s, &XOROSHIRO128_JUMP, xoroshiro128_step);
+    }
+
+    /// Advance by 2^96 outputs.
+    pub fn long_jump(&mut self) {
+        apply_jump(&mut self.s, &XOROSHIRO128_LONG_JUMP, xoroshiro128_step);
+    }
+
+    /// Fast path for direct (non-trait-object) callers.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let out = plus(&self.s);
+        xoroshiro128_step(&mut self.s);
+        out
+    }
+}
+
+impl MBitGen for Xoroshiro128Plus {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        Xoroshiro128Plus::next_u64(self)
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.s = SplitMix64::expand(seed);
+    }
+
+    fn jump(&mut self) -> Result<(), MRndErr> {
+        Xoroshiro128Plus::jump(self);
+        Ok(())
+    }
+
+    fn get_state(&self) -> Result<BitGenState, MRndErr> {
+        Ok(BitGenState::Xoroshiro128Plus { s: self.s })
+    }
+}
+
+impl fmt::Display for Xoshiro256StarStar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_state(f, core::any::type_name::<Self>(), &self.s)
+    }
+}
+
+impl fmt::Display for Xoshiro256PlusPlus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_state(f, core::any::type_name::<Self>(), &self.s)
+    }
+}
+
+impl fmt::Display for Xoroshiro128Plus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_state(f, core::any::type_name::<Self>(), &self.s)
+    }
+}
+
+/* ---------------------------------------------------------------- */
+/* lane-interleaved engines                                         */
+/* ---------------------------------------------------------------- */
+
+macro_rules! lanes_engine {
+    ($name:ident, $scalar:ident, $words:literal, $output:ident, $step:ident) => {
+        #[doc = concat!(
+            "`XOSHIRO_LANES` interleaved ", stringify!($scalar), " streams; see the module docs."
+        )]
+        ///
+        #[doc = concat!(
+            "The output is not that of `", stringify!($scalar), "` with the same seed, and `",
+            stringify!($scalar), "` itself keeps the scalar `fill_u64`: the lane-parallel fill is ",
+            "only for callers who can switch to this stream."
+        )]
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            /// `s[w][k]` is word `w` of lane `k`.
+            pub s: [[u64; XOSHIRO_LANES]; $words],
+            /// Lane of the next output.
+            pub lane: usize,
+        }
+
+        impl $name {
+            #[doc = concat!("The lanes of `", stringify!($scalar), "::new(seed)`.")]
+            pub fn new(seed: u64) -> Self {
+                Self::from_generator(&$scalar::new(seed))
+            }
+
+            #[doc = concat!("The lanes of `", stringify!($scalar), "::from_seed_seq(seq)`.")]
+            pub fn from_seed_seq(seq: &SeedSequence) -> Self {
+                Self::from_generator(&$scalar::from_seed_seq(seq))
+            }
+
+            /// Lane `k` starts as `g` after `k` jumps.
+            pub fn from_generator(g: &$scalar) -> Self {
+                let mut g = g.clone();
+                let mut s = [[0; XOSHIRO_LANES]; $words];
+                for k in 0..XOSHIRO_LANES {
+                    set_lane(&mut s, k, g.s);
+                    g.jump();
+                }
+                Self { s, lane: 0 }
+            }
+
+            /// Build from raw lane states and the lane of the next output.
+            ///
+            /// # Panics
+            /// If a lane state is all zeros or `lane >= XOSHIRO_LANES`.
+            pub fn from_state(s: [[u64; XOSHIRO_LANES]; $words], lane: usize) -> Self {
+                assert!(
+                    lane < XOSHIRO_LANES,
+                    "{}: lane {lane} out of range",
+                    stringify!($name)
+                );
+                for k in 0..XOSHIRO_LANES {
+                    check_state(&get_lane(&s, k), stringify!($name));
+                }
+                Self { s, lane }
+            }
+
+            /// Lane `k` as a scalar generator at its current position.
+            pub fn lane(&self, k: usize) -> $scalar {
+                $scalar::from_state(get_lane(&self.s, k))
+            }
+
+            /// Long-jump every lane, so that the lanes of successive jumps never overlap.
+            pub fn jump(&mut self) {
+                for k in 0..XOSHIRO_LANES {
+                    let mut g = self.lane(k);
+                    g.long_jump();
+                    set_lane(&mut self.s, k, g.s);
+                }
+            }
+
+            /// Fast path for direct (non-trait-object) callers.
+            #[inline]
+            pub fn next_u64(&mut self) -> u64 {
+                lanes_next(&mut self.s, &mut self.lane, $output::<u64>, $step::<u64>)
+            }
+        }
+
+        impl MBitGen for $name {
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                $name::next_u64(self)
+            }
+
+            fn reseed(&mut self, seed: u64) {
+                *self = Self::new(seed);
+            }
+
+            fn jump(&mut self) -> Result<(), MRndErr> {
+                $name::jump(self);
+                Ok(())
+            }
+
+            fn fill_u64(&mut self, out: &mut [u64]) {
+                lanes_fill(
+                    &mut self.s,
+                    &mut self.lane,
+                    out,
+                    $output::<u64>,
+                    $step::<u64>,
+                    $output::<LaneWords>,
+                    $step::<LaneWords>,
+                );
+            }
+
+            fn get_state(&self) -> Result<BitGenState, MRndErr> {
+                Ok(BitGenState::$name {
+                    s: self.s,
+                    lane: self.lane,
+                })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    f,
+                    "struct type: '{}' {{ lane: {}",
+                    core::any::type_name::<Self>(),
+                    self.lane
+                )?;
+                for k in 0..XOSHIRO_LANES {
+                    for (w, &x) in get_lane(&self.s, k).iter().enumerate() {
+                        write!(f, ", ")?;
+                        fmt_u64_dual(f, &format!("lane{k}.s{w}"), x)?;
+                    }
+                }
+                write!(f, " }}")
+            }
+        }
+    };
+}
+
+lanes_engine!(
+    Xoshiro256StarStarX4,
+    Xoshiro256StarStar,
+    4,
+    starstar,
+    xoshiro256_step
+);
+lanes_engine!(
+    Xoshiro256PlusPlusX4,
+    Xoshiro256PlusPlus,
+    4,
+    plusplus,
+    xoshiro256_step
+);
+lanes_engine!(
+    Xoroshiro128PlusX4,
+    Xoroshiro128Plus,
+    2,
+    plus,
+    xoroshiro128_step
+);
@@ -0,0 +1,281 @@
+//! Continuous distributions on `MPRng`, named and parameterized like numpy's `Generator`
+//! methods and sampled with the same algorithms as numpy's `distributions.c`.
+//!
+//! Every sampler validates its parameters first and returns `MRndErr::InvalidArgument` for
+//! out-of-range (or NaN) values, so a bad parameter is reported before any draw is consumed.
+use crate::{MBitGen, MPRng, MRndErr};
+use std::f64::consts::PI;
+
+/// `Ok(())` if `ok`, else `InvalidArgument("<dist>: <cond> (got <value>)")`.
+pub(crate) fn require(ok: bool, dist: &str, cond: &str, value: f64) -> Result<(), MRndErr> {
+    if ok {
+        Ok(())
+    } else {
+        Err(MRndErr::InvalidArgument(format!(
+            "{dist}: {cond} (got {value})"
+        )))
+    }
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// Uniform f64 in (0, 1]; for logarithms that must not see 0.
+    #[inline(always)]
+    pub(crate) fn next_f64_oc(&mut self) -> f64 {
+        1.0 - self.next_f64()
+    }
+
+    /// Gamma(shape, 1) for `shape >= 0`, unchecked. Marsaglia–Tsang (2000) for `shape > 1`,
+    /// Johnk-style rejection from the exponential for `shape < 1`.
+    pub(crate) fn standard_gamma_unchecked(&mut self, shape: f64) -> f64 {
+        if shape == 1.0 {
+            return self.standard_exponential();
+        }
+        if shape == 0.0 {
+            return 0.0;
+        }
+        if shape < 1.0 {
+            loop {
+                let u = self.next_f64();
+                let v = self.standard_exponential();
+                if u <= 1.0 - shape {
+                    let x = u.powf(1.0 / shape);
+                    if x <= v {
+                        return x;
+                    }
+                } else {
+                    let y = -((1.0 - u) / shape).ln();
+                    let x = (1.0 - shape + shape * y).powf(1.0 / shape);
+                    if x <= v + y {
+                        return x;
+                    }
+                }
+            }
+        }
+        let b = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * b).sqrt();
+        loop {
+            let (x, v) = loop {
+                let x = self.standard_normal();
+                let v = 1.0 + c * x;
+                if v > 0.0 {
+                    break (x, v * v * v);
+                }
+            };
+            let u = self.next_f64();
+            // squeeze first, the logarithms are only needed for about 2% of the draws
+            if u < 1.0 - 0.0331 * (x * x) * (x * x) {
+                return b * v;
+            }
+            if u.ln() < 0.5 * x * x + b * (1.0 - v + v.ln()) {
+                return b * v;
+            }
+        }
+    }
+
+    /// Normal with mean `loc` and standard deviation `scale >= 0`.
+    pub fn normal(&mut self, loc: f64, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "normal", "scale must be >= 0", scale)?;
+        Ok(loc + scale * self.standard_normal())
+    }
+
+    /// Exponential with mean `scale >= 0`.
+    pub fn exponential(&mut self, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "exponential", "scale must be >= 0", scale)?;
+        Ok(scale * self.standard_exponential())
+    }
+
+    /// Gamma with shape `k >= 0` and scale 1.
+    pub fn standard_gamma(&mut self, shape: f64) -> Result<f64, MRndErr> {
+        require(shape >= 0.0, "standard_gamma", "shape must be >= 0", shape)?;
+        Ok(self.standard_gamma_unchecked(shape))
+    }
+
+    /// Gamma with shape `k >= 0` and scale `theta >= 0` (mean `k * theta`).
+    pub fn gamma(&mut self, shape: f64, scale: f64) -> Result<f64, MRndErr> {
+        require(shape >= 0.0, "gamma", "shape must be >= 0", shape)?;
+        require(scale >= 0.0, "gamma", "scale must be >= 0", scale)?;
+        Ok(scale * self.standard_gamma_unchecked(shape))
+    }
+
+    /// Beta(a, b) for `a, b > 0`.
+    pub fn beta(&mut self, a: f64, b: f64) -> Result<f64, MRndErr> {
+        require(a > 0.0, "beta", "a must be > 0", a)?;
+        require(b > 0.0, "beta", "b must be > 0", b)?;
+        if a > 1.0 || b > 1.0 {
+            let ga = self.standard_gamma_unchecked(a);
+            let gb = self.standard_gamma_unchecked(b);
+            return Ok(ga / (ga + gb));
+        }
+        // Johnk's algorithm; the two gammas would underflow for small a and b
+        loop {
+            let u = self.next_f64();
+            let v = self.next_f64();
+            let x = u.powf(1.0 / a);
+            let y = v.powf(1.0 / b);
+            let xpy = x + y;
+            if xpy <= 1.0 && u + v > 0.0 {
+                if xpy > 0.0 {
+                    return Ok(x / xpy);
+                }
+                // both underflowed: x / (x + y) in log space
+                let log_x = u.ln() / a;
+                let log_y = v.ln() / b;
+                let log_m = log_x.max(log_y);
+                let (log_x, log_y) = (log_x - log_m, log_y - log_m);
+                return Ok((log_x - (log_x.exp() + log_y.exp()).ln()).exp());
+            }
+        }
+    }
+
+    /// Chi-square with `df > 0` degrees of freedom.
+    pub fn chisquare(&mut self, df: f64) -> Result<f64, MRndErr> {
+        require(df > 0.0, "chisquare", "df must be > 0", df)?;
+        Ok(2.0 * self.standard_gamma_unchecked(df / 2.0))
+    }
+
+    /// Student's t with `df > 0` degrees of freedom.
+    pub fn standard_t(&mut self, df: f64) -> Result<f64, MRndErr> {
+        require(df > 0.0, "standard_t", "df must be > 0", df)?;
+        let num = self.standard_normal();
+        let denom = self.standard_gamma_unchecked(df / 2.0);
+        Ok((df / 2.0).sqrt() * num / denom.sqrt())
+    }
+
+    /// F (Fisher–Snedecor) with `dfnum, dfden > 0`.
+    pub fn f(&mut self, dfnum: f64, dfden: f64) -> Result<f64, MRndErr> {
+        require(dfnum > 0.0, "f", "dfnum must be > 0", dfnum)?;
+        require(dfden > 0.0, "f", "dfden must be > 0", dfden)?;
+        let num = 2.0 * self.standard_gamma_unchecked(dfnum / 2.0) * dfden;
+        let den = 2.0 * self.standard_gamma_unchecked(dfden / 2.0) * dfnum;
+        Ok(num / den)
+    }
+
+    /// Lognormal: `exp(X)` for `X` normal with the given `mean` and `sigma >= 0`.
+    pub fn lognormal(&mut self, mean: f64, sigma: f64) -> Result<f64, MRndErr> {
+        require(sigma >= 0.0, "lognormal", "sigma must be >= 0", sigma)?;
+        Ok((mean + sigma * self.standard_normal()).exp())
+    }
+
+    /// Weibull with shape `a >= 0` and scale 1.
+    pub fn weibull(&mut self, a: f64) -> Result<f64, MRndErr> {
+        require(a >= 0.0, "weibull", "a must be >= 0", a)?;
+        if a == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(self.standard_exponential().powf(1.0 / a))
+    }
+
+    /// Standard Cauchy (Lorentz): the ratio of two standard normals.
+    pub fn standard_cauchy(&mut self) -> f64 {
+        self.standard_normal() / self.standard_normal()
+    }
+
+    /// Laplace (double exponential) with location `loc` and scale `scale >= 0`.
+    pub fn laplace(&mut self, loc: f64, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "laplace", "scale must be >= 0", scale)?;
+        loop {
+            let u = self.next_f64();
+            if u >= 0.5 {
+                return Ok(loc - scale * (2.0 - u - u).ln());
+            }
+            if u > 0.0 {
+                return Ok(loc + scale * (u + u).ln());
+            }
+        }
+    }
+
+    /// Logistic with location `loc` and scale `scale >= 0`.
+    pub fn logistic(&mut self, loc: f64, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "logistic", "scale must be >= 0", scale)?;
+        loop {
+            let u = self.next_f64();
+            if u > 0.0 {
+                return Ok(loc + scale * (u / (1.0 - u)).ln());
+            }
+        }
+    }
+
+    /// Pareto II (Lomax) with shape `a > 0`, like numpy: add 1 and multiply by `x_m` for the
+    /// classical Pareto with minimum `x_m`.
+    pub fn pareto(&mut self, a: f64) -> Result<f64, MRndErr> {
+        require(a > 0.0, "pareto", "a must be > 0", a)?;
+        Ok((self.standard_exponential() / a).exp_m1())
+    }
+
+    /// Gumbel (extreme value type I) with location `loc` and scale `scale >= 0`.
+    pub fn gumbel(&mut self, loc: f64, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "gumbel", "scale must be >= 0", scale)?;
+        loop {
+            let u = self.next_f64_oc();
+            if u < 1.0 {
+                return Ok(loc - scale * (-u.ln()).ln());
+            }
+        }
+    }
+
+    /// Rayleigh with mode `scale >= 0`.
+    pub fn rayleigh(&mut self, scale: f64) -> Result<f64, MRndErr> {
+        require(scale >= 0.0, "rayleigh", "scale must be >= 0", scale)?;
+        Ok(scale * (2.0 * self.standard_exponential()).sqrt())
+    }
+
+    /// von Mises on `[-pi, pi]` with mode `mu` and concentration `kappa >= 0`
+    /// (Best & Fisher 1979; a wrapped normal for very large `kappa`).
+    pub fn vonmises(&mut self, mu: f64, kappa: f64) -> Result<f64, MRndErr> {
+        require(kappa >= 0.0, "vonmises", "kappa must be >= 0", kappa)?;
+        require(mu.is_finite(), "vonmises", "mu must be finite", mu)?;
+        if kappa < 1e-8 {
+            // indistinguishable from uniform; numpy ignores mu here as well
+            return Ok(PI * (2.0 * self.next_f64() - 1.0));
+        }
+        let s = if kappa < 1e-5 {
+            // second order Taylor expansion around kappa = 0
+            1.0 / kappa + kappa
+        } else if kappa <= 1e6 {
+            let r = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+            let rho = (r - (2.0 * r).sqrt()) / (2.0 * kappa);
+            (1.0 + rho * rho) / (2.0 * rho)
+        } else {
+            let x = mu + self.standard_normal() / kappa.sqrt();
+            return Ok(wrap_angle(x));
+        };
+
+        let w = loop {
+            let z = (PI * self.next_f64()).cos();
+            let w = (1.0 + s * z) / (s + z);
+            let y = kappa * (s - w);
+            let v = self.next_f64();
+            if y * (2.0 - y) - v >= 0.0 || (y / v).ln() + 1.0 - y >= 0.0 {
+                break w;
+            }
+        };
+        let mut x = w.acos();
+        if self.next_f64() < 0.5 {
+            x = -x;
+        }
+        Ok(wrap_angle(x + mu))
+    }
+
+    /// Triangular on `[left, right]` with peak at `mode`; needs `left <= mode <= right` and
+    /// `left < right`.
+    pub fn triangular(&mut self, left: f64, mode: f64, right: f64) -> Result<f64, MRndErr> {
+        require(left <= mode, "triangular", "left must be <= mode", left)?;
+        require(mode <= right, "triangular", "mode must be <= right", mode)?;
+        require(left < right, "triangular", "left must be < right", left)?;
+        let base = right - left;
+        let left_base = mode - left;
+        let ratio = left_base / base;
+        let u = self.next_f64();
+        if u <= ratio {
+            Ok(left + (u * left_base * base).sqrt())
+        } else {
+            Ok(right - ((1.0 - u) * (right - mode) * base).sqrt())
+        }
+    }
+}
+
+/// Map an angle into `[-pi, pi]`, keeping its sign.
+fn wrap_angle(x: f64) -> f64 {
+    let m = (x.abs() + PI) % (2.0 * PI) - PI;
+    if x < 0.0 { -m } else { m }
+}
@@ -0,0 +1,511 @@
+//! Discrete distributions on `MPRng`, named and parameterized like numpy's `Generator`
+//! methods and sampled with the same algorithms as numpy's `distributions.c`.
+//!
+//! The large-parameter cases use the classical rejection samplers: BTPE (Kachitvichyanukul &
+//! Schmeiser 1988) for the binomial, PTRS (Hörmann 1993) for the Poisson and H2PE/HRUA
+//! (Stadlober 1989) for the hypergeometric. Parameters are validated before any draw.
+use crate::dist::continuous::require;
+use crate::{MBitGen, MPRng, MRndErr};
+
+/// Largest `lam` accepted by `poisson`, numpy's `POISSON_LAM_MAX`
+/// (`i64::MAX - 10 * sqrt(i64::MAX)`): beyond it the result could overflow an `i64`.
+pub const POISSON_LAM_MAX: f64 = 9.223_372_006_484_771e18;
+
+/// `ln(Gamma(x))` for `x > 0`, Stirling's series shifted to `x >= 7` (numpy's `random_loggam`).
+pub(crate) fn loggam(x: f64) -> f64 {
+    const A: [f64; 10] = [
+        8.333333333333333e-02,
+        -2.777777777777778e-03,
+        7.936507936507937e-04,
+        -5.952380952380952e-04,
+        8.417508417508418e-04,
+        -1.917526917526918e-03,
+        6.41025641025641e-03,
+        -2.955065359477124e-02,
+        1.796443723688307e-01,
+        -1.39243221690590e+00,
+    ];
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+    let n = if x < 7.0 { (7.0 - x) as i32 } else { 0 };
+    let mut x0 = x + n as f64;
+    let x2 = 1.0 / (x0 * x0);
+    let gl0 = A.iter().rev().fold(0.0, |acc, &a| acc * x2 + a);
+    let mut gl = gl0 / x0 + 0.5 * (2.0 * std::f64::consts::PI).ln() + (x0 - 0.5) * x0.ln() - x0;
+    for _ in 0..n {
+        x0 -= 1.0;
+        gl -= x0.ln();
+    }
+    gl
+}
+
+fn logfactorial(k: u64) -> f64 {
+    loggam(k as f64 + 1.0)
+}
+
+fn require_prob(p: f64, dist: &str) -> Result<(), MRndErr> {
+    require((0.0..=1.0).contains(&p), dist, "p must be in [0, 1]", p)
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// Binomial: successes in `n` trials with success probability `p` in `[0, 1]`.
+    pub fn binomial(&mut self, n: u64, p: f64) -> Result<u64, MRndErr> {
+        require_prob(p, "binomial")?;
+        Ok(self.binomial_unchecked(n, p))
+    }
+
+    pub(crate) fn binomial_unchecked(&mut self, n: u64, p: f64) -> u64 {
+        if n == 0 || p == 0.0 {
+            return 0;
+        }
+        if p > 0.5 {
+            return n - self.binomial_unchecked(n, 1.0 - p);
+        }
+        if p * n as f64 <= 30.0 {
+            self.binomial_inversion(n, p)
+        } else {
+            self.binomial_btpe(n, p)
+        }
+    }
+
+    /// Sequential search from 0; `p <= 0.5` and `n * p <= 30`.
+    fn binomial_inversion(&mut self, n: u64, p: f64) -> u64 {
+        let q = 1.0 - p;
+        let qn = (n as f64 * q.ln()).exp();
+        let np = n as f64 * p;
+        let bound = (n as f64).min(np + 10.0 * (np * q + 1.0).sqrt());
+
+        let mut x = 0u64;
+        let mut px = qn;
+        let mut u = self.next_f64();
+        while u > px {
+            x += 1;
+            if x as f64 > bound {
+                // lost in the far tail through rounding: start over
+                x = 0;
+                px = qn;
+                u = self.next_f64();
+            } else {
+                u -= px;
+                px = ((n - x + 1) as f64 * p * px) / (x as f64 * q);
+            }
+        }
+        x
+    }
+
+    /// BTPE: a triangle, two parallelograms and two exponential tails over the PMF;
+    /// `p <= 0.5` and `n * p > 30`.
+    fn binomial_btpe(&mut self, n: u64, p: f64) -> u64 {
+        let nf = n as f64;
+        let r = p;
+        let q = 1.0 - r;
+        let fm = nf * r + r;
+        let m = fm.floor();
+        let p1 = (2.195 * (nf * r * q).sqrt() - 4.6 * q).floor() + 0.5;
+        let xm = m + 0.5;
+        let xl = xm - p1;
+        let xr = xm + p1;
+        let c = 0.134 + 20.5 / (15.3 + m);
+        let a = (fm - xl) / (fm - xl * r);
+        let laml = a * (1.0 + a / 2.0);
+        let a = (xr - fm) / (xr * q);
+        let lamr = a * (1.0 + a / 2.0);
+        let p2 = p1 * (1.0 + 2.0 * c);
+        let p3 = p2 + c / laml;
+        let p4 = p3 + c / lamr;
+        let nrq = nf * r * q;
+
+        loop {
+            let u = self.next_f64() * p4;
+            let mut v = self.next_f64();
+            let y;
+            if u <= p1 {
+                // triangular region: always accepted
+                return (xm - p1 * v + u).floor() as u64;
+            } else if u <= p2 {
+                let x = xl + (u - p1) / c;
+                v = v * c + 1.0 - (m - x + 0.5).abs() / p1;
+                if v > 1.0 {
+                    continue;
+                }
+                y = x.floor();
+            } else if u <= p3 {
+                y = (xl + v.ln() / laml).floor();
+                if y < 0.0 || v == 0.0 {
+                    continue;
+                }
+                v *= (u - p2) * laml;
+            } else {
+                y = (xr - v.ln() / lamr).floor();
+                if y > nf || v == 0.0 {
+                    continue;
+                }
+                v *= (u - p3) * lamr;
+            }
+
+            let k = (y - m).abs();
+            if k <= 20.0 || k >= nrq / 2.0 - 1.0 {
+                // explicit evaluation of f(y) / f(m) by the PMF recurrence
+                let s = r / q;
+                let a = s * (nf + 1.0);
+                let mut f = 1.0;
+                if m < y {
+                    let mut i = m + 1.0;
+                    while i <= y {
+                        f *= a / i - s;
+                        i += 1.0;
+                    }
+                } else if m > y {
+                    let mut i = y + 1.0;
+                    while i <= m {
+                        f /= a / i - s;
+                        i += 1.0;
+                    }
+                }
+                if v > f {
+                    continue;
+                }
+                return y as u64;
+            }
+
+            // squeeze on ln f(y) / f(m), then the final test with Stirling's formula
+            let rho = (k / nrq) * ((k * (k / 3.0 + 0.625) + 0.166_666_666_666_666_66) / nrq + 0.5);
+            let t = -k * k / (2.0 * nrq);
+            let a = v.ln();
+            if a < t - rho {
+                return y as u64;
+            }
+            if a > t + rho {
+                continue;
+            }
+            let x1 = y + 1.0;
+            let f1 = m + 1.0;
+            let z = nf + 1.0 - m;
+            let w = nf - y + 1.0;
+            let stirling = |x: f64| {
+                let x2 = x * x;
+                (13680.0 - (462.0 - (132.0 - (99.0 - 140.0 / x2) / x2) / x2) / x2) / x / 166320.0
+            };
+            let bound = xm * (f1 / x1).ln()
+                + (nf - m + 0.5) * (z / w).ln()
+                + (y - m) * (w * r / (x1 * q)).ln()
+                + stirling(f1)
+                + stirling(z)
+                + stirling(x1)
+                + stirling(w);
+            if a <= bound {
+                return y as u64;
+            }
+        }
+    }
+
+    /// Poisson with mean `lam` in `[0, POISSON_LAM_MAX]`.
+    pub fn poisson(&mut self, lam: f64) -> Result<u64, MRndErr> {
+        require(lam >= 0.0, "poisson", "lam must be >= 0", lam)?;
+        require(
+            lam <= POISSON_LAM_MAX,
+            "poisson",
+            "lam must be <= POISSON_LAM_MAX",
+            lam,
+        )?;
+        Ok(self.poisson_unchecked(lam))
+    }
+
+    pub(crate) fn poisson_unchecked(&mut self, lam: f64) -> u64 {
+        if lam >= 10.0 {
+            return self.poisson_ptrs(lam);
+        }
+        if lam == 0.0 {
+            return 0;
+        }
+        // multiply uniforms until the product drops below exp(-lam)
+        let enlam = (-lam).exp();
+        let mut x = 0;
+        let mut prod = 1.0;
+        loop {
+            prod *= self.next_f64();
+            if prod > enlam {
+                x += 1;
+            } else {
+                return x;
+            }
+        }
+    }
+
+    /// PTRS: transformed rejection with squeeze, for `lam >= 10`.
+    fn poisson_ptrs(&mut self, lam: f64) -> u64 {
+        let slam = lam.sqrt();
+        let loglam = lam.ln();
+        let b = 0.931 + 2.53 * slam;
+        let a = -0.059 + 0.02483 * b;
+        let invalpha = 1.1239 + 1.1328 / (b - 3.4);
+        let vr = 0.9277 - 3.6224 / (b - 2.0);
+
+        loop {
+            let u = self.next_f64() - 0.5;
+            let v = self.next_f64();
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lam + 0.43).floor();
+            if us >= 0.07 && v <= vr {
+                return k as u64;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+            if v.ln() + invalpha.ln() - (a / (us * us) + b).ln()
+                <= -lam + k * loglam - loggam(k + 1.0)
+            {
+                return k as u64;
+            }
+        }
+    }
+
+    /// Geometric: trials up to and including the first success, `p` in `(0, 1]`.
+    pub fn geometric(&mut self, p: f64) -> Result<u64, MRndErr> {
+        require(p > 0.0 && p <= 1.0, "geometric", "p must be in (0, 1]", p)?;
+        if p >= 0.333_333_333_333_333_3 {
+            // short sequential search
+            let mut x = 1;
+            let mut sum = p;
+            let mut prod = p;
+            let u = self.next_f64();
+            while u > sum {
+                prod *= 1.0 - p;
+                sum += prod;
+                x += 1;
+            }
+            return Ok(x);
+        }
+        // inversion of the exponential; the cast saturates for astronomically long waits
+        Ok((-self.standard_exponential() / (-p).ln_1p()).ceil() as u64)
+    }
+
+    /// Negative binomial: failures before the `n`-th success (`n > 0`, may be fractional),
+    /// success probability `p` in `(0, 1]`. Sampled as a gamma–Poisson mixture.
+    pub fn negative_binomial(&mut self, n: f64, p: f64) -> Result<u64, MRndErr> {
+        require(n > 0.0, "negative_binomial", "n must be > 0", n)?;
+        require(
+            p > 0.0 && p <= 1.0,
+            "negative_binomial",
+            "p must be in (0, 1]",
+            p,
+        )?;
+        let scale = (1.0 - p) / p;
+        require(
+            n * scale <= POISSON_LAM_MAX,
+            "negative_binomial",
+            "n * (1 - p) / p must be <= POISSON_LAM_MAX",
+            n * scale,
+        )?;
+        let y = self.standard_gamma_unchecked(n) * scale;
+        // the gamma tail can still pass the mean's bound
+        require(
+            y <= POISSON_LAM_MAX,
+            "negative_binomial",
+            "gamma draw is too large",
+            y,
+        )?;
+        Ok(self.poisson_unchecked(y))
+    }
+
+    /// Hypergeometric: good items among `nsample` drawn without replacement from `ngood` good
+    /// and `nbad` bad ones; `nsample <= ngood + nbad`.
+    pub fn hypergeometric(&mut self, ngood: u64, nbad: u64, nsample: u64) -> Result<u64, MRndErr> {
+        let total = ngood.checked_add(nbad).ok_or_else(|| {
+            MRndErr::InvalidArgument(format!(
+                "hypergeometric: ngood + nbad overflows (got {ngood} + {nbad})"
+            ))
+        })?;
+        if nsample > total {
+            return Err(MRndErr::InvalidArgument(format!(
+                "hypergeometric: nsample must be <= ngood + nbad (got {nsample} > {total})"
+            )));
+        }
+        if nsample >= 10 && nsample <= total - 10 {
+            Ok(self.hypergeometric_hrua(ngood, nbad, nsample))
+        } else {
+            Ok(self.hypergeometric_urn(ngood, nbad, nsample))
+        }
+    }
+
+    /// Draw from the urn one item at a time (or the complement when that is shorter).
+    fn hypergeometric_urn(&mut self, good: u64, bad: u64, sample: u64) -> u64 {
+        let total = good + bad;
+        let complement = sample > total / 2;
+        let mut selected = if complement { total - sample } else { sample };
+        let mut remaining_total = total;
+        let mut remaining_good = good;
+        while selected > 0 && remaining_good > 0 && remaining_total > remaining_good {
+            remaining_total -= 1;
+            if self.bounded_u64(remaining_total + 1, &mut None) < remaining_good {
+                remaining_good -= 1;
+            }
+            selected -= 1;
+        }
+        if remaining_total == remaining_good {
+            // only good items left: the rest of the selection is all good
+            remaining_good -= selected;
+        }
+        if complement {
+            remaining_good
+        } else {
+            good - remaining_good
+        }
+    }
+
+    /// HRUA: ratio of uniforms with the log-factorial PMF; `10 <= sample <= total - 10`.
+    fn hypergeometric_hrua(&mut self, good: u64, bad: u64, sample: u64) -> u64 {
+        const D1: f64 = 1.715_527_769_921_413_5;
+        const D2: f64 = 0.898_916_162_058_898_8;
+
+        let popsize = good + bad;
+        let computed_sample = sample.min(popsize - sample);
+        let min_gb = good.min(bad);
+        let max_gb = good.max(bad);
+        let (popf, csf, minf) = (popsize as f64, computed_sample as f64, min_gb as f64);
+
+        let p = minf / popf;
+        let q = max_gb as f64 / popf;
+        let a = csf * p + 0.5;
+        let var = (popf - csf) * csf * p * q / (popf - 1.0);
+        let c = (var + 0.5).sqrt();
+        let h = D1 * c + D2;
+        let m = ((csf + 1.0) * (minf + 1.0) / (popf + 2.0)).floor() as u64;
+        let log_pmf = |k: u64| {
+            logfactorial(k)
+                + logfactorial(min_gb - k)
+                + logfactorial(computed_sample - k)
+                + logfactorial(max_gb - computed_sample + k)
+        };
+        let g = log_pmf(m);
+        // the tail beyond 16 standard deviations is negligible
+        let b = ((computed_sample.min(min_gb) + 1) as f64).min((a + 16.0 * c).floor());
+
+        let mut k = loop {
+            let u = self.next_f64();
+            let v = self.next_f64();
+            let x = a + h * (v - 0.5) / u;
+            if !(0.0..b).contains(&x) {
+                continue;
+            }
+            let k = x.floor() as u64;
+            let t = g - log_pmf(k);
+            if u * (4.0 - u) - 3.0 <= t {
+                break k;
+            }
+            if u * (u - t) >= 1.0 {
+                continue;
+            }
+            if 2.0 * u.ln() <= t {
+                break k;
+            }
+        };
+        if good > bad {
+            k = computed_sample - k;
+        }
+        if computed_sample < sample {
+            k = good - k;
+        }
+        k
+    }
+
+    /// Logarithmic series with `p` in `[0, 1)`; `P(k) = -p^k / (k ln(1 - p))` for `k >= 1`.
+    pub fn logseries(&mut self, p: f64) -> Result<u64, MRndErr> {
+        require(
+            (0.0..1.0).contains(&p),
+            "logseries",
+            "p must be in [0, 1)",
+            p,
+        )?;
+        // Kemp's second accelerated generator (LK)
+        let r = (-p).ln_1p();
+        loop {
+            let v = self.next_f64();
+            if v >= p {
+                return Ok(1);
+            }
+            let u = self.next_f64();
+            let q = -(r * u).exp_m1();
+            if v <= q * q {
+                let x = (1.0 + v.ln() / q.ln()).floor();
+                if x < 1.0 || v == 0.0 {
+                    continue;
+                }
+                return Ok(x as u64);
+            }
+            return Ok(if v >= q { 1 } else { 2 });
+        }
+    }
+
+    /// Zipf (zeta) with exponent `a > 1`; `P(k) = k^-a / zeta(a)` for `k >= 1`.
+    pub fn zipf(&mut self, a: f64) -> Result<u64, MRndErr> {
+        require(a > 1.0, "zipf", "a must be > 1", a)?;
+        // rejection from the continuous Pareto (Devroye 1986, X.6)
+        let am1 = a - 1.0;
+        let b = 2f64.powf(am1);
+        loop {
+            let u = self.next_f64_oc();
+            let v = self.next_f64();
+            let x = u.powf(-1.0 / am1).floor();
+            // reject results that cannot be represented instead of clipping them
+            if !(1.0..=i64::MAX as f64).contains(&x) {
+                continue;
+            }
+            let t = (1.0 + 1.0 / x).powf(am1);
+            if v * x * (t - 1.0) / (b - 1.0) <= t / b {
+                return Ok(x as u64);
+            }
+        }
+    }
+
+    /// Multinomial: counts of `n` draws over the outcomes with probabilities `pvals`. The last
+    /// probability is implied (`1 - sum` of the others), as in numpy.
+    pub fn multinomial(&mut self, n: u64, pvals: &[f64]) -> Result<Vec<u64>, MRndErr> {
+        let mut out = vec![0; pvals.len()];
+        self.multinomial_into(n, pvals, &mut out)?;
+        Ok(out)
+    }
+
+    /// `multinomial` into a caller-provided buffer of the same length as `pvals`.
+    pub fn multinomial_into(
+        &mut self,
+        n: u64,
+        pvals: &[f64],
+        out: &mut [u64],
+    ) -> Result<(), MRndErr> {
+        if pvals.is_empty() || pvals.len() != out.len() {
+            return Err(MRndErr::InvalidArgument(format!(
+                "multinomial: need a non-empty pvals and an output of the same length (got {} and {})",
+                pvals.len(),
+                out.len()
+            )));
+        }
+        for &p in pvals {
+            require_prob(p, "multinomial")?;
+        }
+        let head = &pvals[..pvals.len() - 1];
+        let sum = head.iter().sum::<f64>();
+        require(
+            sum <= 1.0 + 1e-12,
+            "multinomial",
+            "sum(pvals[:-1]) must be <= 1",
+            sum,
+        )?;
+
+        // conditional binomials: each count given the ones before it
+        out.fill(0);
+        let mut remaining_p = 1.0;
+        let mut dn = n;
+        for (x, &p) in out.iter_mut().zip(head) {
+            if dn == 0 {
+                return Ok(());
+            }
+            *x = self.binomial_unchecked(dn, (p / remaining_p).clamp(0.0, 1.0));
+            dn -= *x;
+            remaining_p -= p;
+        }
+        out[pvals.len() - 1] = dn;
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Multivariate distributions on `MPRng`: the multivariate normal and the Dirichlet, with
+//! numpy's `Generator` names. `multinomial` lives with the discrete distributions.
+//!
+//! Matrices are flat, row-major slices: a `d x d` covariance is `d * d` values.
+use crate::dist::continuous::require;
+use crate::{MBitGen, MPRng, MRndErr};
+
+/// Relative tolerance for the symmetry and positive semi-definiteness checks.
+const COV_TOL: f64 = 1e-8;
+
+fn invalid(msg: String) -> MRndErr {
+    MRndErr::InvalidArgument(format!("multivariate_normal: {msg}"))
+}
+
+/// Lower triangular `L` with `L L^T = a`, or `None` if `a` is not positive definite or so
+/// close to singular that the factor would be inaccurate.
+fn cholesky(a: &[f64], d: usize) -> Option<Vec<f64>> {
+    let mut l = vec![0.0; d * d];
+    for i in 0..d {
+        for j in 0..=i {
+            let s = a[i * d + j] - (0..j).map(|k| l[i * d + k] * l[j * d + k]).sum::<f64>();
+            if i == j {
+                // a pivot that cancelled down to rounding noise means (near) rank deficiency
+                if s <= COV_TOL * a[i * d + i] {
+                    return None;
+                }
+                l[i * d + i] = s.sqrt();
+            } else {
+                l[i * d + j] = s / l[j * d + j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Eigenvalues and eigenvectors (the columns of the returned row-major matrix) of the
+/// symmetric `a`, by cyclic Jacobi rotations.
+fn symmetric_eigen(a: &[f64], d: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = a.to_vec();
+    let mut v = vec![0.0; d * d];
+    for i in 0..d {
+        v[i * d + i] = 1.0;
+    }
+    let norm = a.iter().map(|x| x * x).sum::<f64>();
+    for _sweep in 0..100 {
+        let off = (0..d)
+            .flat_map(|i| (0..d).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * d + j] * a[i * d + j])
+            .sum::<f64>();
+        if off <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..d {
+            for q in p + 1..d {
+                let apq = a[p * d + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                // rotate by theta with tan(2 theta) = 2 a_pq / (a_qq - a_pp), zeroing a_pq
+                let tau = (a[q * d + q] - a[p * d + p]) / (2.0 * apq);
+                let t = tau.signum() / (tau.abs() + (1.0 + tau * tau).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = t * c;
+                for k in 0..d {
+                    let (akp, akq) = (a[k * d + p], a[k * d + q]);
+                    a[k * d + p] = c * akp - s * akq;
+                    a[k * d + q] = s * akp + c * akq;
+                }
+                for k in 0..d {
+                    let (apk, aqk) = (a[p * d + k], a[q * d + k]);
+                    a[p * d + k] = c * apk - s * aqk;
+                    a[q * d + k] = s * apk + c * aqk;
+                }
+                for k in 0..d {
+                    let (vkp, vkq) = (v[k * d + p], v[k * d + q]);
+                    v[k * d + p] = c * vkp - s * vkq;
+                    v[k * d + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..d).map(|i| a[i * d + i]).collect(), v)
+}
+
+/// A multivariate normal with a factored covariance, for drawing many vectors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    /// `A` with `A A^T = cov`, row-major `d x d`.
+    factor: Vec<f64>,
+}
+
+impl MultivariateNormal {
+    /// `mean` of length `d` and a symmetric positive semi-definite `d x d` covariance.
+    /// Positive definite covariances use the Cholesky factor; singular (semi-definite) ones
+    /// fall back to an eigendecomposition.
+    pub fn new(mean: &[f64], cov: &[f64]) -> Result<Self, MRndErr> {
+        let d = mean.len();
+        if d == 0 {
+            return Err(invalid(String::from("mean must not be empty")));
+        }
+        if cov.len() != d * d {
+            return Err(invalid(format!(
+                "cov must have {} values for a mean of length {d} (got {})",
+                d * d,
+                cov.len()
+            )));
+        }
+        if let Some(x) = mean.iter().chain(cov).find(|x| !x.is_finite()) {
+            return Err(invalid(format!("mean and cov must be finite (got {x})")));
+        }
+        let scale = cov.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+        for i in 0..d {
+            for j in 0..i {
+                let (a, b) = (cov[i * d + j], cov[j * d + i]);
+                if (a - b).abs() > COV_TOL * scale {
+                    return Err(invalid(format!(
+                        "cov must be symmetric (cov[{i}][{j}] = {a}, cov[{j}][{i}] = {b})"
+                    )));
+                }
+            }
+        }
+
+        let factor = match cholesky(cov, d) {
+            Some(l) => l,
+            None => {
+                let (values, vectors) = symmetric_eigen(cov, d);
+                let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+                if min < -COV_TOL * scale.max(f64::MIN_POSITIVE) {
+                    return Err(invalid(format!(
+                        "cov must be positive semi-definite (eigenvalue {min})"
+                    )));
+                }
+                // V sqrt(diag(lambda)), clipping the rounding below zero
+                let mut f = vectors;
+                for (j, &lambda) in values.iter().enumerate() {
+                    let s = lambda.max(0.0).sqrt();
+                    for i in 0..d {
+                        f[i * d + j] *= s;
+                    }
+                }
+                f
+            }
+        };
+        Ok(Self {
+            mean: mean.to_vec(),
+            factor,
+        })
+    }
+
+    /// Dimension of the vectors.
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Fill `out` (of length `dim`) with one draw; `dim` standard normals from the ziggurat.
+    pub fn sample_into<G: MBitGen>(
+        &self,
+        rng: &mut MPRng<G>,
+        out: &mut [f64],
+    ) -> Result<(), MRndErr> {
+        let d = self.dim();
+        if out.len() != d {
+            return Err(invalid(format!(
+                "output must have length {d} (got {})",
+                out.len()
+            )));
+        }
+        let z: Vec<f64> = (0..d).map(|_| rng.standard_normal()).collect();
+        for (i, x) in out.iter_mut().enumerate() {
+            let row = &self.factor[i * d..(i + 1) * d];
+            *x = self.mean[i] + row.iter().zip(&z).map(|(a, z)| a * z).sum::<f64>();
+        }
+        Ok(())
+    }
+
+    /// One draw as an owned vector.
+    pub fn sample<G: MBitGen>(&self, rng: &mut MPRng<G>) -> Vec<f64> {
+        let mut out = vec![0.0; self.dim()];
+        self.sample_into(rng, &mut out)
+            .expect("the buffer has the right length");
+        out
+    }
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// One draw from the multivariate normal with `mean` and row-major covariance `cov`.
+    /// Factoring `cov` costs O(d^3): for many draws build a `MultivariateNormal` once.
+    pub fn multivariate_normal(&mut self, mean: &[f64], cov: &[f64]) -> Result<Vec<f64>, MRndErr> {
+        Ok(MultivariateNormal::new(mean, cov)?.sample(self))
+    }
+
+    /// `multivariate_normal` into a caller-provided buffer of the same length as `mean`.
+    pub fn multivariate_normal_into(
+        &mut self,
+        mean: &[f64],
+        cov: &[f64],
+        out: &mut [f64],
+    ) -> Result<(), MRndErr> {
+        MultivariateNormal::new(mean, cov)?.sample_into(self, out)
+    }
+
+    /// A point on the simplex from the Dirichlet distribution with concentrations
+    /// `alpha > 0`.
+    pub fn dirichlet(&mut self, alpha: &[f64]) -> Result<Vec<f64>, MRndErr> {
+        let mut out = vec![0.0; alpha.len()];
+        self.dirichlet_into(alpha, &mut out)?;
+        Ok(out)
+    }
+
+    /// `dirichlet` into a caller-provided buffer of the same length as `alpha`.
+    pub fn dirichlet_into(&mut self, alpha: &[f64], out: &mut [f64]) -> Result<(), MRndErr> {
+        if alpha.is_empty() || alpha.len() != out.len() {
+            return Err(MRndErr::InvalidArgument(format!(
+                "dirichlet: need a non-empty alpha and an output of the same length (got {} and {})",
+                alpha.len(),
+                out.len()
+            )));
+        }
+        for &a in alpha {
+            require(
+                a > 0.0 && a.is_finite(),
+                "dirichlet",
+                "alpha must be > 0 and finite",
+                a,
+            )?;
+        }
+
+        if alpha.iter().all(|&a| a < 0.1) {
+            // small alphas: the gammas would underflow to 0, break a stick with betas instead
+            let k = alpha.len();
+            let mut rest: f64 = alpha.iter().sum();
+            let mut remaining = 1.0;
+            for j in 0..k - 1 {
+                rest -= alpha[j];
+                let v = self.beta(alpha[j], rest.max(f64::MIN_POSITIVE))?;
+                out[j] = remaining * v;
+                remaining *= 1.0 - v;
+            }
+            out[k - 1] = remaining;
+            return Ok(());
+        }
+
+        let mut sum = 0.0;
+        for (x, &a) in out.iter_mut().zip(alpha) {
+            *x = self.standard_gamma_unchecked(a);
+            sum += *x;
+        }
+        for x in out.iter_mut() {
+            *x /= sum;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+//! Prebuilt weighted index samplers, for drawing many times from one discrete distribution.
+//!
+//! `AliasTable` is Vose's alias method ("A Linear Algorithm for Generating Random Numbers with
+//! a Given Distribution", 1991): O(n) setup, then O(1) per sample with one column pick and one
+//! biased coin. Integer weights get an exact table (every threshold is an integer over the
+//! weight total). `FenwickWeightedIndex` keeps the weights in a Fenwick (binary indexed) tree
+//! instead: O(log n) per sample, but a weight can be changed in O(log n) without a rebuild.
+use crate::{MBitGen, MPRng, MRndErr};
+
+fn check_weight(i: usize, w: f64) -> Result<(), MRndErr> {
+    if w >= 0.0 && w.is_finite() {
+        Ok(())
+    } else {
+        Err(MRndErr::InvalidArgument(format!(
+            "weights: weight at index {i} must be finite and >= 0 (got {w})"
+        )))
+    }
+}
+
+fn check_len(n: usize) -> Result<(), MRndErr> {
+    if n == 0 {
+        return Err(MRndErr::InvalidArgument(String::from(
+            "weights: need at least one weight",
+        )));
+    }
+    Ok(())
+}
+
+/// The coins of the alias columns.
+#[derive(Debug, Clone, PartialEq)]
+enum Coins {
+    /// Probability of keeping the column.
+    Float(Vec<f64>),
+    /// Keep the column if a uniform draw in `[0, total)` is below the threshold.
+    Int { threshold: Vec<u64>, total: u64 },
+}
+
+/// Vose's alias table over `0..n` with probabilities proportional to the given weights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasTable {
+    coins: Coins,
+    alias: Vec<usize>,
+    /// Lemire rejection threshold for picking one of the n columns.
+    col_threshold: u64,
+}
+
+/// The name `rand` uses for a prebuilt weighted index; here it is the alias table.
+pub type WeightedIndex = AliasTable;
+
+impl AliasTable {
+    /// Build from non-negative, finite weights that need not sum to 1; at least one must be
+    /// positive.
+    pub fn new(weights: &[f64]) -> Result<Self, MRndErr> {
+        check_len(weights.len())?;
+        for (i, &w) in weights.iter().enumerate() {
+            check_weight(i, w)?;
+        }
+        let sum = weights.iter().sum::<f64>();
+        if !(sum > 0.0 && sum.is_finite()) {
+            return Err(MRndErr::InvalidArgument(format!(
+                "weights: sum must be finite and > 0 (got {sum})"
+            )));
+        }
+
+        let n = weights.len();
+        let scale = n as f64 / sum;
+        let mut p: Vec<f64> = weights.iter().map(|&w| w * scale).collect();
+        let mut prob = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| p[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            prob[s] = p[s];
+            alias[s] = l;
+            // the large column donates what the small one lacks
+            p[l] = (p[l] + p[s]) - 1.0;
+            if p[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // whatever is left is 1 up to rounding and keeps its own column
+
+        Ok(Self::with_coins(Coins::Float(prob), alias))
+    }
+
+    /// Build from integer weights; sampling is then exact. The total must fit in a `u64`.
+    pub fn from_int_weights(weights: &[u64]) -> Result<Self, MRndErr> {
+        check_len(weights.len())?;
+        let total = weights
+            .iter()
+            .try_fold(0u64, |acc, &w| acc.checked_add(w))
+            .ok_or_else(|| MRndErr::InvalidArgument(String::from("weights: sum overflows u64")))?;
+        if total == 0 {
+            return Err(MRndErr::InvalidArgument(String::from(
+                "weights: sum must be > 0 (got 0)",
+            )));
+        }
+
+        // the same construction in units of total / n, so that every column holds `total`
+        let n = weights.len();
+        let total_w = total as u128;
+        let mut p: Vec<u128> = weights.iter().map(|&w| w as u128 * n as u128).collect();
+        let mut threshold = vec![total; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| p[i] < total_w);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            threshold[s] = p[s] as u64;
+            alias[s] = l;
+            p[l] -= total_w - p[s];
+            if p[l] < total_w {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // exact arithmetic: the lists run out together
+
+        Ok(Self::with_coins(Coins::Int { threshold, total }, alias))
+    }
+
+    fn with_coins(coins: Coins, alias: Vec<usize>) -> Self {
+        let n = alias.len() as u64;
+        Self {
+            coins,
+            alias,
+            col_threshold: n.wrapping_neg() % n,
+        }
+    }
+
+    /// Number of outcomes.
+    pub fn len(&self) -> usize {
+        self.alias.len()
+    }
+
+    /// Always false: a table has at least one outcome.
+    pub fn is_empty(&self) -> bool {
+        self.alias.is_empty()
+    }
+
+    /// Probability of outcome `i` (0 outside the table).
+    pub fn probability(&self, i: usize) -> f64 {
+        let n = self.len();
+        if i >= n {
+            return 0.0;
+        }
+        let coin = |j: usize| match &self.coins {
+            Coins::Float(prob) => prob[j],
+            Coins::Int { threshold, total } => threshold[j] as f64 / *total as f64,
+        };
+        let from_alias: f64 = (0..n)
+            .filter(|&j| self.alias[j] == i && j != i)
+            .map(|j| 1.0 - coin(j))
+            .sum();
+        (coin(i) + from_alias) / n as f64
+    }
+
+    /// One index, distributed like the weights.
+    #[inline]
+    pub fn sample<G: MBitGen>(&self, rng: &mut MPRng<G>) -> usize {
+        let col = rng.bounded_u64(self.len() as u64, &mut Some(self.col_threshold)) as usize;
+        let keep = match &self.coins {
+            Coins::Float(prob) => rng.next_f64() < prob[col],
+            Coins::Int { threshold, total } => rng.bounded_u64(*total, &mut None) < threshold[col],
+        };
+        if keep { col } else { self.alias[col] }
+    }
+
+    /// Fill `out` with indices; the same values as repeated `sample`.
+    pub fn fill<G: MBitGen>(&self, rng: &mut MPRng<G>, out: &mut [usize]) {
+        for x in out.iter_mut() {
+            *x = self.sample(rng);
+        }
+    }
+}
+
+/// Weighted index over `0..n` whose weights can be changed after construction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FenwickWeightedIndex {
+    weights: Vec<f64>,
+    /// 1-based Fenwick tree: `tree[i]` is the sum of the weights in `(i - lowbit(i), i]`.
+    tree: Vec<f64>,
+    /// Updates since the last rebuild; rebuilding every n updates bounds the rounding drift
+    /// at O(1) amortized cost.
+    updates: usize,
+    /// Largest total since the last rebuild. The drift is relative to it, so the tree is
+    /// also rebuilt when the total falls well below it.
+    peak: f64,
+    /// Number of positive weights; the tree total may not be exactly 0 when this is.
+    positive: usize,
+}
+
+impl FenwickWeightedIndex {
+    /// Build from non-negative, finite weights; all of them may be zero until an `update`.
+    pub fn new(weights: &[f64]) -> Result<Self, MRndErr> {
+        check_len(weights.len())?;
+        for (i, &w) in weights.iter().enumerate() {
+            check_weight(i, w)?;
+        }
+        let mut index = Self {
+            weights: weights.to_vec(),
+            tree: Vec::new(),
+            updates: 0,
+            peak: 0.0,
+            positive: weights.iter().filter(|&&w| w > 0.0).count(),
+        };
+        index.rebuild();
+        Ok(index)
+    }
+
+    /// Recompute the tree from the weights in O(n), dropping accumulated rounding.
+    fn rebuild(&mut self) {
+        let n = self.weights.len();
+        self.updates = 0;
+        self.tree = vec![0.0; n + 1];
+        self.tree[1..].copy_from_slice(&self.weights);
+        for i in 1..=n {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= n {
+                self.tree[parent] += self.tree[i];
+            }
+        }
+        self.peak = self.total();
+    }
+
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Always false: an index has at least one outcome.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    pub fn weight(&self, i: usize) -> Option<f64> {
+        self.weights.get(i).copied()
+    }
+
+    /// Sum of all weights.
+    pub fn total(&self) -> f64 {
+        let mut i = self.len();
+        let mut sum = 0.0;
+        while i > 0 {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// Set the weight of outcome `i`.
+    pub fn update(&mut self, i: usize, weight: f64) -> Result<(), MRndErr> {
+        let n = self.len();
+        if i >= n {
+            return Err(MRndErr::InvalidArgument(format!(
+                "weights: index {i} out of range for {n} weights"
+            )));
+        }
+        check_weight(i, weight)?;
+        let delta = weight - self.weights[i];
+        self.positive = self.positive + (weight > 0.0) as usize - (self.weights[i] > 0.0) as usize;
+        self.weights[i] = weight;
+        let mut j = i + 1;
+        while j <= n {
+            self.tree[j] += delta;
+            j += j & j.wrapping_neg();
+        }
+        self.updates += 1;
+        let total = self.total();
+        self.peak = self.peak.max(total);
+        if self.updates >= n || total < 0.5 * self.peak {
+            self.rebuild();
+        }
+        Ok(())
+    }
+
+    /// One index, distributed like the current weights; an error if they are all zero.
+    pub fn sample<G: MBitGen>(&self, rng: &mut MPRng<G>) -> Result<usize, MRndErr> {
+        if self.positive == 0 {
+            return Err(MRndErr::InvalidArgument(String::from(
+                "weights: all weights are zero",
+            )));
+        }
+        let total = self.total();
+        let n = self.len();
+        let top = 1usize << (usize::BITS - 1 - n.leading_zeros());
+        loop {
+            // descend to the first prefix sum above the target
+            let mut target = rng.next_f64() * total;
+            let mut pos = 0;
+            let mut step = top;
+            while step > 0 {
+                let next = pos + step;
+                if next <= n && self.tree[next] <= target {
+                    target -= self.tree[next];
+                    pos = next;
+                }
+                step >>= 1;
+            }
+            // rounding in the tree can land just past the end or on a zero weight
+            if pos < n && self.weights[pos] > 0.0 {
+                return Ok(pos);
+            }
+        }
+    }
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// `choice_idx` with a prebuilt table: no validation and O(1) per call.
+    pub fn choice_idx_table(&mut self, table: &AliasTable) -> usize {
+        table.sample(self)
+    }
+
+    /// `choice` with a prebuilt table over `elements`.
+    pub fn choice_table<'a, T>(
+        &mut self,
+        elements: &'a [T],
+        table: &AliasTable,
+    ) -> Result<&'a T, MRndErr> {
+        if elements.len() != table.len() {
+            return Err(MRndErr::InvalidArgument(format!(
+                "The elements and table must have same length (got {} and {})",
+                elements.len(),
+                table.len()
+            )));
+        }
+        Ok(&elements[table.sample(self)])
+    }
+}
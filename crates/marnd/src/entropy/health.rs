@@ -0,0 +1,147 @@
+//! Continuous health tests of NIST SP 800-90B, section 4.4, on the bytes of an entropy
+//! source.
+//!
+//! Every byte is a sample. The repetition count test catches a source stuck on one value, the
+//! adaptive proportion test one that repeats a value far more often than its claimed
+//! min-entropy allows. Both cutoffs follow from that claim `H` (bits per byte) and the false
+//! positive probability `alpha` per sample.
+use crate::{EntropyErr, MRndErr};
+
+/// Samples per adaptive proportion window, the standard's value for non-binary samples.
+pub const APT_WINDOW: u32 = 512;
+
+/// `-log2(alpha)` of `HealthTests::new`: one false alarm per 2^40 bytes.
+const ALPHA_LOG2: f64 = 40.0;
+
+#[derive(Debug, Clone)]
+pub struct HealthTests {
+    rct_cutoff: u32,
+    apt_cutoff: u32,
+    last: u8,
+    run: u32,
+    apt_value: u8,
+    apt_count: u32,
+    apt_seen: u32,
+}
+
+impl HealthTests {
+    /// Cutoffs for a source claiming `min_entropy` bits per byte, in (0, 8], with
+    /// `alpha = 2^-40`.
+    pub fn new(min_entropy: f64) -> Result<Self, MRndErr> {
+        Self::with_alpha(min_entropy, ALPHA_LOG2)
+    }
+
+    /// Cutoffs for a false positive probability of `2^-alpha_log2` per sample.
+    pub fn with_alpha(min_entropy: f64, alpha_log2: f64) -> Result<Self, MRndErr> {
+        if !(0.0..=8.0).contains(&min_entropy) || min_entropy == 0.0 {
+            return Err(MRndErr::InvalidArgument(format!(
+                "min-entropy must be in (0, 8] bits per byte, got {min_entropy}"
+            )));
+        }
+        if !(1.0..=64.0).contains(&alpha_log2) {
+            return Err(MRndErr::InvalidArgument(format!(
+                "-log2(alpha) must be in [1, 64], got {alpha_log2}"
+            )));
+        }
+        // 4.4.1: C = 1 + ceil(-log2(alpha) / H); a claim so small that C does not fit a u32
+        // would never trip the test anyway.
+        let rct_runs = (alpha_log2 / min_entropy).ceil();
+        if rct_runs >= u32::MAX as f64 {
+            return Err(MRndErr::InvalidArgument(format!(
+                "min-entropy {min_entropy} is too small for -log2(alpha) = {alpha_log2}"
+            )));
+        }
+        Ok(Self {
+            rct_cutoff: 1 + rct_runs as u32,
+            // 4.4.2: C = 1 + CRITBINOM(W, 2^-H, 1 - alpha)
+            apt_cutoff: 1 + crit_binom(APT_WINDOW, (-min_entropy).exp2(), alpha_log2),
+            last: 0,
+            run: 0,
+            apt_value: 0,
+            apt_count: 0,
+            apt_seen: 0,
+        })
+    }
+
+    /// Length of a run of identical bytes that fails the repetition count test.
+    pub fn rct_cutoff(&self) -> u32 {
+        self.rct_cutoff
+    }
+
+    /// Count of the first byte of a window that fails the adaptive proportion test.
+    pub fn apt_cutoff(&self) -> u32 {
+        self.apt_cutoff
+    }
+
+    /// Run both tests over `bytes`, continuing the runs and the window of the previous call.
+    /// The tests start over after a failure.
+    pub fn check(&mut self, name: &'static str, bytes: &[u8]) -> Result<(), EntropyErr> {
+        for &b in bytes {
+            if self.run > 0 && b == self.last {
+                self.run += 1;
+            } else {
+                self.last = b;
+                self.run = 1;
+            }
+            if self.run >= self.rct_cutoff {
+                let run = self.run;
+                self.reset();
+                return Err(EntropyErr::RepetitionCount {
+                    name,
+                    run,
+                    cutoff: self.rct_cutoff,
+                });
+            }
+
+            if self.apt_seen == 0 {
+                self.apt_value = b;
+                self.apt_count = 1;
+            } else if b == self.apt_value {
+                self.apt_count += 1;
+            }
+            self.apt_seen += 1;
+            if self.apt_count >= self.apt_cutoff {
+                let count = self.apt_count;
+                self.reset();
+                return Err(EntropyErr::AdaptiveProportion {
+                    name,
+                    count,
+                    window: APT_WINDOW,
+                    cutoff: self.apt_cutoff,
+                });
+            }
+            if self.apt_seen == APT_WINDOW {
+                self.apt_seen = 0;
+            }
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.run = 0;
+        self.apt_seen = 0;
+    }
+}
+
+/// Smallest `k` with `P(X > k) <= 2^-alpha_log2` for `X ~ Binomial(n, p)`, i.e. Excel's
+/// `CRITBINOM(n, p, 1 - alpha)` as used by the standard. The upper tail is summed directly,
+/// `1 - cdf` would lose `alpha` in the rounding.
+fn crit_binom(n: u32, p: f64, alpha_log2: f64) -> u32 {
+    let alpha = (-alpha_log2).exp2();
+    let (ln_p, ln_q) = (p.ln(), (-p).ln_1p());
+    let mut ln_choose = 0.0;
+    let pmf: Vec<f64> = (0..=n)
+        .map(|k| {
+            if k > 0 {
+                ln_choose += ((n - k + 1) as f64 / k as f64).ln();
+            }
+            (ln_choose + k as f64 * ln_p + (n - k) as f64 * ln_q).exp()
+        })
+        .collect();
+    let (mut k, mut tail) = (n, 0.0);
+    while k > 0 && tail + pmf[k as usize] <= alpha {
+        tail += pmf[k as usize];
+        k -= 1;
+    }
+    k
+}
@@ -1,39 +1,96 @@
-use crate::MRndErr;
-use std::fs::File;
-use std::io;
-use std::io::{Error, ErrorKind};
-use std::result::Result::Err;
+use crate::{CpuRng, DevURandom, EntropyErr, EntropySource, GetRandom, HealthTests, MRndErr};
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
 
+/// Samples put through the health tests before the first output (SP 800-90B 4.3).
+const STARTUP_SAMPLES: usize = 1024;
+
+/// Entropy sources tried in order, with the SP 800-90B health tests on their output.
+///
+/// A source that answers `EntropyErr::Unavailable` is skipped from then on; any other error,
+/// or output that fails a health test, is returned.
+#[derive(Debug)]
+pub struct EntropyChain {
+    sources: Vec<Box<dyn EntropySource>>,
+    active: usize,
+    unavailable: Vec<String>,
+    health: HealthTests,
+    started: bool,
+}
+
+impl EntropyChain {
+    pub fn new(sources: Vec<Box<dyn EntropySource>>, health: HealthTests) -> Self {
+        Self {
+            sources,
+            active: 0,
+            unavailable: vec![],
+            health,
+            started: false,
+        }
+    }
+
+    /// `getrandom(2)`, then `/dev/urandom`, then RDSEED/RDRAND, all claimed full entropy.
+    pub fn os_default() -> Self {
+        Self::new(
+            vec![
+                Box::new(GetRandom),
+                Box::new(DevURandom::new()),
+                Box::new(CpuRng),
+            ],
+            HealthTests::new(8.0).expect("8 bits per byte is a valid claim"),
+        )
+    }
+
+    /// The source that serves the next request, if any is left.
+    pub fn active_source(&self) -> Option<&'static str> {
+        self.sources.get(self.active).map(|s| s.name())
+    }
+
+    pub fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), MRndErr> {
+        if !self.started {
+            let mut startup = [0u8; STARTUP_SAMPLES];
+            self.draw(&mut startup)?;
+            self.started = true;
+        }
+        self.draw(out)
+    }
+
+    fn draw(&mut self, out: &mut [u8]) -> Result<(), MRndErr> {
+        while let Some(source) = self.sources.get_mut(self.active) {
+            match source.fill_bytes(out) {
+                Ok(()) => return Ok(self.health.check(source.name(), out)?),
+                Err(e @ EntropyErr::Unavailable { .. }) => {
+                    self.unavailable.push(e.to_string());
+                    self.active += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(EntropyErr::NoSource {
+            tried: self.unavailable.clone(),
+        }
+        .into())
+    }
+}
+
+static OS_ENTROPY: LazyLock<Mutex<EntropyChain>> =
+    LazyLock::new(|| Mutex::new(EntropyChain::os_default()));
+
+/// The process-wide `EntropyChain` that seeds generators built without a seed.
 pub struct MOSEntropy;
 
 impl MOSEntropy {
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub fn fill_bytes(out: &mut [u8]) -> Result<(), MRndErr> {
-        const PATH: &str = "/dev/urandom";
-        use std::fs::File;
-        use std::io::Read;
-
-        let mut f = File::open(PATH).map_err(|e| MRndErr::EntropyOpen {
-            path: PATH,
-            source: e,
-        })?;
-        f.read_exact(out)?;
-        Ok(())
-    }
-
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    pub fn fill_bytes(&mut self, out: &mut [u8]) -> io::Result<()> {
-        use std::env;
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            format!(
-                "{} is not implemented for the target OS '{}' of family '{}' on architecture '{}'",
-                std::any::type_name::<Self>().rsplit("::").next().unwrap(),
-                env::consts::OS,
-                env::consts::FAMILY,
-                env::consts::ARCH
-            ),
-        ))
+        Self::chain().fill_bytes(out)
+    }
+
+    /// Replace the chain, e.g. to add a hardware token or to drop a source.
+    pub fn install(chain: EntropyChain) {
+        *Self::chain() = chain;
+    }
+
+    // a panic cannot leave the chain half-updated, so a poisoned lock is still usable
+    fn chain() -> MutexGuard<'static, EntropyChain> {
+        OS_ENTROPY.lock().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
@@ -41,7 +98,6 @@ impl MOSEntropy {
     pub fn next_u64() -> Result<u64, MRndErr> {
         let mut buf = [0u8; 8];
         Self::fill_bytes(&mut buf)?;
-        println!("MOSEnropy::next_u64 is called. return data {:?}", buf);
         Ok(u64::from_ne_bytes(buf))
     }
 
@@ -0,0 +1,233 @@
+//! Entropy sources: the `getrandom(2)` system call, `/dev/urandom` and the RDSEED/RDRAND
+//! instructions.
+//!
+//! A source that does not exist on this machine answers `EntropyErr::Unavailable`, so that an
+//! `EntropyChain` can fall back to the next one. Sources never log.
+use crate::EntropyErr;
+use core::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+
+pub trait EntropySource: Send + fmt::Debug {
+    /// Name in errors, e.g. `"getrandom"`.
+    fn name(&self) -> &'static str;
+
+    /// Fill all of `out`; its content is unspecified on error.
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr>;
+}
+
+/// The kernel CSPRNG through `getrandom(2)` (`getentropy` on macOS), without a file
+/// descriptor. It blocks only until the kernel pool is first initialized after boot.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GetRandom;
+
+impl EntropySource for GetRandom {
+    fn name(&self) -> &'static str {
+        "getrandom"
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr> {
+        let mut filled = 0;
+        while filled < out.len() {
+            let rest = &mut out[filled..];
+            // the raw system call also works with a libc that has no getrandom wrapper
+            // SAFETY: `rest` is valid for writes of `rest.len()` bytes.
+            let n = unsafe { libc::syscall(libc::SYS_getrandom, rest.as_mut_ptr(), rest.len(), 0) };
+            if n < 0 {
+                let err = io::Error::last_os_error();
+                match err.raw_os_error() {
+                    Some(libc::EINTR) => continue,
+                    // kernels before 3.17, or a seccomp filter that forbids the call
+                    Some(libc::ENOSYS | libc::EPERM) => {
+                        return Err(EntropyErr::Unavailable {
+                            name: self.name(),
+                            reason: err.to_string(),
+                        });
+                    }
+                    _ => {
+                        return Err(EntropyErr::Failed {
+                            name: self.name(),
+                            err,
+                        });
+                    }
+                }
+            }
+            filled += n as usize;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr> {
+        // at most 256 bytes per call
+        for chunk in out.chunks_mut(256) {
+            // SAFETY: `chunk` is valid for writes of `chunk.len()` bytes.
+            if unsafe { libc::getentropy(chunk.as_mut_ptr().cast(), chunk.len()) } != 0 {
+                return Err(EntropyErr::Failed {
+                    name: self.name(),
+                    err: io::Error::last_os_error(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+    fn fill_bytes(&mut self, _out: &mut [u8]) -> Result<(), EntropyErr> {
+        Err(EntropyErr::Unavailable {
+            name: self.name(),
+            reason: format!(
+                "not implemented for the target OS '{}'",
+                std::env::consts::OS
+            ),
+        })
+    }
+}
+
+/// `/dev/urandom`, opened on first use and kept open.
+#[derive(Debug, Default)]
+pub struct DevURandom {
+    file: Option<File>,
+}
+
+impl DevURandom {
+    const PATH: &str = "/dev/urandom";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EntropySource for DevURandom {
+    fn name(&self) -> &'static str {
+        Self::PATH
+    }
+
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => match File::open(Self::PATH) {
+                Ok(file) => self.file.insert(file),
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied
+                    ) =>
+                {
+                    return Err(EntropyErr::Unavailable {
+                        name: Self::PATH,
+                        reason: e.to_string(),
+                    });
+                }
+                Err(err) => {
+                    return Err(EntropyErr::Failed {
+                        name: Self::PATH,
+                        err,
+                    });
+                }
+            },
+        };
+        file.read_exact(out).map_err(|err| EntropyErr::Failed {
+            name: Self::PATH,
+            err,
+        })
+    }
+}
+
+/// The CPU's own generator: RDSEED (conditioned entropy) when present, RDRAND (a DRBG reseeded
+/// from it) otherwise, on x86-64. Both may come up empty under load and are retried.
+///
+/// Some CPUs return all ones from a broken RDRAND; the repetition count test of an
+/// `EntropyChain` rejects that output.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CpuRng;
+
+impl CpuRng {
+    /// Whether RDSEED or RDRAND can be used on this machine.
+    pub fn is_available() -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            is_x86_feature_detected!("rdseed") || is_x86_feature_detected!("rdrand")
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            false
+        }
+    }
+}
+
+impl EntropySource for CpuRng {
+    fn name(&self) -> &'static str {
+        "rdseed/rdrand"
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr> {
+        let next: fn() -> Option<u64> = if is_x86_feature_detected!("rdseed") {
+            // SAFETY: `rdseed` only needs the RDSEED feature, detected just above.
+            || unsafe { x86::rdseed() }
+        } else if is_x86_feature_detected!("rdrand") {
+            // SAFETY: `rdrand` only needs the RDRAND feature, detected just above.
+            || unsafe { x86::rdrand() }
+        } else {
+            return Err(EntropyErr::Unavailable {
+                name: self.name(),
+                reason: "the CPU has neither RDSEED nor RDRAND".into(),
+            });
+        };
+        for chunk in out.chunks_mut(8) {
+            let w = next().ok_or_else(|| EntropyErr::Failed {
+                name: self.name(),
+                err: io::Error::new(io::ErrorKind::WouldBlock, "no data after retries"),
+            })?;
+            chunk.copy_from_slice(&w.to_le_bytes()[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn fill_bytes(&mut self, _out: &mut [u8]) -> Result<(), EntropyErr> {
+        Err(EntropyErr::Unavailable {
+            name: self.name(),
+            reason: format!("not implemented for '{}'", std::env::consts::ARCH),
+        })
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::{_rdrand64_step, _rdseed64_step};
+
+    /// Intel's guidance: 10 tries make an RDRAND failure practically impossible on a healthy
+    /// CPU; RDSEED runs dry much sooner, so it gets more tries with a pause between them.
+    const RDRAND_TRIES: usize = 10;
+    const RDSEED_TRIES: usize = 128;
+
+    /// # Safety
+    /// The CPU must support RDSEED.
+    #[target_feature(enable = "rdseed")]
+    pub(super) unsafe fn rdseed() -> Option<u64> {
+        let mut x = 0;
+        for _ in 0..RDSEED_TRIES {
+            if _rdseed64_step(&mut x) == 1 {
+                return Some(x);
+            }
+            core::hint::spin_loop();
+        }
+        None
+    }
+
+    /// # Safety
+    /// The CPU must support RDRAND.
+    #[target_feature(enable = "rdrand")]
+    pub(super) unsafe fn rdrand() -> Option<u64> {
+        let mut x = 0;
+        for _ in 0..RDRAND_TRIES {
+            if _rdrand64_step(&mut x) == 1 {
+                return Some(x);
+            }
+        }
+        None
+    }
+}
@@ -31,19 +31,52 @@ pub enum MRndErr {
         available: Vec<&'static str>,
     },
 
-    #[error("failed to open entropy source {path}: {source}")]
-    EntropyOpen {
-        path: &'static str,
-        #[source]
-        source: io::Error,
-    },
-
     #[error("entropy error: {0}")]
-    Entropy(#[from] io::Error),
+    Entropy(#[from] EntropyErr),
 
     #[error("internal error: {0}")]
     Internal(String),
 
     #[error("invalid arguments: {0}")]
     InvalidArgument(String),
+
+    #[error("unsupported operation: {0}")]
+    Unsupported(String),
+}
+
+/// Failures of an `EntropySource` or of the health tests on its output.
+#[derive(Error, Debug)]
+pub enum EntropyErr {
+    /// The source does not exist here; an `EntropyChain` moves on to its next source.
+    #[error("{name} is not available: {reason}")]
+    Unavailable { name: &'static str, reason: String },
+
+    #[error("{name} failed: {err}")]
+    Failed {
+        name: &'static str,
+        #[source]
+        err: io::Error,
+    },
+
+    #[error(
+        "{name} failed the repetition count test: {run} identical bytes in a row (cutoff {cutoff})"
+    )]
+    RepetitionCount {
+        name: &'static str,
+        run: u32,
+        cutoff: u32,
+    },
+
+    #[error(
+        "{name} failed the adaptive proportion test: {count} equal bytes in a window of {window} (cutoff {cutoff})"
+    )]
+    AdaptiveProportion {
+        name: &'static str,
+        count: u32,
+        window: u32,
+        cutoff: u32,
+    },
+
+    #[error("no entropy source available, tried: {tried:?}")]
+    NoSource { tried: Vec<String> },
 }
@@ -0,0 +1,124 @@
+//! Bulk output for `MPRng`.
+//!
+//! Every `fill_*` writes exactly the values of the matching single-value calls and leaves the
+//! generator in the same state; they only save the per-word virtual call. Raw words come from
+//! `MBitGen::fill_u64` in blocks of `FILL_BLOCK`, so the conversion loops run over plain
+//! arrays and vectorize. Samplers that may reject (normals, ranges) read through `Prefetch`,
+//! which never fetches more words than the remaining outputs need.
+use crate::{MBitGen, MPRng};
+
+/// Words fetched per `MBitGen::fill_u64` call.
+const FILL_BLOCK: usize = 512;
+
+const F64_SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+const F32_SCALE: f32 = 1.0 / (1u32 << 24) as f32;
+
+/// A stream of 64-bit words, for samplers shared by `MPRng` and `Prefetch`.
+pub(crate) trait WordSource {
+    fn next_u64(&mut self) -> u64;
+
+    /// High half of `next_u64`, as `MPRng::next_u32`.
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// `[0, 1)` from the top 53 bits, as `MPRng::next_f64`.
+    #[inline(always)]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * F64_SCALE
+    }
+}
+
+impl<G: MBitGen> WordSource for MPRng<G> {
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.bitgen.next_u64()
+    }
+}
+
+/// Words prefetched in blocks for a bulk call, then straight from the engine.
+///
+/// `refill(r)` fetches at most `r` words, with `r` the outputs still to produce. A sampler
+/// uses at least one word per output, so the sequential calls would have consumed every
+/// prefetched word too: the output and the final state match them exactly.
+pub(crate) struct Prefetch<'a, G: MBitGen> {
+    rng: &'a mut MPRng<G>,
+    buf: [u64; FILL_BLOCK],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, G: MBitGen> Prefetch<'a, G> {
+    pub(crate) fn new(rng: &'a mut MPRng<G>) -> Self {
+        Self {
+            rng,
+            buf: [0; FILL_BLOCK],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Prefetch up to `remaining` words once the previous block is used up.
+    #[inline(always)]
+    pub(crate) fn refill(&mut self, remaining: usize) {
+        if self.pos == self.len {
+            self.len = remaining.min(FILL_BLOCK);
+            self.pos = 0;
+            self.rng.bitgen.fill_u64(&mut self.buf[..self.len]);
+        }
+    }
+}
+
+impl<G: MBitGen> WordSource for Prefetch<'_, G> {
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        if self.pos < self.len {
+            self.pos += 1;
+            self.buf[self.pos - 1]
+        } else {
+            self.rng.bitgen.next_u64()
+        }
+    }
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// Fill `out` with raw words, one `MBitGen::fill_u64` call for the whole slice.
+    pub fn fill_u64(&mut self, out: &mut [u64]) {
+        self.bitgen.fill_u64(out);
+    }
+
+    /// Fill `out` with uniforms in `[0, 1)`; the same values as repeated `next_f64`.
+    pub fn fill_f64(&mut self, out: &mut [f64]) {
+        let mut words = [0u64; FILL_BLOCK];
+        for chunk in out.chunks_mut(FILL_BLOCK) {
+            let words = &mut words[..chunk.len()];
+            self.bitgen.fill_u64(words);
+            for (x, &w) in chunk.iter_mut().zip(words.iter()) {
+                *x = (w >> 11) as f64 * F64_SCALE;
+            }
+        }
+    }
+
+    /// Fill `out` with uniforms in `[0, 1)`; the same values as repeated `next_f32`.
+    pub fn fill_f32(&mut self, out: &mut [f32]) {
+        let mut words = [0u64; FILL_BLOCK];
+        for chunk in out.chunks_mut(FILL_BLOCK) {
+            let words = &mut words[..chunk.len()];
+            self.bitgen.fill_u64(words);
+            for (x, &w) in chunk.iter_mut().zip(words.iter()) {
+                *x = (w >> 40) as f32 * F32_SCALE;
+            }
+        }
+    }
+
+    /// Fill `out` with standard normals; the same values as repeated `standard_normal`.
+    pub fn fill_normal(&mut self, out: &mut [f64]) {
+        let n = out.len();
+        let mut words = Prefetch::new(self);
+        for (i, x) in out.iter_mut().enumerate() {
+            words.refill(n - i);
+            *x = crate::ziggurat::standard_normal_from(&mut words);
+        }
+    }
+}
@@ -1,21 +1,84 @@
 pub(crate) mod error;
+pub(crate) mod fill;
 pub(crate) mod mpcfg;
 pub(crate) mod mprng;
+#[cfg(feature = "rand_core")]
+pub(crate) mod rand_compat;
+pub(crate) mod range;
+pub(crate) mod seedseq;
+pub(crate) mod seq;
+pub(crate) mod state;
+pub mod stattest;
+pub(crate) mod ziggurat;
+pub(crate) mod ziggurat_tables;
+
+/// Distributions on `MPRng`, named like numpy's `Generator` methods. The samplers are
+/// `MPRng` methods; `weighted` holds prebuilt tables for many draws from one set of weights.
+///
+/// ```
+/// use marnd::{MPCfg, MPRng, MRndErr};
+///
+/// let mut cfg = MPCfg::new();
+/// cfg.insert("schema", "Pcg64");
+/// cfg.insert("seed", "7");
+/// let mut rng = MPRng::build(&cfg).unwrap();
+///
+/// let x = rng.gamma(2.0, 3.0).unwrap();
+/// assert!(x > 0.0);
+/// assert!(matches!(rng.beta(0.0, 1.0), Err(MRndErr::InvalidArgument(_))));
+/// ```
+pub mod dist {
+    pub mod continuous;
+    pub mod discrete;
+    pub mod multivariate;
+    pub mod weighted;
+
+    pub use self::discrete::POISSON_LAM_MAX;
+    pub use self::multivariate::MultivariateNormal;
+    pub use self::weighted::{AliasTable, FenwickWeightedIndex, WeightedIndex};
+}
 
 pub(crate) mod entropy {
+    pub(crate) mod health;
     pub(crate) mod mosentropy;
+    pub(crate) mod sources;
 }
 
 pub(crate) mod bitgen {
+    pub(crate) mod cbrng;
+    pub(crate) mod chacha;
     pub(crate) mod lcg64;
     pub(crate) mod mbitgen;
+    pub(crate) mod mt19937;
+    pub(crate) mod pcg64;
+    pub(crate) mod splitmix64;
+    pub(crate) mod xoshiro;
 }
 
-pub(crate) use crate::bitgen::mbitgen::MBitGen;
 pub(crate) use crate::mpcfg::CfgUtil;
 
+pub use crate::bitgen::cbrng::{Philox4x64, Threefry4x64};
+pub use crate::bitgen::chacha::{ChaCha8Rng, ChaCha12Rng, ChaCha20Rng, ChaChaRng};
 pub use crate::bitgen::lcg64::Lcg64;
-pub use crate::entropy::mosentropy::MOSEntropy;
-pub use crate::error::MRndErr;
+pub use crate::bitgen::mbitgen::MBitGen;
+pub use crate::bitgen::mt19937::{Mt19937, Mt19937_64};
+pub use crate::bitgen::pcg64::{Pcg64, Pcg64Dxsm};
+pub use crate::bitgen::splitmix64::SplitMix64;
+pub use crate::bitgen::xoshiro::{
+    XOSHIRO_LANES, Xoroshiro128Plus, Xoroshiro128PlusX4, Xoshiro256PlusPlus, Xoshiro256PlusPlusX4,
+    Xoshiro256StarStar, Xoshiro256StarStarX4,
+};
+pub use crate::dist::discrete::POISSON_LAM_MAX;
+pub use crate::dist::multivariate::MultivariateNormal;
+pub use crate::dist::weighted::{AliasTable, FenwickWeightedIndex, WeightedIndex};
+pub use crate::entropy::health::{APT_WINDOW, HealthTests};
+pub use crate::entropy::mosentropy::{EntropyChain, MOSEntropy};
+pub use crate::entropy::sources::{CpuRng, DevURandom, EntropySource, GetRandom};
+pub use crate::error::{EntropyErr, MRndErr};
 pub use crate::mpcfg::MPCfg;
-pub use crate::mprng::MPRng;
+pub use crate::mprng::{DynMPRng, MPRng, XOSHIRO_MAX_STREAM};
+#[cfg(feature = "rand_core")]
+pub use crate::rand_compat::RandCoreBitGen;
+pub use crate::seedseq::{SEED_SEQ_POOL_SIZE, SeedSequence};
+pub use crate::state::{BitGenState, MPRNG_STATE_VERSION, MPRngState};
+pub use crate::ziggurat::{ZIGGURAT_EXP_R, ZIGGURAT_NOR_R};
@@ -25,6 +25,23 @@ impl CfgUtil {
         }
     }
 
+    /// Parse a list of u64 such as `"[1, 0x2, 3]"`, `"(1, 2)"` or `"1,2"`; a single value is a
+    /// one-element list and `"[]"` is empty.
+    pub fn parse_u64_list(s: &str) -> Result<Vec<u64>, ParseIntError> {
+        let s = s.trim();
+        let inner = s
+            .strip_prefix('[')
+            .and_then(|r| r.strip_suffix(']'))
+            .or_else(|| s.strip_prefix('(').and_then(|r| r.strip_suffix(')')))
+            .unwrap_or(s);
+        inner
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(Self::parse_u64)
+            .collect()
+    }
+
     pub fn parse_schema(schema: &str) -> Result<(&str, Option<&str>), MRndErr> {
         let (engine, preset) = match schema.split_once("::") {
             Some((e, p)) => (e.trim(), Some(p.trim())),
@@ -95,4 +112,15 @@ impl MPCfg {
         //         println!("map some {}", x);
         //         Some(x)})
     }
+
+    pub fn get_u64_list(&self, key: &str, required: bool) -> Result<Option<Vec<u64>>, MRndErr> {
+        let s = match self.get_str(key, required)? {
+            Some(val) => val,
+            None => return Ok(None),
+        };
+
+        CfgUtil::parse_u64_list(s)
+            .map(Some)
+            .map_err(|_| MRndErr::ParseErr(format!("Invalid u64 list for '{}': '{}'", key, s)))
+    }
 }
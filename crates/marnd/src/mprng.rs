@@ -1,17 +1,55 @@
-use crate::{CfgUtil, Lcg64, MBitGen, MOSEntropy, MPCfg, MRndErr};
+use crate::bitgen::chacha::ChaChaRng;
+use crate::bitgen::pcg64::PCG_DEFAULT_INCREMENT_128;
+use crate::{
+    CfgUtil, Lcg64, MBitGen, MOSEntropy, MPCfg, MPRNG_STATE_VERSION, MPRngState, MRndErr, Mt19937,
+    Mt19937_64, Pcg64, Pcg64Dxsm, Philox4x64, SeedSequence, SplitMix64, Threefry4x64,
+    Xoroshiro128Plus, Xoroshiro128PlusX4, Xoshiro256PlusPlus, Xoshiro256PlusPlusX4,
+    Xoshiro256StarStar, Xoshiro256StarStarX4,
+};
 use core::fmt;
 use marcore::OptionExt;
 
+/// Largest `stream` of the xoshiro family. Stream `k` costs `k` calls to `jump`, each a few
+/// hundred engine steps per lane, so the cap keeps `build` under a few tenths of a second.
+pub const XOSHIRO_MAX_STREAM: u64 = 1 << 16;
+
+const ENGINES: &[&str] = &[
+    "Lcg64",
+    "Pcg64",
+    "Pcg64Dxsm",
+    "SplitMix64",
+    "Xoshiro256StarStar",
+    "Xoshiro256PlusPlus",
+    "Xoroshiro128Plus",
+    "Xoshiro256StarStarX4",
+    "Xoshiro256PlusPlusX4",
+    "Xoroshiro128PlusX4",
+    "ChaCha8Rng",
+    "ChaCha12Rng",
+    "ChaCha20Rng",
+    "Mt19937",
+    "Mt19937_64",
+    "Philox4x64",
+    "Threefry4x64",
+];
+
+/// Distributions and helpers over a bit generator `G`.
+///
+/// With a concrete engine, e.g. `MPRng<Xoshiro256PlusPlus>`, every call inlines down to the
+/// engine. `DynMPRng`, the default, boxes the engine so that it can be chosen at runtime by
+/// `MPRng::build` or restored with `MPRng::from_state`.
 #[derive(Debug)]
-pub struct MPRng {
-    // bitgen: Box<dyn MBitGen>,
-    pub bitgen: Box<dyn MBitGen>,
+pub struct MPRng<G: MBitGen = Box<dyn MBitGen>> {
+    pub bitgen: G,
     pub gen_name: String,
     pub spare_norm: Option<f64>,
 }
 
-impl MPRng {
-    pub fn new(bitgen: Box<dyn MBitGen>, gen_name: &str) -> Self {
+/// `MPRng` with a type-erased engine, as returned by `MPRng::build`.
+pub type DynMPRng = MPRng<Box<dyn MBitGen>>;
+
+impl<G: MBitGen> MPRng<G> {
+    pub fn new(bitgen: G, gen_name: &str) -> Self {
         Self {
             bitgen,
             gen_name: gen_name.into(),
@@ -19,21 +57,41 @@ impl MPRng {
         }
     }
 
+    /// The same generator with its engine boxed.
+    pub fn into_dyn(self) -> DynMPRng
+    where
+        G: 'static,
+    {
+        MPRng {
+            bitgen: Box::new(self.bitgen),
+            gen_name: self.gen_name,
+            spare_norm: self.spare_norm,
+        }
+    }
+}
+
+impl DynMPRng {
     pub fn build(cfg: &MPCfg) -> Result<Self, MRndErr> {
         let schema = cfg.get_str("schema", true)?.unwrap();
         let (engine, preset) = CfgUtil::parse_schema(schema)?;
 
-        let seed = cfg
-            .get_u64("seed", false)?
-            .or_try(|| MOSEntropy::next_u64())?;
+        // a seed list or a spawn_key seeds through a SeedSequence; engines with a wider state
+        // draw all of it from the sequence, the others take one u64 word
+        let seed_seq = Self::seed_seq(cfg)?;
+        let cfg_seed = match &seed_seq {
+            Some(seq) => Some(seq.generate_state_u64(1)[0]),
+            None => cfg.get_u64("seed", false)?,
+        };
+        // only read the OS entropy when the engine needs a seed and none is configured
+        let seed = || cfg_seed.or_try(|| MOSEntropy::next_u64());
         // let seed = match cfg.get_u64("seed", false)? {
         //     Some(s) => s,
         //     None => MOSEntropy::next_u64()?
         // };
 
-        let bitgen = match engine {
-            "Lcg64" => match preset {
-                Some(p) => match Lcg64::from_preset(p, seed) {
+        let bitgen: Box<dyn MBitGen> = match engine {
+            "Lcg64" => Box::new(match preset {
+                Some(p) => match Lcg64::from_preset(p, seed()?) {
                     Some(lcg) => lcg,
                     None => {
                         return Err(MRndErr::UnknownPreset {
@@ -46,21 +104,199 @@ impl MPRng {
                 None => {
                     let a = cfg.get_u64("a", true)?.unwrap();
                     let c = cfg.get_u64("c", true)?.unwrap();
-                    Lcg64::new(a, c, seed)
+                    Lcg64::new(a, c, seed()?)
+                }
+            }),
+            "Pcg64" | "Pcg64Dxsm" => {
+                Self::check_no_preset(engine, preset)?;
+                // optional stream id, the reference default stream otherwise
+                let initseq = match cfg.get_u64("stream", false)? {
+                    Some(s) => s as u128,
+                    None => PCG_DEFAULT_INCREMENT_128 >> 1,
+                };
+                if let Some(seq) = &seed_seq {
+                    if engine == "Pcg64" {
+                        Box::new(Pcg64::from_seed_seq(seq))
+                    } else {
+                        Box::new(Pcg64Dxsm::from_seed_seq(seq))
+                    }
+                } else if engine == "Pcg64" {
+                    Box::new(Pcg64::from_seed(seed()? as u128, initseq))
+                } else {
+                    Box::new(Pcg64Dxsm::from_seed(seed()? as u128, initseq))
+                }
+            }
+            "SplitMix64" => {
+                Self::check_no_preset(engine, preset)?;
+                Box::new(SplitMix64::new(seed()?))
+            }
+            "Xoshiro256StarStar"
+            | "Xoshiro256PlusPlus"
+            | "Xoroshiro128Plus"
+            | "Xoshiro256StarStarX4"
+            | "Xoshiro256PlusPlusX4"
+            | "Xoroshiro128PlusX4" => {
+                Self::check_no_preset(engine, preset)?;
+                // optional stream index: stream k is k jumps away from the seeded state
+                let stream = cfg.get_u64("stream", false)?.unwrap_or(0);
+                if stream > XOSHIRO_MAX_STREAM {
+                    return Err(MRndErr::BadCfg(format!(
+                        "'stream' of {engine} must be at most {XOSHIRO_MAX_STREAM} (got {stream})"
+                    )));
+                }
+                let mut g: Box<dyn MBitGen> = match (engine, &seed_seq) {
+                    ("Xoshiro256StarStar", Some(seq)) => {
+                        Box::new(Xoshiro256StarStar::from_seed_seq(seq))
+                    }
+                    ("Xoshiro256StarStar", None) => Box::new(Xoshiro256StarStar::new(seed()?)),
+                    ("Xoshiro256PlusPlus", Some(seq)) => {
+                        Box::new(Xoshiro256PlusPlus::from_seed_seq(seq))
+                    }
+                    ("Xoshiro256PlusPlus", None) => Box::new(Xoshiro256PlusPlus::new(seed()?)),
+                    ("Xoroshiro128Plus", Some(seq)) => {
+                        Box::new(Xoroshiro128Plus::from_seed_seq(seq))
+                    }
+                    ("Xoroshiro128Plus", None) => Box::new(Xoroshiro128Plus::new(seed()?)),
+                    ("Xoshiro256StarStarX4", Some(seq)) => {
+                        Box::new(Xoshiro256StarStarX4::from_seed_seq(seq))
+                    }
+                    ("Xoshiro256StarStarX4", None) => Box::new(Xoshiro256StarStarX4::new(seed()?)),
+                    ("Xoshiro256PlusPlusX4", Some(seq)) => {
+                        Box::new(Xoshiro256PlusPlusX4::from_seed_seq(seq))
+                    }
+                    ("Xoshiro256PlusPlusX4", None) => Box::new(Xoshiro256PlusPlusX4::new(seed()?)),
+                    (_, Some(seq)) => Box::new(Xoroshiro128PlusX4::from_seed_seq(seq)),
+                    (_, None) => Box::new(Xoroshiro128PlusX4::new(seed()?)),
+                };
+                for _ in 0..stream {
+                    g.jump()?;
                 }
-            },
+                g
+            }
+            "ChaCha8Rng" => Box::new(Self::chacha::<8>(engine, preset, cfg, &seed_seq, cfg_seed)?),
+            "ChaCha12Rng" => Box::new(Self::chacha::<12>(
+                engine, preset, cfg, &seed_seq, cfg_seed,
+            )?),
+            "ChaCha20Rng" => Box::new(Self::chacha::<20>(
+                engine, preset, cfg, &seed_seq, cfg_seed,
+            )?),
+            "Mt19937" => {
+                let mut mt = match &seed_seq {
+                    Some(seq) => Mt19937::from_seed_seq(seq),
+                    None => {
+                        let mut mt = Mt19937::new(0);
+                        mt.reseed(seed()?);
+                        mt
+                    }
+                };
+                match preset {
+                    None => {}
+                    // next_f64 reproduces numpy's legacy RandomState.random()
+                    Some("NumpyLegacy") => mt.legacy_doubles = true,
+                    Some(p) => {
+                        return Err(MRndErr::UnknownPreset {
+                            engine: engine.into(),
+                            preset: p.into(),
+                            available: vec!["NumpyLegacy"],
+                        });
+                    }
+                }
+                Box::new(mt)
+            }
+            "Mt19937_64" => {
+                Self::check_no_preset(engine, preset)?;
+                Box::new(match &seed_seq {
+                    Some(seq) => Mt19937_64::from_seed_seq(seq),
+                    None => Mt19937_64::new(seed()?),
+                })
+            }
+            "Philox4x64" | "Threefry4x64" => {
+                Self::check_no_preset(engine, preset)?;
+                // key [seed, stream], counter zero
+                let stream = cfg.get_u64("stream", false)?.unwrap_or(0);
+                if let Some(seq) = &seed_seq {
+                    if engine == "Philox4x64" {
+                        Box::new(Philox4x64::from_seed_seq(seq))
+                    } else {
+                        Box::new(Threefry4x64::from_seed_seq(seq))
+                    }
+                } else if engine == "Philox4x64" {
+                    Box::new(Philox4x64::new([0; 4], [seed()?, stream]))
+                } else {
+                    Box::new(Threefry4x64::new([0; 4], [seed()?, stream, 0, 0]))
+                }
+            }
             _ => {
                 return Err(MRndErr::UnknownEngine {
                     wrong_engine: engine.into(),
-                    available: vec!["Lcg64"],
+                    available: Self::list_engines(),
                 });
             }
         };
-        Ok(Self::new(Box::new(bitgen), engine))
+        Ok(Self::new(bitgen, engine))
+    }
+
+    /// Engine names accepted in the `schema` of `MPRng::build`.
+    pub fn list_engines() -> Vec<&'static str> {
+        ENGINES.to_vec()
+    }
+
+    /// A ChaCha generator keyed from the seed sequence, from `seed` like rand_chacha's
+    /// `seed_from_u64`, or with a 256-bit key from the OS when no seed is configured;
+    /// optional `stream` id.
+    fn chacha<const ROUNDS: usize>(
+        engine: &str,
+        preset: Option<&str>,
+        cfg: &MPCfg,
+        seed_seq: &Option<SeedSequence>,
+        seed: Option<u64>,
+    ) -> Result<ChaChaRng<ROUNDS>, MRndErr> {
+        Self::check_no_preset(engine, preset)?;
+        let mut rng = match (seed_seq, seed) {
+            (Some(seq), _) => ChaChaRng::from_seed_seq(seq),
+            (None, Some(s)) => ChaChaRng::seed_from_u64(s),
+            (None, None) => ChaChaRng::from_entropy()?,
+        };
+        if let Some(stream) = cfg.get_u64("stream", false)? {
+            rng.set_stream(stream);
+        }
+        Ok(rng)
+    }
+
+    /// The `SeedSequence` for a `seed` list (`"[1, 2, 3]"` or `"1,2,3"`) and/or a
+    /// `spawn_key` (OS entropy when there is no `seed`); `None` for a single u64 `seed`.
+    /// `stream` cannot be combined with it: spawn keys select the streams instead.
+    fn seed_seq(cfg: &MPCfg) -> Result<Option<SeedSequence>, MRndErr> {
+        let spawn_key = cfg.get_u64_list("spawn_key", false)?;
+        let seed_is_list = cfg.get("seed").is_some_and(|s| s.contains([',', '[', '(']));
+        if spawn_key.is_none() && !seed_is_list {
+            return Ok(None);
+        }
+        if cfg.get("stream").is_some() {
+            return Err(MRndErr::BadCfg(
+                "'stream' cannot be combined with a seed list or 'spawn_key'".into(),
+            ));
+        }
+        let spawn_key = spawn_key.unwrap_or_default();
+        Ok(Some(match cfg.get_u64_list("seed", false)? {
+            Some(entropy) => SeedSequence::with_spawn_key(&entropy, &spawn_key),
+            None => SeedSequence::from_u128(SeedSequence::os_entropy()?, &spawn_key),
+        }))
+    }
+
+    fn check_no_preset(engine: &str, preset: Option<&str>) -> Result<(), MRndErr> {
+        match preset {
+            None => Ok(()),
+            Some(p) => Err(MRndErr::UnknownPreset {
+                engine: engine.into(),
+                preset: p.into(),
+                available: vec![],
+            }),
+        }
     }
 }
 
-impl fmt::Display for MPRng {
+impl<G: MBitGen> fmt::Display for MPRng<G> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -93,18 +329,18 @@ impl IntervalMode01 {
     }
 }
 
-use std::ops::{BitOr, Shr};
-use std::ops::{Add, Mul};
 use std::mem;
+use std::ops::{Add, Mul};
+use std::ops::{BitOr, Shr};
 
-pub trait SupportedFloat: Copy + Mul<Self, Output = Self> + Add<Self, Output = Self>  {
+pub trait SupportedFloat: Copy + Mul<Self, Output = Self> + Add<Self, Output = Self> {
     type BitsType: Copy + Shr<u32, Output = Self::BitsType> + BitOr<Output = Self::BitsType>;
 
-    const BITS_SIZE: u32 = (mem::size_of::<Self>() as u32)*8;
+    const BITS_SIZE: u32 = (mem::size_of::<Self>() as u32) * 8;
     const MANTISSA_DIGITS: u32;
     const SCALE: Self;
 
-    const FAST_SHIFT_BITS: u32 = (64 - (Self::MANTISSA_DIGITS-1));
+    const FAST_SHIFT_BITS: u32 = (64 - (Self::MANTISSA_DIGITS - 1));
     const ONE_AS_BITS: Self::BitsType;
 
     fn from_u64(x: u64) -> Self;
@@ -125,7 +361,7 @@ impl SupportedFloat for f64 {
 
     const MANTISSA_DIGITS: u32 = f64::MANTISSA_DIGITS;
     const SCALE: f64 = 1.0 / ((1u64 << Self::MANTISSA_DIGITS) as f64);
-    
+
     // 0x3ff0_0000_0000_0000_u64
     const ONE_AS_BITS: Self::BitsType = Self::to_bits(1.0);
 
@@ -155,7 +391,6 @@ impl SupportedFloat for f64 {
     fn u64_to_bitstype(x: u64) -> Self::BitsType {
         x as Self::BitsType
     }
-
 }
 
 impl SupportedFloat for f32 {
@@ -194,8 +429,9 @@ impl SupportedFloat for f32 {
     }
 }
 
-impl MPRng {
+impl<G: MBitGen> MPRng<G> {
     /// Produce next 64 bits of output.
+    #[inline(always)]
     pub fn next_u64(&mut self) -> u64 {
         self.bitgen.next_u64()
     }
@@ -215,20 +451,14 @@ impl MPRng {
     /// Produce a random f64 in [0.0, 1.0).
     #[inline(always)]
     pub fn next_f64(&mut self) -> f64 {
-        // const SCALE: f64 = 1.0 / ((1u64 << 53) as f64);
-        // let v = self.next_u64() >> 11;
-        // (v as f64) * SCALE
         self.next_float_interval(IntervalMode01::Closed0_Open1)
     }
 
-    /// Produce a random f32 in [0.0, 1.0).
+    /// Produce a random f32 in [0.0, 1.0): the top 24 bits of `next_u64` times 2^-24, like
+    /// `next_f64`. Earlier versions returned `(0.0, 1.0]`, against this documentation.
     #[inline(always)]
     pub fn next_f32(&mut self) -> f32 {
-        // const SCALE: f32 = 1.0 / ((1u32 << 24) as f32);
-        // let v = (self.next_u64() >> 40) as u32;
-        // (v as f32) * SCALE
-        // self.next_float_interval(IntervalMode01::Closed0_Open1)
-        self.next_float_interval(IntervalMode01::Open0_Closed1)
+        self.next_float_interval(IntervalMode01::Closed0_Open1)
     }
 
     #[inline(always)]
@@ -286,9 +516,50 @@ impl MPRng {
         self.bitgen.reseed(seed);
         self.spare_norm = None;
     }
+
+    /// Skip `delta` outputs of the bit generator (see `MBitGen::advance`).
+    pub fn advance(&mut self, delta: u128) -> Result<(), MRndErr> {
+        self.bitgen.advance(delta)?;
+        self.spare_norm = None;
+        Ok(())
+    }
+
+    /// Snapshot of the engine state and the cached normal deviate; see `MPRngState`.
+    pub fn get_state(&self) -> Result<MPRngState, MRndErr> {
+        Ok(MPRngState {
+            version: MPRNG_STATE_VERSION,
+            gen_name: self.gen_name.clone(),
+            bitgen: self.bitgen.get_state()?,
+            spare_norm: self.spare_norm,
+        })
+    }
+
+    /// Move the bit generator to its next stream (see `MBitGen::jump`).
+    pub fn jump(&mut self) -> Result<(), MRndErr> {
+        self.bitgen.jump()?;
+        self.spare_norm = None;
+        Ok(())
+    }
+}
+
+impl DynMPRng {
+    /// Restore a snapshot from `get_state`; the engine may differ from the current one.
+    pub fn set_state(&mut self, state: &MPRngState) -> Result<(), MRndErr> {
+        *self = Self::from_state(state)?;
+        Ok(())
+    }
+
+    pub fn from_state(state: &MPRngState) -> Result<Self, MRndErr> {
+        state.check_version()?;
+        Ok(Self {
+            bitgen: state.bitgen.to_bitgen()?,
+            gen_name: state.gen_name.clone(),
+            spare_norm: state.spare_norm,
+        })
+    }
 }
 
-impl MPRng {
+impl<G: MBitGen> MPRng<G> {
     pub fn choice_idx(&mut self, probs: &[f64]) -> Result<usize, MRndErr> {
         if probs.is_empty() {
             return Ok(0);
@@ -337,7 +608,7 @@ impl MPRng {
     }
 }
 
-impl MPRng {
+impl<G: MBitGen> MPRng<G> {
     /// Box-Muller method
     #[inline(always)]
     pub fn norm_pair_box_muller(&mut self) -> (f64, f64) {
@@ -373,7 +644,7 @@ impl MPRng {
     }
 }
 
-impl MPRng {
+impl<G: MBitGen> MPRng<G> {
     // Marsaglia(/mɑːrˈsɑːljə/) Polar Method
     #[inline(always)]
     fn norm_pair_marsaglia_polar(&mut self) -> (f64, f64) {
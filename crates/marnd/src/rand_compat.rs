@@ -0,0 +1,352 @@
+//! `rand_core` 0.6 interoperability, enabled by the `rand_core` feature.
+//!
+//! Every engine and `MPRng` implement `RngCore`, so they plug into `rand`'s `Rng`,
+//! `SliceRandom` and `rand_distr`. Engines also implement `SeedableRng`; `seed_from_u64(s)`
+//! gives the same stream as `MPRng::build` with `seed = s` (and `Lcg64::DK` for `Lcg64`).
+//! In the other direction `RandCoreBitGen` wraps any `RngCore` as an `MBitGen`.
+//!
+//! 64-bit engines answer `next_u32` with the high half of `next_u64`, like `MPRng::next_u32`;
+//! `Mt19937` and the ChaCha family use their native 32-bit words instead.
+
+use crate::bitgen::pcg64::PCG_DEFAULT_INCREMENT_128;
+use crate::{
+    ChaChaRng, Lcg64, MBitGen, MPRng, Mt19937, Mt19937_64, Pcg64, Pcg64Dxsm, Philox4x64,
+    SplitMix64, Threefry4x64, Xoroshiro128Plus, Xoroshiro128PlusX4, Xoshiro256PlusPlus,
+    Xoshiro256PlusPlusX4, Xoshiro256StarStar, Xoshiro256StarStarX4,
+};
+use core::fmt;
+use rand_core::{Error, RngCore, SeedableRng, impls};
+
+macro_rules! impl_rng_core_u64 {
+    ($($t:ty),* $(,)?) => {$(
+        impl RngCore for $t {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                (MBitGen::next_u64(self) >> 32) as u32
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                MBitGen::next_u64(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                impls::fill_bytes_via_next(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+    )*};
+}
+
+impl_rng_core_u64!(
+    Lcg64,
+    Pcg64,
+    Pcg64Dxsm,
+    SplitMix64,
+    Xoshiro256StarStar,
+    Xoshiro256PlusPlus,
+    Xoroshiro128Plus,
+    Xoshiro256StarStarX4,
+    Xoshiro256PlusPlusX4,
+    Xoroshiro128PlusX4,
+    Mt19937_64,
+    Philox4x64,
+    Threefry4x64,
+);
+
+impl RngCore for Mt19937 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        Mt19937::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        MBitGen::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Word for word the same output as rand_chacha's generator with the same seed.
+impl<const ROUNDS: usize> RngCore for ChaChaRng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        ChaChaRng::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        ChaChaRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ChaChaRng::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        ChaChaRng::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+impl<G: MBitGen> RngCore for MPRng<G> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        MPRng::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        MPRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill(dest);
+        Ok(())
+    }
+}
+
+fn le_u64<const N: usize>(seed: &[u8]) -> [u64; N] {
+    core::array::from_fn(|i| u64::from_le_bytes(seed[8 * i..8 * i + 8].try_into().unwrap()))
+}
+
+/// Seeds with the `DK` preset; use `Lcg64::new` or `Lcg64::from_preset` for other constants.
+impl SeedableRng for Lcg64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Lcg64::from_preset("DK", state).unwrap()
+    }
+}
+
+macro_rules! impl_seedable_pcg {
+    ($($t:ty),*) => {$(
+        /// The seed holds `initstate` then `initseq`, both little-endian u128.
+        impl SeedableRng for $t {
+            type Seed = [u8; 32];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                let initstate = u128::from_le_bytes(seed[..16].try_into().unwrap());
+                let initseq = u128::from_le_bytes(seed[16..].try_into().unwrap());
+                <$t>::from_seed(initstate, initseq)
+            }
+
+            fn seed_from_u64(state: u64) -> Self {
+                <$t>::from_seed(state as u128, PCG_DEFAULT_INCREMENT_128 >> 1)
+            }
+        }
+    )*};
+}
+
+impl_seedable_pcg!(Pcg64, Pcg64Dxsm);
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        SplitMix64::new(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        SplitMix64::new(state)
+    }
+}
+
+macro_rules! impl_seedable_xoshiro {
+    ($($t:ty, $bytes:literal, $words:literal);*) => {$(
+        /// The seed is the raw state, little-endian. The all-zero seed (a fixed point of
+        /// the engine) is replaced by `seed_from_u64(0)`, as rand_xoshiro does.
+        impl SeedableRng for $t {
+            type Seed = [u8; $bytes];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                if seed.iter().all(|&b| b == 0) {
+                    return Self::seed_from_u64(0);
+                }
+                <$t>::from_state(le_u64::<$words>(&seed))
+            }
+
+            fn seed_from_u64(state: u64) -> Self {
+                <$t>::new(state)
+            }
+        }
+    )*};
+}
+
+impl_seedable_xoshiro!(
+    Xoshiro256StarStar, 32, 4;
+    Xoshiro256PlusPlus, 32, 4;
+    Xoroshiro128Plus, 16, 2
+);
+
+macro_rules! impl_seedable_lanes {
+    ($($t:ty, $scalar:ty);*) => {$(
+        /// The lanes of the single-stream engine built from the same seed.
+        impl SeedableRng for $t {
+            type Seed = <$scalar as SeedableRng>::Seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                <$t>::from_generator(&<$scalar>::from_seed(seed))
+            }
+
+            fn seed_from_u64(state: u64) -> Self {
+                <$t>::new(state)
+            }
+        }
+    )*};
+}
+
+impl_seedable_lanes!(
+    Xoshiro256StarStarX4, Xoshiro256StarStar;
+    Xoshiro256PlusPlusX4, Xoshiro256PlusPlus;
+    Xoroshiro128PlusX4, Xoroshiro128Plus
+);
+
+/// Same keying as rand_chacha, so both crates agree on every seed.
+impl<const ROUNDS: usize> SeedableRng for ChaChaRng<ROUNDS> {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        ChaChaRng::from_seed(seed)
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        ChaChaRng::seed_from_u64(state)
+    }
+}
+
+impl SeedableRng for Mt19937 {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Mt19937::new(u32::from_le_bytes(seed))
+    }
+
+    /// numpy's `RandomState(state)`, see `Mt19937::reseed`.
+    fn seed_from_u64(state: u64) -> Self {
+        let mut mt = Mt19937::new(0);
+        mt.reseed(state);
+        mt
+    }
+}
+
+impl SeedableRng for Mt19937_64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Mt19937_64::new(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Mt19937_64::new(state)
+    }
+}
+
+/// The seed is the key, counter zero.
+impl SeedableRng for Philox4x64 {
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Philox4x64::new([0; 4], le_u64::<2>(&seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Philox4x64::new([0; 4], [state, 0])
+    }
+}
+
+/// The seed is the key, counter zero.
+impl SeedableRng for Threefry4x64 {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Threefry4x64::new([0; 4], le_u64::<4>(&seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Threefry4x64::new([0; 4], [state, 0, 0, 0])
+    }
+}
+
+/// Any `rand_core` generator as an `MBitGen`, e.g.
+/// `MPRng::new(RandCoreBitGen::new(rng), "rand_pcg::Pcg64")`.
+///
+/// `next_u64` forwards to the wrapped generator. Built with `new`, `reseed(s)` replaces it
+/// with `R::seed_from_u64(s)`; built with `unseeded`, for sources without a seed such as
+/// `OsRng`, `reseed` keeps it. `advance`, `jump` and `get_state` are
+/// unsupported.
+#[derive(Debug, Clone)]
+pub struct RandCoreBitGen<R> {
+    rng: R,
+    reseed: Option<fn(u64) -> R>,
+}
+
+impl<R: SeedableRng> RandCoreBitGen<R> {
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            reseed: Some(R::seed_from_u64),
+        }
+    }
+}
+
+impl<R> RandCoreBitGen<R> {
+    /// Wrap a generator that cannot be reseeded; `reseed` is a no-op.
+    pub fn unseeded(rng: R) -> Self {
+        Self { rng, reseed: None }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.rng
+    }
+
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R> MBitGen for RandCoreBitGen<R>
+where
+    R: RngCore + Send + Sync + fmt::Debug,
+{
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        if let Some(from_seed) = self.reseed {
+            self.rng = from_seed(seed);
+        }
+    }
+}
+
+impl<R> fmt::Display for RandCoreBitGen<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "struct type: 'RandCoreBitGen<{}>'",
+            core::any::type_name::<R>()
+        )
+    }
+}
@@ -0,0 +1,203 @@
+//! Uniform integers in a range for `MPRng`, without modulo bias.
+//!
+//! Uses Lemire's nearly-divisionless method ("Fast Random Integer Generation in an Interval",
+//! 2019): the draw is multiplied by the span and the high word is the result; the low word
+//! decides the rare rejections, and the one division is only computed when a rejection is
+//! possible. Ranges may be half-open (`lo..hi`), inclusive (`lo..=hi`) or open-ended; an
+//! empty range is `MRndErr::InvalidArgument`.
+use crate::fill::{Prefetch, WordSource};
+use crate::{MBitGen, MPRng, MRndErr};
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+
+/// Integers with an order-preserving map onto `u64`, so one bounded draw serves all of them.
+trait RangeInt: Copy + fmt::Debug {
+    const MIN: Self;
+    const MAX: Self;
+    fn to_offset(self) -> u64;
+    fn from_offset(x: u64) -> Self;
+}
+
+macro_rules! impl_range_int_unsigned {
+    ($($t:ty),*) => {$(
+        impl RangeInt for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            #[inline(always)]
+            fn to_offset(self) -> u64 {
+                self as u64
+            }
+            #[inline(always)]
+            fn from_offset(x: u64) -> Self {
+                x as $t
+            }
+        }
+    )*};
+}
+
+impl_range_int_unsigned!(u64, u32, usize);
+
+impl RangeInt for i64 {
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+    // flipping the sign bit maps i64::MIN..=i64::MAX onto 0..=u64::MAX in order
+    #[inline(always)]
+    fn to_offset(self) -> u64 {
+        (self as u64) ^ (1 << 63)
+    }
+    #[inline(always)]
+    fn from_offset(x: u64) -> Self {
+        (x ^ (1 << 63)) as i64
+    }
+}
+
+/// `(lo, span - 1)` in offset space; `span - 1 == u64::MAX` is the full 64-bit range.
+fn resolve<T: RangeInt, R: RangeBounds<T>>(range: &R) -> Result<(u64, u64), MRndErr> {
+    let empty = || {
+        MRndErr::InvalidArgument(format!(
+            "empty range {:?}..{:?}",
+            range.start_bound(),
+            range.end_bound()
+        ))
+    };
+    let lo = match range.start_bound() {
+        Bound::Included(&x) => x.to_offset(),
+        Bound::Excluded(&x) => x.to_offset().checked_add(1).ok_or_else(empty)?,
+        Bound::Unbounded => T::MIN.to_offset(),
+    };
+    let hi = match range.end_bound() {
+        Bound::Included(&x) => x.to_offset(),
+        Bound::Excluded(&x) => x.to_offset().checked_sub(1).ok_or_else(empty)?,
+        Bound::Unbounded => T::MAX.to_offset(),
+    };
+    if hi < lo {
+        return Err(empty());
+    }
+    Ok((lo, hi - lo))
+}
+
+/// Uniform in `[0, s)` for `s > 0`. `t` caches the rejection threshold `2^64 mod s`
+/// across draws with the same `s`.
+#[inline]
+fn bounded_u64<W: WordSource>(w: &mut W, s: u64, t: &mut Option<u64>) -> u64 {
+    let mut m = w.next_u64() as u128 * s as u128;
+    if (m as u64) < s {
+        // low words below 2^64 mod s would make some results more likely
+        let t = *t.get_or_insert_with(|| s.wrapping_neg() % s);
+        while (m as u64) < t {
+            m = w.next_u64() as u128 * s as u128;
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// Uniform in `[0, s)` for `0 < s < 2^32`, from 32-bit draws.
+#[inline]
+fn bounded_u32<W: WordSource>(w: &mut W, s: u32, t: &mut Option<u64>) -> u32 {
+    let mut m = w.next_u32() as u64 * s as u64;
+    if (m as u32) < s {
+        let t = *t.get_or_insert_with(|| (s.wrapping_neg() % s) as u64) as u32;
+        while (m as u32) < t {
+            m = w.next_u32() as u64 * s as u64;
+        }
+    }
+    (m >> 32) as u32
+}
+
+#[inline]
+fn gen_range_offset<W: WordSource>(w: &mut W, lo: u64, span_m1: u64, t: &mut Option<u64>) -> u64 {
+    match span_m1 {
+        u64::MAX => w.next_u64(),
+        s if s < u32::MAX as u64 => lo + bounded_u32(w, s as u32 + 1, t) as u64,
+        s => lo + bounded_u64(w, s + 1, t),
+    }
+}
+
+impl<G: MBitGen> MPRng<G> {
+    /// The free [`bounded_u64`] on this generator's words.
+    #[inline]
+    pub(crate) fn bounded_u64(&mut self, s: u64, t: &mut Option<u64>) -> u64 {
+        bounded_u64(self, s, t)
+    }
+
+    fn gen_range<T: RangeInt, R: RangeBounds<T>>(&mut self, range: R) -> Result<T, MRndErr> {
+        let (lo, span_m1) = resolve(&range)?;
+        Ok(T::from_offset(gen_range_offset(
+            self, lo, span_m1, &mut None,
+        )))
+    }
+
+    fn fill_range<T: RangeInt, R: RangeBounds<T>>(
+        &mut self,
+        range: R,
+        out: &mut [T],
+    ) -> Result<(), MRndErr> {
+        let (lo, span_m1) = resolve(&range)?;
+        // the same draws as repeated gen_range, computing the threshold at most once
+        let mut t = None;
+        let n = out.len();
+        let mut words = Prefetch::new(self);
+        for (i, x) in out.iter_mut().enumerate() {
+            words.refill(n - i);
+            *x = T::from_offset(gen_range_offset(&mut words, lo, span_m1, &mut t));
+        }
+        Ok(())
+    }
+
+    /// Uniform `u64` in `range`, e.g. `rng.gen_range_u64(10..20)` or `(0..=u64::MAX)`.
+    pub fn gen_range_u64<R: RangeBounds<u64>>(&mut self, range: R) -> Result<u64, MRndErr> {
+        self.gen_range(range)
+    }
+
+    /// Uniform `i64` in `range`, e.g. `rng.gen_range_i64(-5..=5)`.
+    pub fn gen_range_i64<R: RangeBounds<i64>>(&mut self, range: R) -> Result<i64, MRndErr> {
+        self.gen_range(range)
+    }
+
+    /// Uniform `u32` in `range`.
+    pub fn gen_range_u32<R: RangeBounds<u32>>(&mut self, range: R) -> Result<u32, MRndErr> {
+        self.gen_range(range)
+    }
+
+    /// Uniform `usize` in `range`, e.g. an index with `rng.gen_range_usize(0..len)`.
+    pub fn gen_range_usize<R: RangeBounds<usize>>(&mut self, range: R) -> Result<usize, MRndErr> {
+        self.gen_range(range)
+    }
+
+    /// Fill `out` with uniform `u64` in `range`; the same values as repeated `gen_range_u64`.
+    pub fn fill_range_u64<R: RangeBounds<u64>>(
+        &mut self,
+        range: R,
+        out: &mut [u64],
+    ) -> Result<(), MRndErr> {
+        self.fill_range(range, out)
+    }
+
+    /// Fill `out` with uniform `i64` in `range`; the same values as repeated `gen_range_i64`.
+    pub fn fill_range_i64<R: RangeBounds<i64>>(
+        &mut self,
+        range: R,
+        out: &mut [i64],
+    ) -> Result<(), MRndErr> {
+        self.fill_range(range, out)
+    }
+
+    /// Fill `out` with uniform `u32` in `range`; the same values as repeated `gen_range_u32`.
+    pub fn fill_range_u32<R: RangeBounds<u32>>(
+        &mut self,
+        range: R,
+        out: &mut [u32],
+    ) -> Result<(), MRndErr> {
+        self.fill_range(range, out)
+    }
+
+    /// Fill `out` with uniform `usize` in `range`; the same values as repeated
+    /// `gen_range_usize`.
+    pub fn fill_range_usize<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        out: &mut [usize],
+    ) -> Result<(), MRndErr> {
+        self.fill_range(range, out)
+    }
+}
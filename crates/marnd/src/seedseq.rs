@@ -0,0 +1,179 @@
+//! `SeedSequence`, a port of `numpy.random.SeedSequence`.
+//!
+//! Entropy of any length and a spawn key are hashed into a pool of 128 bits, from which
+//! `generate_state` draws as many seed words as an engine needs. `spawn` derives children that
+//! share the entropy and extend the spawn key, giving independent streams. For the same
+//! entropy and spawn key the words are identical to numpy's.
+use crate::{MOSEntropy, MRndErr};
+
+/// Size of the entropy pool in u32 words (numpy's `DEFAULT_POOL_SIZE`).
+pub const SEED_SEQ_POOL_SIZE: usize = 4;
+
+const INIT_A: u32 = 0x43b0_d7e5;
+const MULT_A: u32 = 0x931e_8875;
+const INIT_B: u32 = 0x8b51_f9dd;
+const MULT_B: u32 = 0x58f3_8ded;
+const MIX_MULT_L: u32 = 0xca01_f9dd;
+const MIX_MULT_R: u32 = 0x4973_f715;
+const XSHIFT: u32 = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedSequence {
+    entropy: Vec<u32>,
+    spawn_key: Vec<u64>,
+    n_children_spawned: u64,
+    pool: [u32; SEED_SEQ_POOL_SIZE],
+}
+
+/// The u32 words numpy uses for a sequence of non-negative ints: each one least significant
+/// word first, with no leading zero words (0 is one word).
+fn int_words(xs: &[u64]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(2 * xs.len());
+    for &x in xs {
+        out.push(x as u32);
+        if x >> 32 != 0 {
+            out.push((x >> 32) as u32);
+        }
+    }
+    out
+}
+
+fn hashmix(value: u32, hash_const: &mut u32) -> u32 {
+    let mut v = value ^ *hash_const;
+    *hash_const = hash_const.wrapping_mul(MULT_A);
+    v = v.wrapping_mul(*hash_const);
+    v ^ (v >> XSHIFT)
+}
+
+fn mix(x: u32, y: u32) -> u32 {
+    let r = MIX_MULT_L
+        .wrapping_mul(x)
+        .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    r ^ (r >> XSHIFT)
+}
+
+impl SeedSequence {
+    /// Like `SeedSequence([e0, e1, ...])`; a single `u64` seed is `new(&[seed])`.
+    pub fn new(entropy: &[u64]) -> Self {
+        Self::with_spawn_key(entropy, &[])
+    }
+
+    /// Like `SeedSequence(entropy, spawn_key=spawn_key)`.
+    pub fn with_spawn_key(entropy: &[u64], spawn_key: &[u64]) -> Self {
+        Self::from_words(int_words(entropy), spawn_key.to_vec())
+    }
+
+    /// 128 bits of OS entropy, like `SeedSequence()`.
+    pub fn from_entropy() -> Result<Self, MRndErr> {
+        Ok(Self::from_u128(Self::os_entropy()?, &[]))
+    }
+
+    pub(crate) fn os_entropy() -> Result<u128, MRndErr> {
+        Ok((MOSEntropy::next_u64()? as u128) | ((MOSEntropy::next_u64()? as u128) << 64))
+    }
+
+    /// Entropy given as one 128-bit int, e.g. numpy's `SeedSequence().entropy`.
+    pub fn from_u128(entropy: u128, spawn_key: &[u64]) -> Self {
+        let mut words = vec![entropy as u32];
+        let mut rest = entropy >> 32;
+        while rest != 0 {
+            words.push(rest as u32);
+            rest >>= 32;
+        }
+        Self::from_words(words, spawn_key.to_vec())
+    }
+
+    fn from_words(entropy: Vec<u32>, spawn_key: Vec<u64>) -> Self {
+        let mut seq = Self {
+            entropy,
+            spawn_key,
+            n_children_spawned: 0,
+            pool: [0; SEED_SEQ_POOL_SIZE],
+        };
+        seq.mix_entropy();
+        seq
+    }
+
+    /// The entropy words followed by the spawn key words; the entropy is zero-padded to the
+    /// pool size when there is a spawn key, so children never collide with other seeds.
+    fn assembled_entropy(&self) -> Vec<u32> {
+        let mut words = self.entropy.clone();
+        let spawn = int_words(&self.spawn_key);
+        if !spawn.is_empty() && words.len() < SEED_SEQ_POOL_SIZE {
+            words.resize(SEED_SEQ_POOL_SIZE, 0);
+        }
+        words.extend(spawn);
+        words
+    }
+
+    fn mix_entropy(&mut self) {
+        let entropy = self.assembled_entropy();
+        let mut hash_const = INIT_A;
+        let pool = &mut self.pool;
+        for (i, p) in pool.iter_mut().enumerate() {
+            *p = hashmix(entropy.get(i).copied().unwrap_or(0), &mut hash_const);
+        }
+        for src in 0..SEED_SEQ_POOL_SIZE {
+            for dst in 0..SEED_SEQ_POOL_SIZE {
+                if src != dst {
+                    pool[dst] = mix(pool[dst], hashmix(pool[src], &mut hash_const));
+                }
+            }
+        }
+        for &e in entropy.iter().skip(SEED_SEQ_POOL_SIZE) {
+            for p in pool.iter_mut() {
+                *p = mix(*p, hashmix(e, &mut hash_const));
+            }
+        }
+    }
+
+    /// `n_words` seed words, like `generate_state(n_words, np.uint32)`.
+    pub fn generate_state(&self, n_words: usize) -> Vec<u32> {
+        let mut hash_const = INIT_B;
+        self.pool
+            .iter()
+            .cycle()
+            .take(n_words)
+            .map(|&p| {
+                let mut v = p ^ hash_const;
+                hash_const = hash_const.wrapping_mul(MULT_B);
+                v = v.wrapping_mul(hash_const);
+                v ^ (v >> XSHIFT)
+            })
+            .collect()
+    }
+
+    /// `n_words` 64-bit seed words, like `generate_state(n_words, np.uint64)`.
+    pub fn generate_state_u64(&self, n_words: usize) -> Vec<u64> {
+        self.generate_state(2 * n_words)
+            .chunks_exact(2)
+            .map(|w| w[0] as u64 | ((w[1] as u64) << 32))
+            .collect()
+    }
+
+    /// `n` children with spawn keys `spawn_key + [i]`, continuing from earlier calls.
+    pub fn spawn(&mut self, n: usize) -> Vec<SeedSequence> {
+        let first = self.n_children_spawned;
+        self.n_children_spawned += n as u64;
+        (first..self.n_children_spawned)
+            .map(|i| {
+                let mut key = self.spawn_key.clone();
+                key.push(i);
+                Self::from_words(self.entropy.clone(), key)
+            })
+            .collect()
+    }
+
+    /// The entropy as u32 words, least significant first.
+    pub fn entropy(&self) -> &[u32] {
+        &self.entropy
+    }
+
+    pub fn spawn_key(&self) -> &[u64] {
+        &self.spawn_key
+    }
+
+    pub fn n_children_spawned(&self) -> u64 {
+        self.n_children_spawned
+    }
+}
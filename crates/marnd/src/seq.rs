@@ -0,0 +1,123 @@
+//! Shuffling and sampling without replacement on `MPRng`.
+//!
+//! Every index draw is an unbiased bounded integer (Lemire's method, see `range.rs`), so a
+//! shuffle reaches each permutation with the same probability as long as the generator has
+//! enough state.
+use crate::{MBitGen, MPRng, MRndErr};
+use std::collections::HashSet;
+
+impl<G: MBitGen> MPRng<G> {
+    /// Uniform in `0..=i`.
+    #[inline]
+    fn index_to(&mut self, i: usize) -> usize {
+        self.bounded_u64(i as u64 + 1, &mut None) as usize
+    }
+
+    /// Shuffle `xs` in place (Fisher–Yates, from the back as numpy does).
+    pub fn shuffle<T>(&mut self, xs: &mut [T]) {
+        for i in (1..xs.len()).rev() {
+            let j = self.index_to(i);
+            xs.swap(i, j);
+        }
+    }
+
+    /// A random permutation of `0..n`.
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut out: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut out);
+        out
+    }
+
+    /// `k` distinct indices from `0..n`, in random order. Floyd's algorithm (O(k) memory)
+    /// when `k` is a small part of `n`, otherwise a partial shuffle of `0..n`.
+    pub fn sample_indices(&mut self, n: usize, k: usize) -> Result<Vec<usize>, MRndErr> {
+        if k > n {
+            return Err(MRndErr::InvalidArgument(format!(
+                "sample_indices: cannot take {k} distinct indices from {n}"
+            )));
+        }
+        if k.saturating_mul(4) >= n {
+            // only the first k slots of the shuffle are drawn
+            let mut pool: Vec<usize> = (0..n).collect();
+            for i in 0..k {
+                let j = i + self.index_to(n - 1 - i);
+                pool.swap(i, j);
+            }
+            pool.truncate(k);
+            return Ok(pool);
+        }
+
+        let mut seen = HashSet::with_capacity(k);
+        let mut out = Vec::with_capacity(k);
+        for j in n - k..n {
+            let t = self.index_to(j);
+            // j itself is new: everything taken so far is below it
+            let x = if seen.contains(&t) { j } else { t };
+            seen.insert(x);
+            out.push(x);
+        }
+        // Floyd picks a uniform set, not a uniform order
+        self.shuffle(&mut out);
+        Ok(out)
+    }
+
+    /// `k` distinct indices drawn with probability proportional to `weights`, without
+    /// replacement, in the order successive weighted draws would pick them
+    /// (Efraimidis & Spirakis 2006: the `k` smallest keys `E_i / w_i` for exponential `E_i`).
+    /// Zero weights are never picked, so at least `k` weights must be positive.
+    pub fn sample_weighted(&mut self, weights: &[f64], k: usize) -> Result<Vec<usize>, MRndErr> {
+        let mut positive = 0;
+        for (i, &w) in weights.iter().enumerate() {
+            if !(w >= 0.0 && w.is_finite()) {
+                return Err(MRndErr::InvalidArgument(format!(
+                    "sample_weighted: weight at index {i} must be finite and >= 0 (got {w})"
+                )));
+            }
+            positive += (w > 0.0) as usize;
+        }
+        if k > positive {
+            return Err(MRndErr::InvalidArgument(format!(
+                "sample_weighted: cannot take {k} indices, only {positive} weights are positive"
+            )));
+        }
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut keys: Vec<(f64, usize)> = weights
+            .iter()
+            .enumerate()
+            .filter(|&(_, &w)| w > 0.0)
+            .map(|(i, &w)| (self.standard_exponential() / w, i))
+            .collect();
+        keys.select_nth_unstable_by(k - 1, |a, b| a.0.total_cmp(&b.0));
+        keys.truncate(k);
+        keys.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(keys.into_iter().map(|(_, i)| i).collect())
+    }
+
+    /// `k` items from `iter`, each subset equally likely, in random order; all items if there
+    /// are fewer than `k`. One pass with O(k) memory and O(k log(n / k)) draws (Li's
+    /// Algorithm L), so `iter` may be long or of unknown length.
+    pub fn reservoir_sample<I: IntoIterator>(&mut self, iter: I, k: usize) -> Vec<I::Item> {
+        let mut iter = iter.into_iter();
+        let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+        if reservoir.len() == k && k > 0 {
+            let kf = k as f64;
+            let mut w = (self.next_f64_oc().ln() / kf).exp();
+            loop {
+                // items to pass over before the next replacement, geometric in w
+                let skip = (self.next_f64_oc().ln() / (-w).ln_1p()).floor();
+                // the cast saturates: a skip past usize::MAX ends any iterator
+                let Some(item) = iter.nth(skip as usize) else {
+                    break;
+                };
+                let slot = self.index_to(k - 1);
+                reservoir[slot] = item;
+                w *= (self.next_f64_oc().ln() / kf).exp();
+            }
+        }
+        self.shuffle(&mut reservoir);
+        reservoir
+    }
+}
@@ -0,0 +1,626 @@
+//! Snapshots of `MPRng` for checkpointing: `MPRng::get_state` / `MPRng::set_state`.
+//!
+//! An `MPRngState` holds the format version, the engine name, the full engine state and the
+//! cached `spare_norm`, so a restored generator continues exactly where the snapshot was
+//! taken. It serializes to JSON (`to_json`) and to a compact little-endian binary form
+//! (`to_bytes`). A snapshot of a ChaCha engine contains its key: treat it as a secret.
+use crate::bitgen::chacha::ChaChaRng;
+use crate::bitgen::mt19937::{MT19937_64_NN, MT19937_N};
+use crate::{
+    Lcg64, MBitGen, MRndErr, Mt19937, Mt19937_64, Pcg64, Pcg64Dxsm, Philox4x64, SplitMix64,
+    Threefry4x64, XOSHIRO_LANES, Xoroshiro128Plus, Xoroshiro128PlusX4, Xoshiro256PlusPlus,
+    Xoshiro256PlusPlusX4, Xoshiro256StarStar, Xoshiro256StarStarX4,
+};
+use serde::{Deserialize, Serialize};
+
+/// Version written by this build; other versions are rejected when loading.
+pub const MPRNG_STATE_VERSION: u32 = 1;
+
+const MAGIC: &[u8; 4] = b"MRNS";
+
+/// The state of one bit generator, tagged with its engine.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BitGenState {
+    Lcg64 {
+        a: u64,
+        c: u64,
+        state: u64,
+        preset: Option<String>,
+    },
+    Pcg64 {
+        state: u128,
+        inc: u128,
+    },
+    Pcg64Dxsm {
+        state: u128,
+        inc: u128,
+    },
+    SplitMix64 {
+        state: u64,
+    },
+    Xoshiro256StarStar {
+        s: [u64; 4],
+    },
+    Xoshiro256PlusPlus {
+        s: [u64; 4],
+    },
+    Xoroshiro128Plus {
+        s: [u64; 2],
+    },
+    Xoshiro256StarStarX4 {
+        s: [[u64; XOSHIRO_LANES]; 4],
+        lane: usize,
+    },
+    Xoshiro256PlusPlusX4 {
+        s: [[u64; XOSHIRO_LANES]; 4],
+        lane: usize,
+    },
+    Xoroshiro128PlusX4 {
+        s: [[u64; XOSHIRO_LANES]; 2],
+        lane: usize,
+    },
+    ChaCha {
+        rounds: u32,
+        key: [u8; 32],
+        stream: u64,
+        word_pos: u128,
+    },
+    Mt19937 {
+        mt: Vec<u32>,
+        mti: usize,
+        legacy_doubles: bool,
+    },
+    Mt19937_64 {
+        mt: Vec<u64>,
+        mti: usize,
+    },
+    Philox4x64 {
+        ctr: [u64; 4],
+        key: [u64; 2],
+        buffer: [u64; 4],
+        buffer_pos: usize,
+    },
+    Threefry4x64 {
+        ctr: [u64; 4],
+        key: [u64; 4],
+        buffer: [u64; 4],
+        buffer_pos: usize,
+    },
+}
+
+/// A versioned snapshot of an `MPRng`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MPRngState {
+    pub version: u32,
+    pub gen_name: String,
+    pub bitgen: BitGenState,
+    pub spare_norm: Option<f64>,
+}
+
+fn bad_state(msg: String) -> MRndErr {
+    MRndErr::InvalidArgument(format!("state: {msg}"))
+}
+
+fn check_lanes<const N: usize>(s: &[[u64; XOSHIRO_LANES]; N], lane: usize) -> Result<(), MRndErr> {
+    if lane >= XOSHIRO_LANES {
+        return Err(bad_state(format!("lane {lane} out of range")));
+    }
+    if (0..XOSHIRO_LANES).any(|k| s.iter().all(|row| row[k] == 0)) {
+        return Err(bad_state("all-zero xoshiro lane".into()));
+    }
+    Ok(())
+}
+
+fn check_buffer_pos(buffer_pos: usize) -> Result<(), MRndErr> {
+    if buffer_pos > 4 {
+        return Err(bad_state(format!("buffer_pos {buffer_pos} out of range")));
+    }
+    Ok(())
+}
+
+impl BitGenState {
+    /// Build the engine this state describes.
+    pub fn to_bitgen(&self) -> Result<Box<dyn MBitGen>, MRndErr> {
+        let nonzero = |s: &[u64]| {
+            if s.iter().all(|&x| x == 0) {
+                Err(bad_state("all-zero xoshiro state".into()))
+            } else {
+                Ok(())
+            }
+        };
+
+        Ok(match self {
+            Self::Lcg64 {
+                a,
+                c,
+                state,
+                preset,
+            } => Box::new(Lcg64 {
+                a: *a,
+                c: *c,
+                state: *state,
+                preset: preset.clone(),
+            }),
+            Self::Pcg64 { state, inc } => Box::new(Pcg64::new(*state, *inc)),
+            Self::Pcg64Dxsm { state, inc } => Box::new(Pcg64Dxsm::new(*state, *inc)),
+            Self::SplitMix64 { state } => Box::new(SplitMix64::new(*state)),
+            Self::Xoshiro256StarStar { s } => {
+                nonzero(s)?;
+                Box::new(Xoshiro256StarStar::from_state(*s))
+            }
+            Self::Xoshiro256PlusPlus { s } => {
+                nonzero(s)?;
+                Box::new(Xoshiro256PlusPlus::from_state(*s))
+            }
+            Self::Xoroshiro128Plus { s } => {
+                nonzero(s)?;
+                Box::new(Xoroshiro128Plus::from_state(*s))
+            }
+            Self::Xoshiro256StarStarX4 { s, lane } => {
+                check_lanes(s, *lane)?;
+                Box::new(Xoshiro256StarStarX4::from_state(*s, *lane))
+            }
+            Self::Xoshiro256PlusPlusX4 { s, lane } => {
+                check_lanes(s, *lane)?;
+                Box::new(Xoshiro256PlusPlusX4::from_state(*s, *lane))
+            }
+            Self::Xoroshiro128PlusX4 { s, lane } => {
+                check_lanes(s, *lane)?;
+                Box::new(Xoroshiro128PlusX4::from_state(*s, *lane))
+            }
+            Self::ChaCha {
+                rounds,
+                key,
+                stream,
+                word_pos,
+            } => match rounds {
+                8 => Box::new(chacha::<8>(key, *stream, *word_pos)),
+                12 => Box::new(chacha::<12>(key, *stream, *word_pos)),
+                20 => Box::new(chacha::<20>(key, *stream, *word_pos)),
+                r => return Err(bad_state(format!("unsupported ChaCha rounds {r}"))),
+            },
+            Self::Mt19937 {
+                mt,
+                mti,
+                legacy_doubles,
+            } => {
+                let key = mt
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| bad_state(format!("Mt19937 needs 624 words, got {}", mt.len())))?;
+                if *mti > MT19937_N {
+                    return Err(bad_state(format!("Mt19937 position {mti} out of range")));
+                }
+                let mut g = Mt19937::from_state(key, *mti);
+                g.legacy_doubles = *legacy_doubles;
+                Box::new(g)
+            }
+            Self::Mt19937_64 { mt, mti } => {
+                let key = mt.as_slice().try_into().map_err(|_| {
+                    bad_state(format!("Mt19937_64 needs 312 words, got {}", mt.len()))
+                })?;
+                if *mti > MT19937_64_NN {
+                    return Err(bad_state(format!("Mt19937_64 position {mti} out of range")));
+                }
+                Box::new(Mt19937_64::from_state(key, *mti))
+            }
+            Self::Philox4x64 {
+                ctr,
+                key,
+                buffer,
+                buffer_pos,
+            } => {
+                check_buffer_pos(*buffer_pos)?;
+                Box::new(Philox4x64 {
+                    ctr: *ctr,
+                    key: *key,
+                    buffer: *buffer,
+                    buffer_pos: *buffer_pos,
+                })
+            }
+            Self::Threefry4x64 {
+                ctr,
+                key,
+                buffer,
+                buffer_pos,
+            } => {
+                check_buffer_pos(*buffer_pos)?;
+                Box::new(Threefry4x64 {
+                    ctr: *ctr,
+                    key: *key,
+                    buffer: *buffer,
+                    buffer_pos: *buffer_pos,
+                })
+            }
+        })
+    }
+}
+
+fn chacha<const ROUNDS: usize>(key: &[u8; 32], stream: u64, word_pos: u128) -> ChaChaRng<ROUNDS> {
+    let mut g = ChaChaRng::<ROUNDS>::from_seed(*key);
+    g.set_stream(stream);
+    g.set_word_pos(word_pos);
+    g
+}
+
+impl MPRngState {
+    /// JSON object with `version`, `gen_name`, `bitgen` (`{"<engine>": {...}}`) and
+    /// `spare_norm`.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("MPRngState is always serializable")
+    }
+
+    pub fn from_json(s: &str) -> Result<Self, MRndErr> {
+        let st: Self =
+            serde_json::from_str(s).map_err(|e| MRndErr::ParseErr(format!("state json: {e}")))?;
+        st.check_version()?;
+        Ok(st)
+    }
+
+    /// `b"MRNS"`, then the version, the engine name, `spare_norm` and the engine state, all
+    /// little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = ByteWriter(MAGIC.to_vec());
+        w.u32(self.version);
+        w.str(&self.gen_name);
+        match self.spare_norm {
+            Some(x) => {
+                w.u8(1);
+                w.u64(x.to_bits());
+            }
+            None => w.u8(0),
+        }
+        w.bitgen(&self.bitgen);
+        w.0
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MRndErr> {
+        let mut r = ByteReader { bytes, pos: 0 };
+        if r.take(MAGIC.len())? != MAGIC {
+            return Err(MRndErr::ParseErr("state bytes: bad magic".into()));
+        }
+        let version = r.u32()?;
+        if version != MPRNG_STATE_VERSION {
+            return Err(version_err(version));
+        }
+        let gen_name = r.str()?;
+        let spare_norm = match r.u8()? {
+            0 => None,
+            1 => Some(f64::from_bits(r.u64()?)),
+            t => return Err(MRndErr::ParseErr(format!("state bytes: bad flag {t}"))),
+        };
+        let bitgen = r.bitgen()?;
+        if r.pos != bytes.len() {
+            return Err(MRndErr::ParseErr("state bytes: trailing data".into()));
+        }
+        Ok(Self {
+            version,
+            gen_name,
+            bitgen,
+            spare_norm,
+        })
+    }
+
+    pub(crate) fn check_version(&self) -> Result<(), MRndErr> {
+        if self.version != MPRNG_STATE_VERSION {
+            return Err(version_err(self.version));
+        }
+        Ok(())
+    }
+}
+
+fn version_err(version: u32) -> MRndErr {
+    MRndErr::ParseErr(format!(
+        "state version {version} is not supported (expected {MPRNG_STATE_VERSION})"
+    ))
+}
+
+/* ---------------------------------------------------------------- */
+/* binary encoding                                                  */
+/* ---------------------------------------------------------------- */
+
+// engine tags of the binary form; never reuse a retired tag
+const TAG_LCG64: u8 = 1;
+const TAG_PCG64: u8 = 2;
+const TAG_PCG64_DXSM: u8 = 3;
+const TAG_SPLITMIX64: u8 = 4;
+const TAG_XOSHIRO256_STARSTAR: u8 = 5;
+const TAG_XOSHIRO256_PLUSPLUS: u8 = 6;
+const TAG_XOROSHIRO128_PLUS: u8 = 7;
+const TAG_CHACHA: u8 = 8;
+const TAG_MT19937: u8 = 9;
+const TAG_MT19937_64: u8 = 10;
+const TAG_PHILOX4X64: u8 = 11;
+const TAG_THREEFRY4X64: u8 = 12;
+const TAG_XOSHIRO256_STARSTAR_X4: u8 = 13;
+const TAG_XOSHIRO256_PLUSPLUS_X4: u8 = 14;
+const TAG_XOROSHIRO128_PLUS_X4: u8 = 15;
+
+struct ByteWriter(Vec<u8>);
+
+impl ByteWriter {
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u64(&mut self, v: u64) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u128(&mut self, v: u128) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u64s(&mut self, vs: &[u64]) {
+        vs.iter().for_each(|&v| self.u64(v));
+    }
+
+    fn str(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn bitgen(&mut self, st: &BitGenState) {
+        use BitGenState as S;
+        match st {
+            S::Lcg64 {
+                a,
+                c,
+                state,
+                preset,
+            } => {
+                self.u8(TAG_LCG64);
+                self.u64s(&[*a, *c, *state]);
+                match preset {
+                    Some(p) => {
+                        self.u8(1);
+                        self.str(p);
+                    }
+                    None => self.u8(0),
+                }
+            }
+            S::Pcg64 { state, inc } | S::Pcg64Dxsm { state, inc } => {
+                let dxsm = matches!(st, S::Pcg64Dxsm { .. });
+                self.u8(if dxsm { TAG_PCG64_DXSM } else { TAG_PCG64 });
+                self.u128(*state);
+                self.u128(*inc);
+            }
+            S::SplitMix64 { state } => {
+                self.u8(TAG_SPLITMIX64);
+                self.u64(*state);
+            }
+            S::Xoshiro256StarStar { s } => {
+                self.u8(TAG_XOSHIRO256_STARSTAR);
+                self.u64s(s);
+            }
+            S::Xoshiro256PlusPlus { s } => {
+                self.u8(TAG_XOSHIRO256_PLUSPLUS);
+                self.u64s(s);
+            }
+            S::Xoroshiro128Plus { s } => {
+                self.u8(TAG_XOROSHIRO128_PLUS);
+                self.u64s(s);
+            }
+            S::Xoshiro256StarStarX4 { s, lane } => {
+                self.u8(TAG_XOSHIRO256_STARSTAR_X4);
+                self.u64s(s.as_flattened());
+                self.u64(*lane as u64);
+            }
+            S::Xoshiro256PlusPlusX4 { s, lane } => {
+                self.u8(TAG_XOSHIRO256_PLUSPLUS_X4);
+                self.u64s(s.as_flattened());
+                self.u64(*lane as u64);
+            }
+            S::Xoroshiro128PlusX4 { s, lane } => {
+                self.u8(TAG_XOROSHIRO128_PLUS_X4);
+                self.u64s(s.as_flattened());
+                self.u64(*lane as u64);
+            }
+            S::ChaCha {
+                rounds,
+                key,
+                stream,
+                word_pos,
+            } => {
+                self.u8(TAG_CHACHA);
+                self.u32(*rounds);
+                self.0.extend_from_slice(key);
+                self.u64(*stream);
+                self.u128(*word_pos);
+            }
+            S::Mt19937 {
+                mt,
+                mti,
+                legacy_doubles,
+            } => {
+                self.u8(TAG_MT19937);
+                self.u32(mt.len() as u32);
+                mt.iter().for_each(|&v| self.u32(v));
+                self.u64(*mti as u64);
+                self.u8(*legacy_doubles as u8);
+            }
+            S::Mt19937_64 { mt, mti } => {
+                self.u8(TAG_MT19937_64);
+                self.u32(mt.len() as u32);
+                self.u64s(mt);
+                self.u64(*mti as u64);
+            }
+            S::Philox4x64 {
+                ctr,
+                key,
+                buffer,
+                buffer_pos,
+            } => {
+                self.u8(TAG_PHILOX4X64);
+                self.u64s(ctr);
+                self.u64s(key);
+                self.u64s(buffer);
+                self.u64(*buffer_pos as u64);
+            }
+            S::Threefry4x64 {
+                ctr,
+                key,
+                buffer,
+                buffer_pos,
+            } => {
+                self.u8(TAG_THREEFRY4X64);
+                self.u64s(ctr);
+                self.u64s(key);
+                self.u64s(buffer);
+                self.u64(*buffer_pos as u64);
+            }
+        }
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], MRndErr> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| MRndErr::ParseErr("state bytes: truncated".into()))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], MRndErr> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, MRndErr> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, MRndErr> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, MRndErr> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn u128(&mut self) -> Result<u128, MRndErr> {
+        self.array().map(u128::from_le_bytes)
+    }
+
+    fn usize(&mut self) -> Result<usize, MRndErr> {
+        usize::try_from(self.u64()?)
+            .map_err(|_| MRndErr::ParseErr("state bytes: index too large".into()))
+    }
+
+    fn u64s<const N: usize>(&mut self) -> Result<[u64; N], MRndErr> {
+        let mut out = [0; N];
+        for x in &mut out {
+            *x = self.u64()?;
+        }
+        Ok(out)
+    }
+
+    fn lanes<const N: usize>(&mut self) -> Result<[[u64; XOSHIRO_LANES]; N], MRndErr> {
+        let mut out = [[0; XOSHIRO_LANES]; N];
+        for row in &mut out {
+            *row = self.u64s()?;
+        }
+        Ok(out)
+    }
+
+    fn str(&mut self) -> Result<String, MRndErr> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| MRndErr::ParseErr("state bytes: invalid utf-8".into()))
+    }
+
+    fn bitgen(&mut self) -> Result<BitGenState, MRndErr> {
+        use BitGenState as S;
+        Ok(match self.u8()? {
+            TAG_LCG64 => {
+                let [a, c, state] = self.u64s()?;
+                let preset = match self.u8()? {
+                    0 => None,
+                    _ => Some(self.str()?),
+                };
+                S::Lcg64 {
+                    a,
+                    c,
+                    state,
+                    preset,
+                }
+            }
+            TAG_PCG64 => S::Pcg64 {
+                state: self.u128()?,
+                inc: self.u128()?,
+            },
+            TAG_PCG64_DXSM => S::Pcg64Dxsm {
+                state: self.u128()?,
+                inc: self.u128()?,
+            },
+            TAG_SPLITMIX64 => S::SplitMix64 { state: self.u64()? },
+            TAG_XOSHIRO256_STARSTAR => S::Xoshiro256StarStar { s: self.u64s()? },
+            TAG_XOSHIRO256_PLUSPLUS => S::Xoshiro256PlusPlus { s: self.u64s()? },
+            TAG_XOROSHIRO128_PLUS => S::Xoroshiro128Plus { s: self.u64s()? },
+            TAG_XOSHIRO256_STARSTAR_X4 => S::Xoshiro256StarStarX4 {
+                s: self.lanes()?,
+                lane: self.usize()?,
+            },
+            TAG_XOSHIRO256_PLUSPLUS_X4 => S::Xoshiro256PlusPlusX4 {
+                s: self.lanes()?,
+                lane: self.usize()?,
+            },
+            TAG_XOROSHIRO128_PLUS_X4 => S::Xoroshiro128PlusX4 {
+                s: self.lanes()?,
+                lane: self.usize()?,
+            },
+            TAG_CHACHA => S::ChaCha {
+                rounds: self.u32()?,
+                key: self.array()?,
+                stream: self.u64()?,
+                word_pos: self.u128()?,
+            },
+            TAG_MT19937 => {
+                let len = self.u32()? as usize;
+                let mt = (0..len).map(|_| self.u32()).collect::<Result<_, _>>()?;
+                S::Mt19937 {
+                    mt,
+                    mti: self.usize()?,
+                    legacy_doubles: self.u8()? != 0,
+                }
+            }
+            TAG_MT19937_64 => {
+                let len = self.u32()? as usize;
+                let mt = (0..len).map(|_| self.u64()).collect::<Result<_, _>>()?;
+                S::Mt19937_64 {
+                    mt,
+                    mti: self.usize()?,
+                }
+            }
+            TAG_PHILOX4X64 => S::Philox4x64 {
+                ctr: self.u64s()?,
+                key: self.u64s()?,
+                buffer: self.u64s()?,
+                buffer_pos: self.usize()?,
+            },
+            TAG_THREEFRY4X64 => S::Threefry4x64 {
+                ctr: self.u64s()?,
+                key: self.u64s()?,
+                buffer: self.u64s()?,
+                buffer_pos: self.usize()?,
+            },
+            t => {
+                return Err(MRndErr::ParseErr(format!(
+                    "state bytes: unknown engine tag {t}"
+                )));
+            }
+        })
+    }
+}
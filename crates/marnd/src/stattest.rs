@@ -0,0 +1,602 @@
+//! Statistical tests for bit generators, after NIST SP 800-22 and TestU01's SmallCrush.
+//!
+//! Tests read bits from a `BitStream`, which can be restricted to a slice of each output
+//! (TestU01's `r`, `s` parameters) to look at weak low bits on their own. Each test returns
+//! a `TestResult` whose p-value is uniform on `[0, 1]` for a good generator; like TestU01,
+//! `passed` flags p-values in either tail, as a statistic that is too regular is suspicious too.
+//!
+//! `battery` runs every test with default sizes, and the `marnd-stattest` binary runs it on
+//! any engine schema.
+
+use crate::dist::discrete::loggam;
+use crate::{MBitGen, MRndErr};
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub name: &'static str,
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// `alpha <= p_value <= 1 - alpha`.
+    pub fn passed(&self, alpha: f64) -> bool {
+        self.p_value >= alpha && self.p_value <= 1.0 - alpha
+    }
+}
+
+impl fmt::Display for TestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<18} stat = {:>14.4}  p = ", self.name, self.statistic)?;
+        if self.p_value != 0.0 && self.p_value < 1e-4 {
+            write!(f, "{:.4e}", self.p_value)
+        } else {
+            write!(f, "{:.6}", self.p_value)
+        }
+    }
+}
+
+/// Bits of a generator, most significant first, optionally taken from a slice of each output.
+pub struct BitStream<'a> {
+    bitgen: &'a mut dyn MBitGen,
+    shift: u32,
+    width: u32,
+    acc: u128,
+    n_acc: u32,
+}
+
+impl<'a> BitStream<'a> {
+    /// All 64 bits of every output.
+    pub fn new(bitgen: &'a mut dyn MBitGen) -> Self {
+        Self {
+            bitgen,
+            shift: 0,
+            width: 64,
+            acc: 0,
+            n_acc: 0,
+        }
+    }
+
+    /// Only bits `shift..shift + width` of every output; `with_slice(g, 0, 8)` tests the low byte.
+    pub fn with_slice(
+        bitgen: &'a mut dyn MBitGen,
+        shift: u32,
+        width: u32,
+    ) -> Result<Self, MRndErr> {
+        if width == 0 || shift.checked_add(width).is_none_or(|end| end > 64) {
+            return Err(MRndErr::InvalidArgument(format!(
+                "BitStream: need 0 < width and shift + width <= 64 (got shift {shift}, width {width})"
+            )));
+        }
+        Ok(Self {
+            shift,
+            width,
+            ..Self::new(bitgen)
+        })
+    }
+
+    /// The next `k` bits (`1 <= k <= 64`) as an integer.
+    pub fn next_bits(&mut self, k: u32) -> u64 {
+        debug_assert!((1..=64).contains(&k));
+        while self.n_acc < k {
+            let chunk = (self.bitgen.next_u64() >> self.shift) & low_mask(self.width);
+            self.acc = (self.acc << self.width) | chunk as u128;
+            self.n_acc += self.width;
+        }
+        self.n_acc -= k;
+        let out = (self.acc >> self.n_acc) as u64 & low_mask(k);
+        self.acc &= (1u128 << self.n_acc) - 1;
+        out
+    }
+
+    #[inline]
+    pub fn next_bit(&mut self) -> u8 {
+        self.next_bits(1) as u8
+    }
+
+    /// A uniform in `[0, 1)` from the next 53 bits.
+    #[inline]
+    pub fn next_unit(&mut self) -> f64 {
+        self.next_bits(53) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    fn collect_bits(&mut self, n: usize) -> Vec<u8> {
+        (0..n).map(|_| self.next_bit()).collect()
+    }
+}
+
+fn low_mask(k: u32) -> u64 {
+    if k == 64 { u64::MAX } else { (1u64 << k) - 1 }
+}
+
+fn invalid(test: &str, msg: String) -> MRndErr {
+    MRndErr::InvalidArgument(format!("{test}: {msg}"))
+}
+
+// ---------------------------------------------------------------------------------------------
+// Distribution functions
+
+/// Regularized upper incomplete gamma function `Q(a, x)`.
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - gamma_p_series(a, x)
+    } else {
+        gamma_q_cont_frac(a, x)
+    }
+}
+
+/// Regularized lower incomplete gamma function `P(a, x) = 1 - Q(a, x)`.
+pub fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        gamma_p_series(a, x)
+    } else {
+        1.0 - gamma_q_cont_frac(a, x)
+    }
+}
+
+fn gamma_p_series(a: f64, x: f64) -> f64 {
+    let (mut ap, mut del) = (a, 1.0 / a);
+    let mut sum = del;
+    for _ in 0..10_000 {
+        ap += 1.0;
+        del *= x / ap;
+        sum += del;
+        if del.abs() < sum.abs() * 1e-16 {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - loggam(a)).exp()
+}
+
+/// Modified Lentz evaluation of the continued fraction for `Q(a, x)`.
+fn gamma_q_cont_frac(a: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..10_000 {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let del = d * c;
+        h *= del;
+        if (del - 1.0).abs() < 1e-16 {
+            break;
+        }
+    }
+    (-x + a * x.ln() - loggam(a)).exp() * h
+}
+
+/// Complementary error function, as `Q(1/2, x^2)`.
+pub fn erfc(x: f64) -> f64 {
+    if x >= 0.0 {
+        gamma_q(0.5, x * x)
+    } else {
+        2.0 - gamma_q(0.5, x * x)
+    }
+}
+
+/// Upper tail of the chi-square distribution with `df` degrees of freedom.
+pub fn chi_square_p_value(statistic: f64, df: f64) -> f64 {
+    gamma_q(df / 2.0, statistic / 2.0)
+}
+
+/// Upper tail of the Kolmogorov distribution, `P(K > lambda)`.
+pub fn kolmogorov_q(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0;
+    }
+    let (mut sum, mut sign) = (0.0, 1.0);
+    for j in 1..=100 {
+        let term = 2.0 * (-2.0 * (j * j) as f64 * lambda * lambda).exp();
+        sum += sign * term;
+        if term <= 1e-16 * sum.abs() {
+            break;
+        }
+        sign = -sign;
+    }
+    sum.clamp(0.0, 1.0)
+}
+
+// ---------------------------------------------------------------------------------------------
+// Toolbox
+
+/// Pearson's chi-square of `observed` counts against `expected` counts, `len - 1` degrees
+/// of freedom. Cells are not pooled; keep every expected count at 5 or more.
+pub fn chi_square(observed: &[u64], expected: &[f64]) -> Result<TestResult, MRndErr> {
+    if observed.len() != expected.len() || observed.len() < 2 {
+        return Err(invalid(
+            "chi_square",
+            format!(
+                "need at least 2 cells and as many expected counts as observed (got {} and {})",
+                observed.len(),
+                expected.len()
+            ),
+        ));
+    }
+    if let Some(e) = expected.iter().find(|e| !(e.is_finite() && **e > 0.0)) {
+        return Err(invalid(
+            "chi_square",
+            format!("expected counts must be positive (got {e})"),
+        ));
+    }
+    let statistic: f64 = observed
+        .iter()
+        .zip(expected)
+        .map(|(&o, &e)| (o as f64 - e) * (o as f64 - e) / e)
+        .sum();
+    Ok(TestResult {
+        name: "chi_square",
+        statistic,
+        p_value: chi_square_p_value(statistic, (observed.len() - 1) as f64),
+    })
+}
+
+/// Kolmogorov–Smirnov test of `samples` against the uniform distribution on `[0, 1]`, with
+/// Stephens' small-sample correction.
+pub fn ks_uniform(samples: &[f64]) -> Result<TestResult, MRndErr> {
+    if samples.is_empty() {
+        return Err(invalid("ks_uniform", "no samples".into()));
+    }
+    if let Some(x) = samples.iter().find(|x| !(0.0..=1.0).contains(*x)) {
+        return Err(invalid(
+            "ks_uniform",
+            format!("samples must lie in [0, 1] (got {x})"),
+        ));
+    }
+    let mut xs = samples.to_vec();
+    xs.sort_unstable_by(f64::total_cmp);
+    let n = xs.len() as f64;
+    let d = xs
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| ((i + 1) as f64 / n - x).max(x - i as f64 / n))
+        .fold(0.0, f64::max);
+    let sqrt_n = n.sqrt();
+    Ok(TestResult {
+        name: "ks_uniform",
+        statistic: d,
+        p_value: kolmogorov_q((sqrt_n + 0.12 + 0.11 / sqrt_n) * d),
+    })
+}
+
+// ---------------------------------------------------------------------------------------------
+// Tests
+
+/// Frequency (monobit) test, SP 800-22 2.1: the balance of ones and zeros in `n` bits.
+pub fn monobit(bits: &mut BitStream, n: usize) -> Result<TestResult, MRndErr> {
+    if n < 100 {
+        return Err(invalid("monobit", format!("need n >= 100 (got {n})")));
+    }
+    let ones: usize = (0..n).map(|_| bits.next_bit() as usize).sum();
+    let s_obs = (2.0 * ones as f64 - n as f64).abs() / (n as f64).sqrt();
+    Ok(TestResult {
+        name: "monobit",
+        statistic: s_obs,
+        p_value: erfc(s_obs / core::f64::consts::SQRT_2),
+    })
+}
+
+/// Runs test, SP 800-22 2.3: the number of runs of identical bits in `n` bits. A sequence
+/// that already fails the frequency prerequisite gets a p-value of 0.
+pub fn runs(bits: &mut BitStream, n: usize) -> Result<TestResult, MRndErr> {
+    if n < 100 {
+        return Err(invalid("runs", format!("need n >= 100 (got {n})")));
+    }
+    let s = bits.collect_bits(n);
+    let nf = n as f64;
+    let pi = s.iter().map(|&b| b as usize).sum::<usize>() as f64 / nf;
+    let v = 1 + s.windows(2).filter(|w| w[0] != w[1]).count();
+    let p_value = if (pi - 0.5).abs() >= 2.0 / nf.sqrt() {
+        0.0
+    } else {
+        let q = pi * (1.0 - pi);
+        erfc((v as f64 - 2.0 * nf * q).abs() / (2.0 * (2.0 * nf).sqrt() * q))
+    };
+    Ok(TestResult {
+        name: "runs",
+        statistic: v as f64,
+        p_value,
+    })
+}
+
+/// Poker test: chi-square of `n_hands` values of `m` bits over the `2^m` possible hands.
+pub fn poker(bits: &mut BitStream, m: u32, n_hands: usize) -> Result<TestResult, MRndErr> {
+    if !(1..=16).contains(&m) {
+        return Err(invalid("poker", format!("need 1 <= m <= 16 (got {m})")));
+    }
+    let cells = 1usize << m;
+    if n_hands < 5 * cells {
+        return Err(invalid(
+            "poker",
+            format!("need n_hands >= 5 * 2^m = {} (got {n_hands})", 5 * cells),
+        ));
+    }
+    let mut counts = vec![0u64; cells];
+    for _ in 0..n_hands {
+        counts[bits.next_bits(m) as usize] += 1;
+    }
+    let expected = vec![n_hands as f64 / cells as f64; cells];
+    Ok(TestResult {
+        name: "poker",
+        ..chi_square(&counts, &expected)?
+    })
+}
+
+/// `psi^2_m` of SP 800-22 2.11: overlapping `m`-bit pattern counts, wrapping around.
+fn psi_sq(s: &[u8], m: u32) -> f64 {
+    if m == 0 {
+        return 0.0;
+    }
+    let n = s.len();
+    let mask = (1usize << m) - 1;
+    let mut counts = vec![0u64; 1 << m];
+    let mut w = s[..m as usize - 1]
+        .iter()
+        .fold(0usize, |w, &b| (w << 1) | b as usize);
+    for i in 0..n {
+        w = ((w << 1) | s[(i + m as usize - 1) % n] as usize) & mask;
+        counts[w] += 1;
+    }
+    let sum_sq: f64 = counts.iter().map(|&c| (c * c) as f64).sum();
+    (1u64 << m) as f64 / n as f64 * sum_sq - n as f64
+}
+
+/// Serial test, SP 800-22 2.11: the first difference `psi^2_m - psi^2_{m-1}` of overlapping
+/// `m`-bit pattern counts in `n` bits.
+pub fn serial(bits: &mut BitStream, n: usize, m: u32) -> Result<TestResult, MRndErr> {
+    if !(2..=24).contains(&m) || n >> (m + 3) == 0 {
+        return Err(invalid(
+            "serial",
+            format!("need 2 <= m <= 24 and n >= 2^(m + 3) (got m {m}, n {n})"),
+        ));
+    }
+    let s = bits.collect_bits(n);
+    let del = psi_sq(&s, m) - psi_sq(&s, m - 1);
+    Ok(TestResult {
+        name: "serial",
+        statistic: del,
+        p_value: gamma_q((1u64 << (m - 2)) as f64, del / 2.0),
+    })
+}
+
+/// Marsaglia's birthday spacings test: `reps` times, `n` birthdays of `log2_days` bits are
+/// sorted and the repeated values among their spacings counted. The total is approximately
+/// Poisson with mean `reps * n^3 / (4 * 2^log2_days)`; the p-value is its upper tail.
+pub fn birthday_spacings(
+    bits: &mut BitStream,
+    n: usize,
+    log2_days: u32,
+    reps: usize,
+) -> Result<TestResult, MRndErr> {
+    if !(1..=64).contains(&log2_days) || n < 2 || reps == 0 {
+        return Err(invalid(
+            "birthday_spacings",
+            format!("need 1 <= log2_days <= 64, n >= 2, reps >= 1 (got {log2_days}, {n}, {reps})"),
+        ));
+    }
+    let lambda = (n as f64).powi(3) / (4.0 * 2f64.powi(log2_days as i32));
+    let mut birthdays = vec![0u64; n];
+    let mut spacings = vec![0u64; n - 1];
+    let mut collisions = 0usize;
+    for _ in 0..reps {
+        for b in birthdays.iter_mut() {
+            *b = bits.next_bits(log2_days);
+        }
+        birthdays.sort_unstable();
+        for (s, w) in spacings.iter_mut().zip(birthdays.windows(2)) {
+            *s = w[1] - w[0];
+        }
+        spacings.sort_unstable();
+        collisions += spacings.windows(2).filter(|w| w[0] == w[1]).count();
+    }
+    let p_value = if collisions == 0 {
+        1.0
+    } else {
+        gamma_p(collisions as f64, reps as f64 * lambda)
+    };
+    Ok(TestResult {
+        name: "birthday_spacings",
+        statistic: collisions as f64,
+        p_value,
+    })
+}
+
+/// Knuth's gap test: lengths of runs of uniforms outside `[alpha, beta)`, counted up to `t`
+/// (longer gaps share the last cell), chi-square over `n_gaps` gaps.
+pub fn gap(
+    bits: &mut BitStream,
+    n_gaps: usize,
+    alpha: f64,
+    beta: f64,
+    t: usize,
+) -> Result<TestResult, MRndErr> {
+    if !(0.0 <= alpha && alpha < beta && beta <= 1.0) || t == 0 {
+        return Err(invalid(
+            "gap",
+            format!("need 0 <= alpha < beta <= 1 and t >= 1 (got {alpha}, {beta}, {t})"),
+        ));
+    }
+    let p = beta - alpha;
+    let mut expected: Vec<f64> = (0..t)
+        .map(|r| n_gaps as f64 * p * (1.0 - p).powi(r as i32))
+        .collect();
+    expected.push(n_gaps as f64 * (1.0 - p).powi(t as i32));
+
+    let mut counts = vec![0u64; t + 1];
+    for _ in 0..n_gaps {
+        // By independence a gap cut at `t` can end right there, which keeps a generator
+        // that never enters the interval from stalling the test.
+        let mut r = 0;
+        while r < t {
+            let u = bits.next_unit();
+            if alpha <= u && u < beta {
+                break;
+            }
+            r += 1;
+        }
+        counts[r] += 1;
+    }
+    Ok(TestResult {
+        name: "gap",
+        ..chi_square(&counts, &expected)?
+    })
+}
+
+fn gf2_rank(mut rows: [u32; 32]) -> usize {
+    let mut rank = 0;
+    for bit in (0..32).rev() {
+        let mask = 1u32 << bit;
+        let Some(p) = (rank..32).find(|&i| rows[i] & mask != 0) else {
+            continue;
+        };
+        rows.swap(rank, p);
+        for i in 0..32 {
+            if i != rank && rows[i] & mask != 0 {
+                rows[i] ^= rows[rank];
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Probability that a random binary `m x q` matrix has rank `r`.
+fn rank_probability(r: i32, m: i32, q: i32) -> f64 {
+    let prod: f64 = (0..r)
+        .map(|i| (1.0 - 2f64.powi(i - q)) * (1.0 - 2f64.powi(i - m)) / (1.0 - 2f64.powi(i - r)))
+        .product();
+    2f64.powi(r * (q + m - r) - m * q) * prod
+}
+
+/// Binary matrix rank test, SP 800-22 2.5: ranks over GF(2) of `n_matrices` 32 x 32
+/// matrices, binned as full, full - 1 and lower.
+pub fn matrix_rank(bits: &mut BitStream, n_matrices: usize) -> Result<TestResult, MRndErr> {
+    if n_matrices < 38 {
+        return Err(invalid(
+            "matrix_rank",
+            format!("need n_matrices >= 38 (got {n_matrices})"),
+        ));
+    }
+    let mut counts = [0u64; 3];
+    for _ in 0..n_matrices {
+        let rows: [u32; 32] = core::array::from_fn(|_| bits.next_bits(32) as u32);
+        counts[32usize.saturating_sub(gf2_rank(rows)).min(2)] += 1;
+    }
+    let p_full = rank_probability(32, 32, 32);
+    let p_minus1 = rank_probability(31, 32, 32);
+    let expected = [p_full, p_minus1, 1.0 - p_full - p_minus1].map(|p| p * n_matrices as f64);
+    Ok(TestResult {
+        name: "matrix_rank",
+        ..chi_square(&counts, &expected)?
+    })
+}
+
+/// Length of the shortest LFSR generating `s` (Berlekamp–Massey over GF(2)).
+fn berlekamp_massey(s: &[u8]) -> usize {
+    let n = s.len();
+    let mut c = vec![0u8; n + 1];
+    let mut b = vec![0u8; n + 1];
+    c[0] = 1;
+    b[0] = 1;
+    let (mut l, mut m) = (0usize, 0usize);
+    for i in 0..n {
+        let d = (1..=l).fold(s[i], |d, j| d ^ (c[j] & s[i - j]));
+        if d == 1 {
+            let t = c.clone();
+            // distance from the last length change, `m` counts from -1
+            let shift = i + 1 - m;
+            for j in 0..=n - shift {
+                c[j + shift] ^= b[j];
+            }
+            if 2 * l <= i {
+                l = i + 1 - l;
+                m = i + 1;
+                b = t;
+            }
+        }
+    }
+    l
+}
+
+/// Linear complexity test, SP 800-22 2.10: Berlekamp–Massey complexities of `n_blocks`
+/// blocks of `m` bits against their known distribution. SP 800-22 asks for
+/// `500 <= m <= 5000` and at least 200 blocks.
+pub fn linear_complexity(
+    bits: &mut BitStream,
+    m: usize,
+    n_blocks: usize,
+) -> Result<TestResult, MRndErr> {
+    if !(500..=5000).contains(&m) || n_blocks == 0 {
+        return Err(invalid(
+            "linear_complexity",
+            format!("need 500 <= m <= 5000 and n_blocks >= 1 (got {m}, {n_blocks})"),
+        ));
+    }
+    const PI: [f64; 7] = [
+        1.0 / 96.0,
+        1.0 / 32.0,
+        1.0 / 8.0,
+        1.0 / 2.0,
+        1.0 / 4.0,
+        1.0 / 16.0,
+        1.0 / 48.0,
+    ];
+    let mf = m as f64;
+    let sign = if m.is_multiple_of(2) { 1.0 } else { -1.0 };
+    let mu = mf / 2.0 + (9.0 - sign) / 36.0 - (mf / 3.0 + 2.0 / 9.0) / 2f64.powi(m as i32);
+
+    let mut counts = [0u64; 7];
+    for _ in 0..n_blocks {
+        let l = berlekamp_massey(&bits.collect_bits(m)) as f64;
+        let t = sign * (l - mu) + 2.0 / 9.0;
+        counts[(t + 2.5).ceil().clamp(0.0, 6.0) as usize] += 1;
+    }
+    let expected = PI.map(|p| p * n_blocks as f64);
+    Ok(TestResult {
+        name: "linear_complexity",
+        ..chi_square(&counts, &expected)?
+    })
+}
+
+/// Kolmogorov–Smirnov test of `n` uniforms built from 53 bits each.
+pub fn uniform_ks(bits: &mut BitStream, n: usize) -> Result<TestResult, MRndErr> {
+    if n < 10 {
+        return Err(invalid("uniform_ks", format!("need n >= 10 (got {n})")));
+    }
+    let xs: Vec<f64> = (0..n).map(|_| bits.next_unit()).collect();
+    Ok(TestResult {
+        name: "uniform_ks",
+        ..ks_uniform(&xs)?
+    })
+}
+
+/// Every test with default sizes, multiplied by `scale` (`1` reads about 2^24 bits).
+pub fn battery(bits: &mut BitStream, scale: usize) -> Result<Vec<TestResult>, MRndErr> {
+    let k = scale.max(1);
+    Ok(vec![
+        monobit(bits, k << 20)?,
+        runs(bits, k << 20)?,
+        poker(bits, 8, k << 16)?,
+        serial(bits, k << 20, 10)?,
+        birthday_spacings(bits, 1 << 12, 32, 16 * k)?,
+        gap(bits, 10_000 * k, 0.0, 1.0 / 16.0, 64)?,
+        matrix_rank(bits, 1000 * k)?,
+        linear_complexity(bits, 500, 500 * k)?,
+        uniform_ks(bits, 10_000 * k)?,
+    ])
+}
@@ -0,0 +1,150 @@
+//! Ziggurat samplers (Marsaglia & Tsang, 2000) for the standard normal and exponential
+//! distributions, following numpy's `random_standard_normal` / `random_standard_exponential`.
+//!
+//! One draw picks a layer from its low 8 bits and a position in it from the rest; about 99% of
+//! the samples are a multiply and a compare. Only the wedges and the tail need `exp`/`ln`.
+use crate::fill::WordSource;
+use crate::ziggurat_tables::{
+    FE_DOUBLE, FE_FLOAT, FI_DOUBLE, FI_FLOAT, KE_DOUBLE, KE_FLOAT, KI_DOUBLE, KI_FLOAT, WE_DOUBLE,
+    WE_FLOAT, WI_DOUBLE, WI_FLOAT,
+};
+use crate::{MBitGen, MPRng};
+
+/// Start of the normal tail (right edge of the base layer).
+pub const ZIGGURAT_NOR_R: f64 = 3.654_152_885_361_009;
+/// Start of the exponential tail.
+pub const ZIGGURAT_EXP_R: f64 = 7.697_117_470_131_05;
+
+const ZIGGURAT_NOR_INV_R: f64 = 1.0 / ZIGGURAT_NOR_R;
+const ZIGGURAT_NOR_R_F: f32 = ZIGGURAT_NOR_R as f32;
+const ZIGGURAT_NOR_INV_R_F: f32 = ZIGGURAT_NOR_INV_R as f32;
+const ZIGGURAT_EXP_R_F: f32 = ZIGGURAT_EXP_R as f32;
+
+impl<G: MBitGen> MPRng<G> {
+    /// Uniform f32 in [0, 1) from the top 24 bits of `next_u32`.
+    #[inline(always)]
+    fn next_f32_co(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Standard normal deviate (mean 0, standard deviation 1) by the ziggurat method.
+    pub fn standard_normal(&mut self) -> f64 {
+        standard_normal_from(self)
+    }
+
+    /// `standard_normal` in single precision, one 32-bit draw per try.
+    pub fn standard_normal_f32(&mut self) -> f32 {
+        loop {
+            // 8 bits layer, 1 bit sign, 23 bits position
+            let r = self.next_u32();
+            let idx = (r & 0xff) as usize;
+            let rabs = (r >> 9) & 0x007f_ffff;
+            let mut x = rabs as f32 * WI_FLOAT[idx];
+            if (r >> 8) & 1 != 0 {
+                x = -x;
+            }
+            if rabs < KI_FLOAT[idx] {
+                return x;
+            }
+            if idx == 0 {
+                loop {
+                    let xx = -ZIGGURAT_NOR_INV_R_F * (-self.next_f32_co()).ln_1p();
+                    let yy = -(-self.next_f32_co()).ln_1p();
+                    if yy + yy > xx * xx {
+                        return if (rabs >> 8) & 1 != 0 {
+                            -(ZIGGURAT_NOR_R_F + xx)
+                        } else {
+                            ZIGGURAT_NOR_R_F + xx
+                        };
+                    }
+                }
+            }
+            if (FI_FLOAT[idx - 1] - FI_FLOAT[idx]) * self.next_f32_co() + FI_FLOAT[idx]
+                < (-0.5 * x * x).exp()
+            {
+                return x;
+            }
+        }
+    }
+
+    /// Standard exponential deviate (rate 1) by the ziggurat method.
+    pub fn standard_exponential(&mut self) -> f64 {
+        loop {
+            // 8 bits layer, 53 bits position
+            let r = self.next_u64() >> 3;
+            let idx = (r & 0xff) as usize;
+            let r = r >> 8;
+            let x = r as f64 * WE_DOUBLE[idx];
+            if r < KE_DOUBLE[idx] {
+                return x;
+            }
+            if idx == 0 {
+                // memoryless tail: r plus a fresh exponential
+                return ZIGGURAT_EXP_R - (-self.next_f64()).ln_1p();
+            }
+            if (FE_DOUBLE[idx - 1] - FE_DOUBLE[idx]) * self.next_f64() + FE_DOUBLE[idx] < (-x).exp()
+            {
+                return x;
+            }
+        }
+    }
+
+    /// `standard_exponential` in single precision, one 32-bit draw per try.
+    pub fn standard_exponential_f32(&mut self) -> f32 {
+        loop {
+            // 8 bits layer, 23 bits position
+            let r = self.next_u32() >> 1;
+            let idx = (r & 0xff) as usize;
+            let r = r >> 8;
+            let x = r as f32 * WE_FLOAT[idx];
+            if r < KE_FLOAT[idx] {
+                return x;
+            }
+            if idx == 0 {
+                return ZIGGURAT_EXP_R_F - (-self.next_f32_co()).ln_1p();
+            }
+            if (FE_FLOAT[idx - 1] - FE_FLOAT[idx]) * self.next_f32_co() + FE_FLOAT[idx] < (-x).exp()
+            {
+                return x;
+            }
+        }
+    }
+}
+
+/// `MPRng::standard_normal` on any word source, so `fill_normal` can read prefetched words.
+#[inline]
+pub(crate) fn standard_normal_from<W: WordSource>(w: &mut W) -> f64 {
+    loop {
+        // 8 bits layer, 1 bit sign, 52 bits position
+        let r = w.next_u64();
+        let idx = (r & 0xff) as usize;
+        let r = r >> 8;
+        let rabs = (r >> 1) & 0x000f_ffff_ffff_ffff;
+        let mut x = rabs as f64 * WI_DOUBLE[idx];
+        if r & 1 != 0 {
+            x = -x;
+        }
+        if rabs < KI_DOUBLE[idx] {
+            return x;
+        }
+        if idx == 0 {
+            // tail beyond r (Marsaglia 1964); 1 - U avoids ln(0)
+            loop {
+                let xx = -ZIGGURAT_NOR_INV_R * (-w.next_f64()).ln_1p();
+                let yy = -(-w.next_f64()).ln_1p();
+                if yy + yy > xx * xx {
+                    return if (rabs >> 8) & 1 != 0 {
+                        -(ZIGGURAT_NOR_R + xx)
+                    } else {
+                        ZIGGURAT_NOR_R + xx
+                    };
+                }
+            }
+        }
+        if (FI_DOUBLE[idx - 1] - FI_DOUBLE[idx]) * w.next_f64() + FI_DOUBLE[idx]
+            < (-0.5 * x * x).exp()
+        {
+            return x;
+        }
+    }
+}
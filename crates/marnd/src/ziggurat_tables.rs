@@ -0,0 +1,695 @@
+//! Tables of the 256-layer ziggurats of `crate::ziggurat`.
+//!
+//! Built with Marsaglia & Tsang's `zigset` construction (as numpy's `ziggurat_constants.h`),
+//! evaluated in 60-digit arithmetic: `K*` are the acceptance thresholds scaled to the integer
+//! draw, `W*` the layer widths per unit of the draw and `F*` the density at each layer edge.
+
+#[rustfmt::skip]
+pub(crate) const KI_DOUBLE: [u64; 256] = [
+    0x000e_f33d_8025_ef65, 0x0000_0000_0000_0000, 0x000c_08be_98fb_c6c6, 0x000d_a354_fabd_814b,
+    0x000e_51f6_7ec1_eeef, 0x000e_b255_e9d3_f780, 0x000e_ef4b_817e_caba, 0x000f_1947_0afa_44ac,
+    0x000f_37ed_61ff_cb17, 0x000f_4f46_9561_255b, 0x000f_61a5_e41b_a396, 0x000f_707a_7553_96a4,
+    0x000f_7cb2_ec28_449b, 0x000f_86f1_0c63_57d3, 0x000f_8fa6_5783_25dd, 0x000f_9724_c74d_d0da,
+    0x000f_9da9_07db_f508, 0x000f_a360_f581_fa72, 0x000f_a86f_de5b_4bf8, 0x000f_acf1_60d3_54dc,
+    0x000f_b0fb_6718_b90e, 0x000f_b49f_8d53_74c5, 0x000f_b7ec_2366_fe77, 0x000f_baec_e9a1_e50c,
+    0x000f_bdab_9d04_0bed, 0x000f_c030_60ff_6c57, 0x000f_c282_1037_a248, 0x000f_c4a6_7ae2_5bd1,
+    0x000f_c6a2_977a_ee30, 0x000f_c87a_a928_96a4, 0x000f_ca32_5e4b_de85, 0x000f_cbcc_e902_231a,
+    0x000f_cd4d_12f8_39c4, 0x000f_ceb5_4d8f_ec99, 0x000f_d007_bf1d_c930, 0x000f_d146_4dd6_c4e5,
+    0x000f_d272_a8e2_f450, 0x000f_d38e_4ff0_c91e, 0x000f_d49a_9990_b479, 0x000f_d598_b892_0f52,
+    0x000f_d689_c08e_99ec, 0x000f_d76e_a9c8_e832, 0x000f_d848_547b_08e8, 0x000f_d917_8bad_2c8b,
+    0x000f_d9dd_07a7_add2, 0x000f_da99_7010_5e8b, 0x000f_db4d_5dc0_2e1f, 0x000f_dbf9_5c5b_fcd0,
+    0x000f_dc9d_ebb9_9a7d, 0x000f_dd3b_8118_729d, 0x000f_ddd2_8834_2f8f, 0x000f_de63_6436_9f63,
+    0x000f_deee_708d_514e, 0x000f_df74_01a6_b42e, 0x000f_dff4_6599_ed3e, 0x000f_e06f_e4bc_24f1,
+    0x000f_e0e6_c225_a258, 0x000f_e159_3c28_b84b, 0x000f_e1c7_8cbc_3f98, 0x000f_e231_e9db_1ca9,
+    0x000f_e298_85da_1b91, 0x000f_e2fb_8fb5_4186, 0x000f_e35b_3355_8d4a, 0x000f_e3b7_99d0_002a,
+    0x000f_e410_e99e_ad7e, 0x000f_e467_46d4_7734, 0x000f_e4ba_d34c_095b, 0x000f_e50b_aed2_9524,
+    0x000f_e559_f74e_bc77, 0x000f_e5a5_c8e4_1212, 0x000f_e5ef_3e13_8689, 0x000f_e636_6fd9_1077,
+    0x000f_e67b_75c6_d578, 0x000f_e6be_661e_11aa, 0x000f_e6ff_55e5_f4f2, 0x000f_e73e_5900_a701,
+    0x000f_e77b_823e_9e39, 0x000f_e7b6_e370_70a1, 0x000f_e7f0_8d77_4242, 0x000f_e828_9053_f08c,
+    0x000f_e85e_fb35_173b, 0x000f_e893_dc84_0864, 0x000f_e8c7_41f0_cebc, 0x000f_e8f9_387d_4ef6,
+    0x000f_e929_cc87_9b1c, 0x000f_e959_09d3_88ea, 0x000f_e986_fb93_9aa1, 0x000f_e9b3_ac71_4865,
+    0x000f_e9df_2694_b6d5, 0x000f_ea09_73ab_e67b, 0x000f_ea32_9cf1_66a4, 0x000f_ea5a_ab32_952c,
+    0x000f_ea81_a6d5_7419, 0x000f_eaa7_97de_1cef, 0x000f_eacc_85f3_d91f, 0x000f_eaf0_7865_e63c,
+    0x000f_eb13_762f_ec12, 0x000f_eb35_85fe_2a4a, 0x000f_eb56_ae31_62b4, 0x000f_eb76_f4e2_84f9,
+    0x000f_eb96_5fe6_2013, 0x000f_ebb4_f4cf_9d7c, 0x000f_ebd2_b8f4_49cf, 0x000f_ebef_b16e_2e3d,
+    0x000f_ec0b_e31e_bde8, 0x000f_ec27_52b1_5a14, 0x000f_ec42_049d_afd3, 0x000f_ec5b_fd29_f196,
+    0x000f_ec75_406c_eef4, 0x000f_ec8d_d250_0cb4, 0x000f_eca5_b691_1f10, 0x000f_ecbc_f0c4_27fe,
+    0x000f_ecd3_8454_fb15, 0x000f_ece9_7488_c8b3, 0x000f_ecfe_c47f_91b7, 0x000f_ed13_7735_8528,
+    0x000f_ed27_8f84_4903, 0x000f_ed3b_1024_2f4c, 0x000f_ed4d_fbad_586d, 0x000f_ed60_5498_c3dc,
+    0x000f_ed72_1d41_4fe8, 0x000f_ed83_57e4_a981, 0x000f_ed94_06a4_2cc8, 0x000f_eda4_2b85_b704,
+    0x000f_edb3_c874_6ab3, 0x000f_edc2_df41_6652, 0x000f_edd1_71a4_6e52, 0x000f_eddf_813c_8ad2,
+    0x000f_eded_0f90_997f, 0x000f_edfa_1e0f_d413, 0x000f_ee06_ae12_4bc4, 0x000f_ee12_c0d9_5a06,
+    0x000f_ee1e_5790_06df, 0x000f_ee29_734b_6524, 0x000f_ee34_150a_e4bb, 0x000f_ee3e_3db8_9b3c,
+    0x000f_ee47_ee29_82f3, 0x000f_ee51_271d_b086, 0x000f_ee59_e940_7f41, 0x000f_ee62_3528_b42d,
+    0x000f_ee6a_0b58_97f0, 0x000f_ee71_6c3e_077a, 0x000f_ee78_5832_7b81, 0x000f_ee7e_cf7b_06b9,
+    0x000f_ee84_d248_4ab2, 0x000f_ee8a_60b6_6342, 0x000f_ee8f_7acc_c851, 0x000f_ee94_207e_25da,
+    0x000f_ee98_51a8_29eb, 0x000f_ee9c_0e13_485b, 0x000f_ee9f_5572_73f3, 0x000f_eea2_2762_ccae,
+    0x000f_eea4_836b_42ab, 0x000f_eea6_68fc_2d71, 0x000f_eea7_d76e_d6f9, 0x000f_eea8_ce04_fa0a,
+    0x000f_eea9_4be8_333b, 0x000f_eea9_5029_640f, 0x000f_eea8_d9c0_075d, 0x000f_eea7_e789_7653,
+    0x000f_eea6_7848_1d24, 0x000f_eea4_8aa2_9e82, 0x000f_eea2_1d22_e4d9, 0x000f_ee9f_2e35_2024,
+    0x000f_ee9b_bc26_af2e, 0x000f_ee97_c524_f2e3, 0x000f_ee93_473c_0a39, 0x000f_ee8e_4055_7515,
+    0x000f_ee88_ae36_9c79, 0x000f_ee82_8e7f_3dfc, 0x000f_ee7b_dea7_b887, 0x000f_ee74_9bff_37ff,
+    0x000f_ee6c_c3a9_bd5e, 0x000f_ee64_529e_007f, 0x000f_ee5b_45a3_2888, 0x000f_ee51_994e_57b5,
+    0x000f_ee47_4a00_06ce, 0x000f_ee3c_53e1_2c4f, 0x000f_ee30_b2e0_2ad7, 0x000f_ee24_62ad_8204,
+    0x000f_ee17_5eb8_3c59, 0x000f_ee09_a22a_1447, 0x000f_edfb_27e3_49cb, 0x000f_edeb_ea76_216c,
+    0x000f_eddb_e422_047d, 0x000f_edcb_0ece_39d3, 0x000f_edb9_6404_2cf3, 0x000f_eda6_dce9_38c9,
+    0x000f_ed93_7237_e98c, 0x000f_ed7f_1c38_a836, 0x000f_ed69_d2b9_c02a, 0x000f_ed53_8d06_adff,
+    0x000f_ed3c_41de_a422, 0x000f_ed23_e76a_2fd7, 0x000f_ed0a_732f_e643, 0x000f_ecef_da07_fe33,
+    0x000f_ecd4_100e_b7b8, 0x000f_ecb7_0895_6eb4, 0x000f_ec98_b612_30c0, 0x000f_ec79_0a0d_a978,
+    0x000f_ec57_f50f_31fd, 0x000f_ec35_6686_c961, 0x000f_ec11_4cb4_b334, 0x000f_ebeb_948e_6fd0,
+    0x000f_ebc4_29a0_b691, 0x000f_eb9a_f5ee_0cdc, 0x000f_eb6f_e1c9_8542, 0x000f_eb42_d3ad_1f9e,
+    0x000f_eb13_b00b_2d4b, 0x000f_eae2_591a_02e8, 0x000f_eaae_ae99_2256, 0x000f_ea78_8d8e_e326,
+    0x000f_ea3f_cffd_73e5, 0x000f_ea04_4c8d_d9f6, 0x000f_e9c5_d62f_563a, 0x000f_e984_3ba9_47a3,
+    0x000f_e93f_471d_4728, 0x000f_e8f6_bd76_c5d6, 0x000f_e8aa_5dc4_e8e6, 0x000f_e859_e07a_b1ea,
+    0x000f_e804_f690_a93f, 0x000f_e7ab_4882_33bf, 0x000f_e74c_751f_6aa5, 0x000f_e6e8_102a_a201,
+    0x000f_e67d_a0b6_abd8, 0x000f_e60c_9f38_307d, 0x000f_e594_7338_f742, 0x000f_e514_7097_7280,
+    0x000f_e48b_d436_f457, 0x000f_e3f9_bffd_1e37, 0x000f_e35d_35ee_b19b, 0x000f_e2b5_122f_e4fd,
+    0x000f_e200_0399_5557, 0x000f_e13c_8278_8314, 0x000f_e068_c4ee_67af, 0x000f_df82_b02b_71aa,
+    0x000f_de87_c57e_feaa, 0x000f_dd75_09c6_3bfd, 0x000f_dc46_e529_bf12, 0x000f_daf8_f82e_0282,
+    0x000f_d985_e1b2_ba75, 0x000f_d7e6_ef48_cf04, 0x000f_d613_adbd_650b, 0x000f_d401_49e2_f011,
+    0x000f_d1a1_a7b4_c7ac, 0x000f_cee2_0476_1f9e, 0x000f_cba8_d85e_11b1, 0x000f_c7d2_6ecd_2d22,
+    0x000f_c32b_2f1e_22ec, 0x000f_bd65_81c0_b839, 0x000f_b606_c400_5433, 0x000f_ac40_582a_2873,
+    0x000f_9e97_1e01_4597, 0x000f_89fa_48a4_1dfb, 0x000f_66c5_f7f0_302c, 0x000f_1a5a_4b33_1c49,
+];
+
+#[rustfmt::skip]
+pub(crate) const WI_DOUBLE: [f64; 256] = [
+    8.683627060801315e-16, 4.779330175727817e-17, 6.354352417405312e-17, 7.454870481247737e-17,
+    8.329366815793134e-17, 9.068060405059514e-17, 9.714860076567794e-17, 1.0294750314241046e-16,
+    1.0823430288447711e-16, 1.1311470196109058e-16, 1.1766359457022946e-16, 1.2193617278714386e-16,
+    1.2597439914637113e-16, 1.2981099886264051e-16, 1.3347203736824142e-16, 1.3697864842571223e-16,
+    1.4034823001242402e-16, 1.4359529452056965e-16, 1.4673208742364442e-16, 1.4976904668391056e-16,
+    1.527151500359622e-16, 1.5557818169460786e-16, 1.5836494009290908e-16, 1.6108140175274953e-16,
+    1.6373285203969875e-16, 1.6632399058420855e-16, 1.6885901708676616e-16, 1.7134170176559678e-16,
+    1.737754436586488e-16, 1.7616331923001016e-16, 1.7850812316976747e-16, 1.808124028579917e-16,
+    1.830784876482677e-16, 1.8530851388618039e-16, 1.87504446393739e-16, 1.8966809700774777e-16,
+    1.918011406483864e-16, 1.9390512930625123e-16, 1.959815042662884e-16, 1.9803160683128189e-16,
+    2.0005668776273345e-16, 2.0205791562071666e-16, 2.0403638415480224e-16, 2.059931188740372e-16,
+    2.0792908290414032e-16, 2.0984518222370366e-16, 2.1174227035760354e-16, 2.136211525944988e-16,
+    2.154825897858147e-16, 2.173273017756438e-16, 2.1915597050427286e-16, 2.2096924282235332e-16,
+    2.227677330478957e-16, 2.2455202529414375e-16, 2.26322675592857e-16, 2.280802138345019e-16,
+    2.2982514554424704e-16, 2.3155795351040823e-16, 2.3327909928004376e-16, 2.3498902453470975e-16,
+    2.366881523579162e-16, 2.3837688840454263e-16, 2.4005562198135078e-16, 2.417247270467504e-16,
+    2.4338456313711043e-16, 2.450354762261497e-16, 2.466777995232707e-16, 2.483118542161089e-16,
+    2.4993795016204544e-16, 2.5155638653296593e-16, 2.5316745241713597e-16, 2.547714273816946e-16,
+    2.5636858199893983e-16, 2.579591783392868e-16, 2.5954347043351717e-16, 2.611217047067021e-16,
+    2.626941203859727e-16, 2.642609498841191e-16, 2.658224191608309e-16, 2.673787480632365e-16,
+    2.6893015064726174e-16, 2.7047683548119967e-16, 2.7201900593277335e-16, 2.7355686044086806e-16,
+    2.750905927730168e-16, 2.766203922696392e-16, 2.781464440759545e-16, 2.796689293624231e-16,
+    2.8118802553450217e-16, 2.82703906432448e-16, 2.842167425218407e-16, 2.857267010754602e-16,
+    2.8723394634709804e-16, 2.8873863973784824e-16, 2.902409399553843e-16, 2.917410031666946e-16,
+    2.9323898314471826e-16, 2.947350314092936e-16, 2.962292973628067e-16, 2.97721928420903e-16,
+    2.992130701386014e-16, 3.007028663321332e-16, 3.0219145919680625e-16, 3.036789894211803e-16,
+    3.0516559629782197e-16, 3.0665141783089555e-16, 3.081365908408298e-16, 3.0962125106629235e-16,
+    3.111055332636894e-16, 3.125895713044e-16, 3.1407349826994477e-16, 3.155574465452802e-16,
+    3.17041547910403e-16, 3.1852593363044075e-16, 3.2001073454440124e-16, 3.214960811527448e-16,
+    3.229821037039416e-16, 3.2446893228016983e-16, 3.2595669688230794e-16, 3.2744552751437077e-16,
+    3.2893555426753707e-16, 3.3042690740391294e-16, 3.319197174401753e-16, 3.334141152312373e-16,
+    3.349102320540779e-16, 3.3640819969187656e-16, 3.3790815051859503e-16, 3.39410217584149e-16,
+    3.409145347003127e-16, 3.424212365275019e-16, 3.439304586625832e-16, 3.4544233772785845e-16,
+    3.4695701146137845e-16, 3.4847461880874147e-16, 3.499953000165382e-16, 3.5151919672760754e-16,
+    3.530464520782741e-16, 3.5457721079774367e-16, 3.5611161930983894e-16, 3.5764982583726515e-16,
+    3.5919198050860314e-16, 3.6073823546823534e-16, 3.6228874498941935e-16, 3.6384366559073464e-16,
+    3.6540315615613714e-16, 3.6696737805887024e-16, 3.6853649528949155e-16, 3.7011067458829e-16,
+    3.716900855823824e-16, 3.7327490092779445e-16, 3.7486529645684897e-16, 3.764614513312029e-16,
+    3.780635482008961e-16, 3.7967177336979448e-16, 3.812863169678378e-16, 3.8290737313052437e-16,
+    3.8453514018609596e-16, 3.8616982085091493e-16, 3.878116224335587e-16, 3.894607570481926e-16,
+    3.9111744183782054e-16, 3.927818992080542e-16, 3.9445435707208776e-16, 3.9613504910761354e-16,
+    3.978242150264683e-16, 3.995221008578565e-16, 4.0122895924606296e-16, 4.0294504976363284e-16,
+    4.0467063924107504e-16, 4.0640600211422504e-16, 4.0815142079049387e-16, 4.0990718603532664e-16,
+    4.116735973803025e-16, 4.134509635544235e-16, 4.152396029402687e-16, 4.1703984405683144e-16,
+    4.188520260710111e-16, 4.206764993399014e-16, 4.2251362598620484e-16, 4.2436378050930775e-16,
+    4.262273504347798e-16, 4.2810473700531167e-16, 4.2999635591638323e-16, 4.3190263810026294e-16,
+    4.338240305622791e-16, 4.357609972736849e-16, 4.3771402012585875e-16, 4.3968359995105214e-16,
+    4.416702576154204e-16, 4.4367453519065673e-16, 4.456969972112043e-16, 4.477382320247534e-16,
+    4.49798853244555e-16, 4.518795013130059e-16, 4.539808451870034e-16, 4.561035841567423e-16,
+    4.582484498109568e-16, 4.604162081631154e-16, 4.626076619547847e-16, 4.648236531543208e-16,
+    4.670650656712633e-16, 4.69332828309333e-16, 4.716279179838353e-16, 4.739513632325869e-16,
+    4.763042480533139e-16, 4.786877161048725e-16, 4.811029753147419e-16, 4.835513029411527e-16,
+    4.860340511450813e-16, 4.885526531353604e-16, 4.911086299595271e-16, 4.937035980240336e-16,
+    4.963392774403988e-16, 4.990175013091822e-16, 5.01740226071809e-16, 5.045095430818728e-16,
+    5.073276915733543e-16, 5.101970732341562e-16, 5.131202686306784e-16, 5.161000557743228e-16,
+    5.1913943117577e-16, 5.222416338000235e-16, 5.254101724177597e-16, 5.286488569504945e-16,
+    5.3196183453384e-16, 5.353536311816497e-16, 5.388292001334053e-16, 5.423939782201712e-16,
+    5.460539519074781e-16, 5.498157350892814e-16, 5.536866612467876e-16, 5.576748932926577e-16,
+    5.617895553555417e-16, 5.660408920082423e-16, 5.70440462129139e-16, 5.750013768919896e-16,
+    5.797385945724595e-16, 5.84669289345548e-16, 5.8981331764779e-16, 5.951938149641445e-16,
+    6.008379696271909e-16, 6.067780409333449e-16, 6.130527208725281e-16, 6.197089894581626e-16,
+    6.268046963301283e-16, 6.344122407127505e-16, 6.426239659548054e-16, 6.515603317344993e-16,
+    6.613827885097663e-16, 6.723150462505586e-16, 6.846803417564259e-16, 6.98971833638762e-16,
+    7.159994934830664e-16, 7.372424301798798e-16, 7.658936370805572e-16, 8.113849337656484e-16,
+];
+
+#[rustfmt::skip]
+pub(crate) const FI_DOUBLE: [f64; 256] = [
+    1.0, 0.9771017012676708, 0.959879091800106, 0.9451989534422991,
+    0.9320600759592299, 0.9199915050393465, 0.9087264400521303, 0.898095921898343,
+    0.8879846607558328, 0.8783096558089168, 0.8690086880368565, 0.8600336211963311,
+    0.8513462584586775, 0.8429156531122037, 0.834716292986883, 0.8267268339462209,
+    0.8189291916037019, 0.8113078743126557, 0.8038494831709638, 0.7965423304229584,
+    0.789376143566024, 0.782341832654802, 0.7754313049811866, 0.7686373157984857,
+    0.7619533468367948, 0.7553735065070957, 0.7488924472191564, 0.7425052963401506,
+    0.7362075981268621, 0.7299952645614757, 0.7238645334686297, 0.7178119326307215,
+    0.711834248878248, 0.7059285013327538, 0.7000919181365112, 0.6943219161261163,
+    0.6886160830046714, 0.6829721616449943, 0.6773880362187731, 0.6718617198970817,
+    0.6663913439087498, 0.6609751477766628, 0.6556114705796969, 0.6502987431108164,
+    0.645035480820822, 0.6398202774530561, 0.6346517992876233, 0.6295287799248362,
+    0.6244500155470261, 0.619414360605834, 0.6144207238889134, 0.6094680649257731,
+    0.6045553906974673, 0.5996817526191248, 0.5948462437679869, 0.5900479963328255,
+    0.5852861792633709, 0.5805599961007903, 0.5758686829723532, 0.5712115067352527,
+    0.5665877632561639, 0.5619967758145239, 0.5574378936187655, 0.5529104904258318,
+    0.5484139632552654, 0.5439477311900258, 0.5395112342569516, 0.5351039323804572,
+    0.5307253044036615, 0.526374847171684, 0.5220520746723214, 0.5177565172297559,
+    0.5134877207473265, 0.5092452459957476, 0.5050286679434679, 0.5008375751261483,
+    0.4966715690524893, 0.49253026364386815, 0.4884132847054576, 0.4843202694266829,
+    0.4802508659090464, 0.4762047327195055, 0.47218153846772976, 0.4681809614056932,
+    0.4642026890481739, 0.4602464178128425, 0.4563118526787161, 0.45239870686184824,
+    0.44850670150720273, 0.4446355653957391, 0.44078503466580377, 0.43695485254798533,
+    0.4331447691126521, 0.42935454102944126, 0.4255839313380218, 0.42183270922949573,
+    0.41810064983784795, 0.4143875340408909, 0.410693148270188, 0.40701728432947315,
+    0.4033597392211143, 0.399720314980197, 0.39609881851583223, 0.3924950614593154,
+    0.38890886001878855, 0.38534003484007706, 0.38178841087339344, 0.37825381724561896,
+    0.37473608713789086, 0.3712350576682392, 0.36775056977903225, 0.3642824681290037,
+    0.36083060098964775, 0.3573948201457802, 0.35397498080007656, 0.3505709414814059,
+    0.3471825639567935, 0.34380971314685055, 0.34045225704452164, 0.3371100666370059,
+    0.33378301583071823, 0.3304709813791634, 0.3271738428136013, 0.32389148237639104,
+    0.3206237849569053, 0.3173706380299135, 0.31413193159633707, 0.31090755812628634,
+    0.3076974125042919, 0.3045013919766498, 0.3013193961008029, 0.2981513266966853,
+    0.29499708779996164, 0.291856585617095, 0.2887297284821827, 0.2856164268155016,
+    0.2825165930837074, 0.2794301417616377, 0.2763569892956681, 0.2732970540685769,
+    0.2702502563658752, 0.26721651834356114, 0.2641957639972608, 0.2611879191327208,
+    0.2581929113376189, 0.2552106699546617, 0.2522411260559419, 0.24928421241852824,
+    0.24633986350126363, 0.24340801542275012, 0.2404886059405004, 0.23758157443123795,
+    0.2346868618723299, 0.23180441082433859, 0.22893416541468023, 0.2260760713223802,
+    0.22323007576391746, 0.22039612748015194, 0.21757417672433113, 0.21476417525117358,
+    0.21196607630703015, 0.209179834621125, 0.20640540639788071, 0.20364274931033485,
+    0.20089182249465656, 0.1981525865457751, 0.19542500351413428, 0.19270903690358912,
+    0.19000465167046496, 0.18731181422380025, 0.18463049242679927, 0.18196065559952254,
+    0.17930227452284767, 0.176655321443735, 0.17401977008183875, 0.17139559563750595,
+    0.1687827748012115, 0.16618128576448205, 0.1635911082323657, 0.16101222343751107,
+    0.1584446141559243, 0.1558882647244792, 0.15334316106026283, 0.15080929068184568,
+    0.14828664273257453, 0.14577520800599403, 0.1432749789735134, 0.1407859498144447,
+    0.1383081164485507, 0.13584147657125373, 0.13338602969166913, 0.1309417771736443,
+    0.12850872227999952, 0.12608687022018586, 0.12367622820159654, 0.12127680548479021,
+    0.11888861344290998, 0.1165116656256108, 0.11414597782783835, 0.111791568163838,
+    0.10944845714681163, 0.10711666777468364, 0.1047962256224869, 0.10248715894193508,
+    0.10018949876880981, 0.09790327903886228, 0.09562853671300882, 0.09336531191269086,
+    0.09111364806637363, 0.08887359206827579, 0.08664519445055796, 0.08442850957035337,
+    0.08222359581320286, 0.08003051581466306, 0.07784933670209604, 0.07568013035892707,
+    0.07352297371398127, 0.07137794905889037, 0.06924514439700677, 0.0671246538277885,
+    0.06501657797124284, 0.06292102443775811, 0.060838108349539864, 0.05876795292093376,
+    0.0567106901062029, 0.054666461324888914, 0.052635418276792176, 0.05061772386094776,
+    0.04861355321586852, 0.04662309490193037, 0.04464655225129444, 0.04268414491647443,
+    0.04073611065594093, 0.03880270740452611, 0.036884215688567284, 0.034980941461716084,
+    0.03309321945857852, 0.031221417191920245, 0.029365939758133314, 0.027527235669603082,
+    0.025705804008548896, 0.023902203305795882, 0.022117062707308864, 0.020351096230044517,
+    0.018605121275724643, 0.016880083152543166, 0.015177088307935325, 0.01349745060173988,
+    0.011842757857907888, 0.010214971439701471, 0.008616582769398732, 0.007050875471373227,
+    0.005522403299250997, 0.0040379725933630305, 0.0026090727461021627, 0.0012602859304985975,
+];
+
+#[rustfmt::skip]
+pub(crate) const KI_FLOAT: [u32; 256] = [
+    0x0077_99ec, 0x0000_0000, 0x0060_45f4, 0x006d_1aa7, 0x0072_8fb3, 0x0075_92af,
+    0x0077_7a5c, 0x0078_ca38, 0x0079_bf6b, 0x007a_7a34, 0x007b_0d2f, 0x007b_83d3,
+    0x007b_e597, 0x007c_3788, 0x007c_7d32, 0x007c_b926, 0x007c_ed48, 0x007d_1b07,
+    0x007d_437e, 0x007d_678b, 0x007d_87db, 0x007d_a4fc, 0x007d_bf61, 0x007d_d767,
+    0x007d_ed5c, 0x007e_0183, 0x007e_1410, 0x007e_2533, 0x007e_3514, 0x007e_43d5,
+    0x007e_5192, 0x007e_5e67, 0x007e_6a68, 0x007e_75aa, 0x007e_803d, 0x007e_8a32,
+    0x007e_9395, 0x007e_9c72, 0x007e_a4d4, 0x007e_acc5, 0x007e_b44e, 0x007e_bb75,
+    0x007e_c242, 0x007e_c8bc, 0x007e_cee8, 0x007e_d4cb, 0x007e_da6a, 0x007e_dfca,
+    0x007e_e4ef, 0x007e_e9dc, 0x007e_ee94, 0x007e_f31b, 0x007e_f773, 0x007e_fba0,
+    0x007e_ffa3, 0x007f_037f, 0x007f_0736, 0x007f_0ac9, 0x007f_0e3c, 0x007f_118f,
+    0x007f_14c4, 0x007f_17dc, 0x007f_1ad9, 0x007f_1dbc, 0x007f_2087, 0x007f_233a,
+    0x007f_25d6, 0x007f_285d, 0x007f_2acf, 0x007f_2d2e, 0x007f_2f79, 0x007f_31b3,
+    0x007f_33db, 0x007f_35f3, 0x007f_37fa, 0x007f_39f2, 0x007f_3bdc, 0x007f_3db7,
+    0x007f_3f84, 0x007f_4144, 0x007f_42f7, 0x007f_449e, 0x007f_463a, 0x007f_47c9,
+    0x007f_494e, 0x007f_4ac8, 0x007f_4c37, 0x007f_4d9d, 0x007f_4ef9, 0x007f_504b,
+    0x007f_5194, 0x007f_52d5, 0x007f_540d, 0x007f_553c, 0x007f_5664, 0x007f_5783,
+    0x007f_589b, 0x007f_59ac, 0x007f_5ab5, 0x007f_5bb7, 0x007f_5cb2, 0x007f_5da7,
+    0x007f_5e95, 0x007f_5f7d, 0x007f_605f, 0x007f_613a, 0x007f_6210, 0x007f_62df,
+    0x007f_63aa, 0x007f_646e, 0x007f_652d, 0x007f_65e7, 0x007f_669c, 0x007f_674b,
+    0x007f_67f6, 0x007f_689b, 0x007f_693c, 0x007f_69d8, 0x007f_6a6f, 0x007f_6b02,
+    0x007f_6b90, 0x007f_6c1a, 0x007f_6ca0, 0x007f_6d21, 0x007f_6d9e, 0x007f_6e16,
+    0x007f_6e8b, 0x007f_6efc, 0x007f_6f68, 0x007f_6fd0, 0x007f_7035, 0x007f_7096,
+    0x007f_70f2, 0x007f_714b, 0x007f_71a0, 0x007f_71f1, 0x007f_723f, 0x007f_7289,
+    0x007f_72cf, 0x007f_7311, 0x007f_7350, 0x007f_738b, 0x007f_73c2, 0x007f_73f6,
+    0x007f_7426, 0x007f_7453, 0x007f_747b, 0x007f_74a1, 0x007f_74c2, 0x007f_74e0,
+    0x007f_74fa, 0x007f_7511, 0x007f_7524, 0x007f_7533, 0x007f_753e, 0x007f_7546,
+    0x007f_754a, 0x007f_754a, 0x007f_7546, 0x007f_753f, 0x007f_7533, 0x007f_7524,
+    0x007f_7510, 0x007f_74f9, 0x007f_74dd, 0x007f_74be, 0x007f_749a, 0x007f_7472,
+    0x007f_7445, 0x007f_7414, 0x007f_73de, 0x007f_73a4, 0x007f_7366, 0x007f_7322,
+    0x007f_72da, 0x007f_728c, 0x007f_723a, 0x007f_71e2, 0x007f_7185, 0x007f_7123,
+    0x007f_70ba, 0x007f_704d, 0x007f_6fd9, 0x007f_6f5f, 0x007f_6edf, 0x007f_6e58,
+    0x007f_6dcb, 0x007f_6d36, 0x007f_6c9b, 0x007f_6bf8, 0x007f_6b4e, 0x007f_6a9c,
+    0x007f_69e2, 0x007f_691f, 0x007f_6853, 0x007f_677e, 0x007f_66a0, 0x007f_65b8,
+    0x007f_64c5, 0x007f_63c8, 0x007f_62bf, 0x007f_61ab, 0x007f_608a, 0x007f_5f5c,
+    0x007f_5e21, 0x007f_5cd7, 0x007f_5b7f, 0x007f_5a16, 0x007f_589d, 0x007f_5712,
+    0x007f_5575, 0x007f_53c4, 0x007f_51fe, 0x007f_5022, 0x007f_4e2e, 0x007f_4c21,
+    0x007f_49fa, 0x007f_47b5, 0x007f_4552, 0x007f_42cf, 0x007f_4027, 0x007f_3d5a,
+    0x007f_3a63, 0x007f_3740, 0x007f_33ed, 0x007f_3064, 0x007f_2ca3, 0x007f_28a3,
+    0x007f_245e, 0x007f_1fcd, 0x007f_1ae9, 0x007f_15a8, 0x007f_1000, 0x007f_09e4,
+    0x007f_0346, 0x007e_fc15, 0x007e_f43e, 0x007e_eba8, 0x007e_e237, 0x007e_d7c7,
+    0x007e_cc2f, 0x007e_bf37, 0x007e_b09d, 0x007e_a00a, 0x007e_8d0d, 0x007e_7710,
+    0x007e_5d46, 0x007e_3e93, 0x007e_1959, 0x007d_eb2c, 0x007d_b036, 0x007d_6202,
+    0x007c_f4b8, 0x007c_4fd2, 0x007b_362f, 0x0078_d2d2,
+];
+
+#[rustfmt::skip]
+pub(crate) const WI_FLOAT: [f32; 256] = [
+    4.6619868e-07, 2.5658833e-08, 3.411467e-08, 4.0023032e-08, 4.4717947e-08, 4.8683777e-08,
+    5.2156256e-08, 5.526952e-08, 5.8107847e-08, 6.072799e-08, 6.317016e-08, 6.5463986e-08,
+    6.763199e-08, 6.969175e-08, 7.1657254e-08, 7.353985e-08, 7.534888e-08, 7.709214e-08,
+    7.877619e-08, 8.040664e-08, 8.198832e-08, 8.35254e-08, 8.502153e-08, 8.647992e-08,
+    8.790341e-08, 8.929451e-08, 9.06555e-08, 9.1988376e-08, 9.329498e-08, 9.457696e-08,
+    9.5835816e-08, 9.707292e-08, 9.828951e-08, 9.948675e-08, 1.0066568e-07, 1.0182728e-07,
+    1.0297245e-07, 1.0410202e-07, 1.0521677e-07, 1.0631741e-07, 1.0740462e-07, 1.08479014e-07,
+    1.095412e-07, 1.10591714e-07, 1.11631074e-07, 1.1265978e-07, 1.1367827e-07, 1.1468698e-07,
+    1.1568633e-07, 1.1667671e-07, 1.17658466e-07, 1.1863196e-07, 1.1959752e-07, 1.2055546e-07,
+    1.2150606e-07, 1.2244963e-07, 1.2338644e-07, 1.2431673e-07, 1.2524076e-07, 1.2615877e-07,
+    1.2707099e-07, 1.2797761e-07, 1.2887888e-07, 1.2977497e-07, 1.306661e-07, 1.3155243e-07,
+    1.3243414e-07, 1.3331142e-07, 1.3418442e-07, 1.3505331e-07, 1.3591824e-07, 1.3677936e-07,
+    1.3763683e-07, 1.3849078e-07, 1.3934134e-07, 1.4018865e-07, 1.4103283e-07, 1.4187401e-07,
+    1.4271232e-07, 1.4354788e-07, 1.4438078e-07, 1.4521115e-07, 1.4603908e-07, 1.4686472e-07,
+    1.4768814e-07, 1.4850944e-07, 1.4932874e-07, 1.501461e-07, 1.5096167e-07, 1.5177551e-07,
+    1.525877e-07, 1.5339836e-07, 1.5420756e-07, 1.5501537e-07, 1.5582192e-07, 1.5662725e-07,
+    1.5743149e-07, 1.5823467e-07, 1.5903689e-07, 1.5983824e-07, 1.6063879e-07, 1.6143862e-07,
+    1.6223781e-07, 1.6303642e-07, 1.6383453e-07, 1.6463223e-07, 1.6542957e-07, 1.6622664e-07,
+    1.6702352e-07, 1.6782025e-07, 1.6861692e-07, 1.6941361e-07, 1.7021038e-07, 1.7100731e-07,
+    1.7180446e-07, 1.726019e-07, 1.733997e-07, 1.7419794e-07, 1.7499667e-07, 1.7579598e-07,
+    1.7659593e-07, 1.773966e-07, 1.7819804e-07, 1.7900034e-07, 1.7980356e-07, 1.8060777e-07,
+    1.8141306e-07, 1.8221947e-07, 1.830271e-07, 1.8383601e-07, 1.8464625e-07, 1.8545795e-07,
+    1.8627112e-07, 1.8708589e-07, 1.879023e-07, 1.8872043e-07, 1.8954037e-07, 1.9036219e-07,
+    1.9118598e-07, 1.9201178e-07, 1.9283972e-07, 1.9366986e-07, 1.9450229e-07, 1.9533708e-07,
+    1.9617433e-07, 1.9701412e-07, 1.9785652e-07, 1.9870166e-07, 1.9954959e-07, 2.0040044e-07,
+    2.0125427e-07, 2.0211121e-07, 2.0297132e-07, 2.0383473e-07, 2.0470154e-07, 2.0557184e-07,
+    2.0644573e-07, 2.0732334e-07, 2.0820478e-07, 2.0909015e-07, 2.0997958e-07, 2.1087318e-07,
+    2.1177107e-07, 2.1267338e-07, 2.1358025e-07, 2.1449179e-07, 2.1540816e-07, 2.1632948e-07,
+    2.172559e-07, 2.1818757e-07, 2.1912463e-07, 2.2006725e-07, 2.2101558e-07, 2.219698e-07,
+    2.2293007e-07, 2.2389656e-07, 2.2486947e-07, 2.2584898e-07, 2.2683527e-07, 2.2782856e-07,
+    2.2882907e-07, 2.2983698e-07, 2.3085254e-07, 2.3187596e-07, 2.329075e-07, 2.339474e-07,
+    2.3499592e-07, 2.3605334e-07, 2.3711992e-07, 2.3819595e-07, 2.3928175e-07, 2.4037763e-07,
+    2.4148392e-07, 2.4260095e-07, 2.4372912e-07, 2.4486874e-07, 2.4602025e-07, 2.4718406e-07,
+    2.483606e-07, 2.495503e-07, 2.5075366e-07, 2.5197113e-07, 2.532033e-07, 2.544507e-07,
+    2.557139e-07, 2.569935e-07, 2.5829019e-07, 2.5960463e-07, 2.6093755e-07, 2.6228972e-07,
+    2.6366195e-07, 2.650551e-07, 2.6647012e-07, 2.6790798e-07, 2.6936974e-07, 2.708565e-07,
+    2.7236948e-07, 2.7390996e-07, 2.7547935e-07, 2.770791e-07, 2.7871087e-07, 2.8037636e-07,
+    2.8207745e-07, 2.838162e-07, 2.8559484e-07, 2.874158e-07, 2.8928173e-07, 2.9119556e-07,
+    2.931605e-07, 2.951801e-07, 2.9725825e-07, 2.9939943e-07, 3.0160848e-07, 3.0389089e-07,
+    3.062529e-07, 3.0870152e-07, 3.1124478e-07, 3.1389195e-07, 3.1665363e-07, 3.1954224e-07,
+    3.2257242e-07, 3.2576148e-07, 3.2913016e-07, 3.3270373e-07, 3.365132e-07, 3.4059747e-07,
+    3.4500613e-07, 3.498038e-07, 3.550772e-07, 3.609464e-07, 3.6758496e-07, 3.7525766e-07,
+    3.843993e-07, 3.9580402e-07, 4.1118602e-07, 4.3560897e-07,
+];
+
+#[rustfmt::skip]
+pub(crate) const FI_FLOAT: [f32; 256] = [
+    1.0, 0.9771017, 0.9598791, 0.94519895, 0.93206006, 0.9199915,
+    0.90872645, 0.8980959, 0.88798463, 0.87830967, 0.86900866, 0.86003363,
+    0.85134625, 0.84291565, 0.8347163, 0.82672685, 0.8189292, 0.81130785,
+    0.80384946, 0.79654235, 0.78937614, 0.78234184, 0.7754313, 0.7686373,
+    0.76195335, 0.7553735, 0.7488924, 0.7425053, 0.7362076, 0.72999525,
+    0.72386456, 0.71781194, 0.71183425, 0.7059285, 0.7000919, 0.69432193,
+    0.6886161, 0.68297213, 0.677388, 0.6718617, 0.6663914, 0.66097516,
+    0.65561146, 0.6502987, 0.6450355, 0.6398203, 0.6346518, 0.62952876,
+    0.62445, 0.6194144, 0.6144207, 0.60946804, 0.60455537, 0.59968174,
+    0.59484625, 0.590048, 0.5852862, 0.58055997, 0.57586867, 0.5712115,
+    0.56658775, 0.56199676, 0.5574379, 0.5529105, 0.548414, 0.54394776,
+    0.53951126, 0.5351039, 0.5307253, 0.5263749, 0.52205205, 0.5177565,
+    0.5134877, 0.5092452, 0.50502867, 0.50083756, 0.49667156, 0.49253026,
+    0.48841327, 0.48432028, 0.48025087, 0.47620472, 0.47218153, 0.46818095,
+    0.4642027, 0.4602464, 0.45631185, 0.45239872, 0.4485067, 0.44463557,
+    0.44078502, 0.43695486, 0.43314478, 0.42935455, 0.42558393, 0.4218327,
+    0.41810066, 0.41438752, 0.41069314, 0.4070173, 0.40335974, 0.3997203,
+    0.39609882, 0.39249507, 0.38890886, 0.38534003, 0.3817884, 0.37825382,
+    0.3747361, 0.37123504, 0.36775056, 0.36428246, 0.3608306, 0.3573948,
+    0.35397497, 0.35057095, 0.34718257, 0.34380972, 0.34045225, 0.33711007,
+    0.33378303, 0.33047098, 0.32717383, 0.3238915, 0.3206238, 0.31737062,
+    0.31413195, 0.31090757, 0.30769742, 0.30450138, 0.3013194, 0.2981513,
+    0.2949971, 0.2918566, 0.28872973, 0.28561643, 0.2825166, 0.27943015,
+    0.276357, 0.27329704, 0.27025026, 0.2672165, 0.26419577, 0.2611879,
+    0.2581929, 0.25521067, 0.25224113, 0.24928421, 0.24633986, 0.24340801,
+    0.2404886, 0.23758158, 0.23468687, 0.23180442, 0.22893417, 0.22607607,
+    0.22323008, 0.22039613, 0.21757418, 0.21476418, 0.21196608, 0.20917983,
+    0.2064054, 0.20364276, 0.20089182, 0.19815259, 0.195425, 0.19270904,
+    0.19000465, 0.18731181, 0.1846305, 0.18196066, 0.17930228, 0.17665532,
+    0.17401977, 0.1713956, 0.16878277, 0.16618128, 0.1635911, 0.16101222,
+    0.15844461, 0.15588826, 0.15334316, 0.15080929, 0.14828664, 0.14577521,
+    0.14327498, 0.14078595, 0.13830812, 0.13584147, 0.13338603, 0.13094178,
+    0.12850872, 0.12608688, 0.123676226, 0.1212768, 0.11888862, 0.116511665,
+    0.11414598, 0.111791566, 0.109448455, 0.10711667, 0.10479622, 0.10248716,
+    0.1001895, 0.09790328, 0.09562854, 0.09336531, 0.09111365, 0.088873595,
+    0.08664519, 0.08442851, 0.082223594, 0.080030516, 0.077849336, 0.07568013,
+    0.07352297, 0.07137795, 0.069245145, 0.06712466, 0.065016575, 0.062921025,
+    0.060838107, 0.058767952, 0.05671069, 0.05466646, 0.05263542, 0.050617725,
+    0.048613552, 0.046623096, 0.044646554, 0.042684145, 0.04073611, 0.038802706,
+    0.036884215, 0.03498094, 0.033093218, 0.031221418, 0.02936594, 0.027527235,
+    0.025705803, 0.023902204, 0.022117063, 0.020351097, 0.01860512, 0.016880084,
+    0.015177088, 0.01349745, 0.011842757, 0.010214971, 0.0086165825, 0.0070508756,
+    0.0055224034, 0.0040379725, 0.0026090727, 0.001260286,
+];
+
+#[rustfmt::skip]
+pub(crate) const KE_DOUBLE: [u64; 256] = [
+    0x001c_5214_2724_97c7, 0x0000_0000_0000_0000, 0x0013_7d5b_d79c_317f, 0x0018_6ef5_8e3f_3c10,
+    0x001a_9bb7_320e_b0ae, 0x001b_d127_f719_447c, 0x001c_951d_0f88_651b, 0x001d_1bfe_2d5c_3973,
+    0x001d_7e5b_d56b_18b3, 0x001d_c934_dd17_2c71, 0x001e_0409_dfac_9dc9, 0x001e_337b_71d4_7837,
+    0x001e_5a8b_177c_b7a3, 0x001e_7b42_096f_046c, 0x001e_970d_af08_ae3e, 0x001e_aef5_b14e_f09e,
+    0x001e_c3bd_07b4_6557, 0x001e_d5f6_f087_99ce, 0x001e_e614_ae6e_5688, 0x001e_f46e_ca36_1cd0,
+    0x001f_014b_76dd_d4a4, 0x001f_0ce3_13a7_96b7, 0x001f_1763_69f1_f77a, 0x001f_20f2_0c45_2571,
+    0x001f_29ae_1951_a874, 0x001f_31b1_8fb9_5532, 0x001f_3912_5157_c106, 0x001f_3fe2_eb6e_694c,
+    0x001f_4633_32d7_88fb, 0x001f_4c10_bf1d_3a0f, 0x001f_5187_4c5c_3322, 0x001f_56a1_09c3_ecc0,
+    0x001f_5b66_d909_9996, 0x001f_5fe0_8210_d08c, 0x001f_6414_dd44_5772, 0x001f_6809_f685_9679,
+    0x001f_6bc5_2a2b_02e7, 0x001f_6f4b_3d32_e4f4, 0x001f_72a0_7190_f13a, 0x001f_75c8_974d_09d7,
+    0x001f_78c7_1b04_5cc0, 0x001f_7b9f_1241_3ff5, 0x001f_7e53_4607_9f8a, 0x001f_80e6_3be2_1139,
+    0x001f_835a_3dad_9162, 0x001f_85b1_6056_b913, 0x001f_87ed_89b2_4262, 0x001f_8a10_7593_74fa,
+    0x001f_8c1b_ba3d_39ad, 0x001f_8e10_cc45_d04a, 0x001f_8ff1_0201_3e17, 0x001f_91bd_9683_58e1,
+    0x001f_9377_ac47_afd8, 0x001f_9520_4f8b_64db, 0x001f_96b8_7863_3892, 0x001f_9841_0c96_8892,
+    0x001f_99ba_e146_ba81, 0x001f_9b26_bc69_7f00, 0x001f_9c85_561b_717a, 0x001f_9dd7_59cf_d803,
+    0x001f_9f1d_6761_a1ce, 0x001f_a058_1409_36c0, 0x001f_a187_eb3a_3339, 0x001f_a2ad_6f6b_c4fc,
+    0x001f_a3c9_1ace_0683, 0x001f_a4db_5fee_6aa3, 0x001f_a5e4_aa4d_097d, 0x001f_a6e5_5ee4_6783,
+    0x001f_a7dd_dca5_1ec4, 0x001f_a8ce_7ce6_a875, 0x001f_a9b7_93ce_5fef, 0x001f_aa99_70ad_b858,
+    0x001f_ab74_5e58_8232, 0x001f_ac48_a374_0585, 0x001f_ad16_82bf_9fe9, 0x001f_adde_3b57_82c1,
+    0x001f_aea0_08f2_1d6d, 0x001f_af5c_2418_b07e, 0x001f_b012_c25b_7a13, 0x001f_b0c4_1681_dff4,
+    0x001f_b170_50b6_f1fb, 0x001f_b217_9eb2_963a, 0x001f_b2ba_2bdf_a84b, 0x001f_b358_217f_4e18,
+    0x001f_b3f1_a6c9_be0c, 0x001f_b486_e10c_acd7, 0x001f_b517_f3c7_93fd, 0x001f_b5a5_00c5_fdaa,
+    0x001f_b62e_2837_fe59, 0x001f_b6b3_88c9_010a, 0x001f_b735_3fb5_0799, 0x001f_b7b3_68dc_7da8,
+    0x001f_b82e_1ed6_ba09, 0x001f_b8a5_7b03_47f6, 0x001f_b919_959a_0f74, 0x001f_b98a_85ba_7204,
+    0x001f_b9f8_6179_6f27, 0x001f_ba63_3dee_e286, 0x001f_bacb_2f41_ec17, 0x001f_bb30_48b4_9145,
+    0x001f_bb92_9cae_a4e2, 0x001f_bbf2_3cc8_029e, 0x001f_bc4f_39d2_2995, 0x001f_bca9_a3e1_40d5,
+    0x001f_bd01_8a54_8f9f, 0x001f_bd56_fbde_729c, 0x001f_bdaa_068b_d66b, 0x001f_bdfa_b7cb_3f41,
+    0x001f_be49_1c73_64de, 0x001f_be95_40c9_695f, 0x001f_bedf_3086_b128, 0x001f_bf26_f6de_6175,
+    0x001f_bf6c_9e82_8ae3, 0x001f_bfb0_31a9_04c4, 0x001f_bff1_ba0f_fdb0, 0x001f_c031_4102_4589,
+    0x001f_c06e_cf5b_54b3, 0x001f_c0aa_6d8b_1427, 0x001f_c0e4_2399_698a, 0x001f_c11b_f929_8a64,
+    0x001f_c151_f57d_1943, 0x001f_c186_1f77_0f4b, 0x001f_c1b8_7d9e_74b4, 0x001f_c1e9_1620_ea43,
+    0x001f_c217_eed5_05de, 0x001f_c245_0d3c_83ff, 0x001f_c270_7686_4fc2, 0x001f_c29a_2f90_630f,
+    0x001f_c2c2_3ce9_8046, 0x001f_c2e8_a2d2_c6b4, 0x001f_c30d_6541_22ed, 0x001f_c330_87de_9c0f,
+    0x001f_c352_0e0b_7ec7, 0x001f_c371_fadf_66f8, 0x001f_c390_512a_2887, 0x001f_c3ad_1374_97fa,
+    0x001f_c3c8_4401_3349, 0x001f_c3e1_e4cc_ab40, 0x001f_c3f9_f78e_4da8, 0x001f_c410_7db8_5061,
+    0x001f_c425_7877_fd68, 0x001f_c438_e8b5_bfc7, 0x001f_c44a_cf15_112a, 0x001f_c45b_2bf4_47e8,
+    0x001f_c469_ff6c_4504, 0x001f_c477_4950_01b2, 0x001f_c483_092b_fbb9, 0x001f_c48d_3e45_7ff6,
+    0x001f_c495_e799_d21b, 0x001f_c49d_03dd_30b1, 0x001f_c4a2_9179_b433, 0x001f_c4a6_8e8e_07fc,
+    0x001f_c4a8_f8eb_fb8c, 0x001f_c4a9_ce16_ea9f, 0x001f_c4a9_0b41_fa34, 0x001f_c4a6_ad4e_28a0,
+    0x001f_c4a2_b0c8_2e75, 0x001f_c49d_11e6_2de3, 0x001f_c495_cc85_2df5, 0x001f_c48c_dc26_5ec1,
+    0x001f_c482_3bec_237a, 0x001f_c475_e696_dee6, 0x001f_c467_d681_7e83, 0x001f_c458_059d_c037,
+    0x001f_c446_6d70_2e21, 0x001f_c433_070b_cb99, 0x001f_c41d_cb0d_6e0e, 0x001f_c406_b196_bbf7,
+    0x001f_c3ed_b248_cb62, 0x001f_c3d2_c43e_593c, 0x001f_c3b5_de05_91b4, 0x001f_c396_f599_614c,
+    0x001f_c376_005a_4593, 0x001f_c352_f306_9371, 0x001f_c32d_c1b2_2819, 0x001f_c306_5fbd_7888,
+    0x001f_c2dc_bfcb_f263, 0x001f_c2b0_d3b9_9f9e, 0x001f_c282_8c8f_fcf0, 0x001f_c251_da79_f164,
+    0x001f_c21e_acb6_d39e, 0x001f_c1e8_f18c_6756, 0x001f_c1b0_9637_bb3c, 0x001f_c175_86dc_cd10,
+    0x001f_c137_ae74_d6b7, 0x001f_c0f6_f6bb_2415, 0x001f_c0b3_4818_4da4, 0x001f_c06c_898b_aff1,
+    0x001f_c022_a092_f365, 0x001f_bfd5_710f_72b9, 0x001f_bf84_dd29_488f, 0x001f_bf30_c52f_c60b,
+    0x001f_bed9_0777_0cc6, 0x001f_be7d_8032_7ddb, 0x001f_be1e_094b_a614, 0x001f_bdba_7a35_4408,
+    0x001f_bd52_a7b9_f826, 0x001f_bce6_63c6_201b, 0x001f_bc75_7d2c_4de5, 0x001f_bbff_bf63_b7aa,
+    0x001f_bb84_f23f_e6a2, 0x001f_bb04_d9a0_d18d, 0x001f_ba7f_351a_70ad, 0x001f_b9f3_bf92_b619,
+    0x001f_b962_2ed4_abfc, 0x001f_b8ca_3317_4a17, 0x001f_b82b_7676_5b54, 0x001f_b785_9c5b_895c,
+    0x001f_b6d8_40d5_5594, 0x001f_b622_f7d9_6943, 0x001f_b565_4c6f_37e1, 0x001f_b49e_bfbf_69d2,
+    0x001f_b3ce_c803_e747, 0x001f_b2f4_cf53_9c3f, 0x001f_b210_3244_2853, 0x001f_b120_3e5a_9604,
+    0x001f_b024_3042_e1c2, 0x001f_af1b_31c4_79a7, 0x001f_ae04_5767_e105, 0x001f_acde_9dbf_2d73,
+    0x001f_aba8_e640_060b, 0x001f_aa61_f399_ff28, 0x001f_a908_656f_66a2, 0x001f_a79a_b350_8d3d,
+    0x001f_a617_26d1_f214, 0x001f_a47b_d48b_ea00, 0x001f_a2c6_93c5_c095, 0x001f_a0f4_f47d_f315,
+    0x001f_9f04_336b_be0b, 0x001f_9cf1_2b79_f9bd, 0x001f_9ab8_4415_abc5, 0x001f_9855_5b78_2fb9,
+    0x001f_95c3_abd0_3f79, 0x001f_92fd_a9ce_f1f3, 0x001f_8ffc_da9a_e41d, 0x001f_8cb9_9e73_85f8,
+    0x001f_892a_ec47_9607, 0x001f_8545_f904_db8f, 0x001f_80fd_c336_039b, 0x001f_7c42_7839_e926,
+    0x001f_7700_a358_2acc, 0x001f_7120_0f1a_241c, 0x001f_6a82_34b7_352b, 0x001f_6300_00a8_e267,
+    0x001f_5a66_904f_e3c4, 0x001f_5072_4ece_1172, 0x001f_44c7_665c_6fdb, 0x001f_36e5_a38a_59a2,
+    0x001f_2614_3450_340a, 0x001f_113e_047b_0414, 0x001e_f6ae_fa57_cbe7, 0x001e_d38c_a188_151e,
+    0x001e_a2a6_1e12_2db1, 0x001e_5961_c78b_267c, 0x001d_ddf6_2bac_0bb1, 0x001c_db4d_d9e4_e8c0,
+];
+
+#[rustfmt::skip]
+pub(crate) const WE_DOUBLE: [f64; 256] = [
+    9.655740063209183e-16, 7.089014243955414e-18, 1.1639412496691224e-17, 1.524391512353216e-17,
+    1.833284885723744e-17, 2.1089651094644866e-17, 2.3611280778431382e-17, 2.595595772310894e-17,
+    2.8161735541977523e-17, 3.0255041303213823e-17, 3.225508254836375e-17, 3.417632340185027e-17,
+    3.6029969787344525e-17, 3.782490776869649e-17, 3.956832198097553e-17, 4.1266117781759464e-17,
+    4.2923218084425256e-17, 4.4543777432823714e-17, 4.613133981483186e-17, 4.768895725264636e-17,
+    4.921928043727963e-17, 5.072462904503147e-17, 5.220704702792672e-17, 5.366834661718192e-17,
+    5.511014372835095e-17, 5.653388673239667e-17, 5.794088004852767e-17, 5.933230365208943e-17,
+    6.07092293284718e-17, 6.207263431163193e-17, 6.342341280303077e-17, 6.476238575956142e-17,
+    6.609030925769405e-17, 6.740788167872722e-17, 6.871574991183812e-17, 7.00145147340393e-17,
+    7.130473549660643e-17, 7.258693422414648e-17, 7.386159921381792e-17, 7.512918820723728e-17,
+    7.639013119550826e-17, 7.764483290797848e-17, 7.88936750272979e-17, 8.013701816675454e-17,
+    8.137520364041762e-17, 8.260855505210038e-17, 8.383737972539139e-17, 8.506196999385323e-17,
+    8.628260436784113e-17, 8.749954859216183e-17, 8.871305660690252e-17, 8.992337142215357e-17,
+    9.113072591597909e-17, 9.233534356381788e-17, 9.353743910649129e-17, 9.47372191631295e-17,
+    9.593488279457997e-17, 9.713062202221521e-17, 9.832462230649511e-17, 9.951706298915072e-17,
+    1.0070811770242949e-16, 1.0189795474846941e-16, 1.030867374515422e-16, 1.0427462448561886e-16,
+    1.0546177017945764e-16, 1.0664832480119147e-16, 1.0783443482419485e-16, 1.0902024317583505e-16,
+    1.1020588947055781e-16, 1.1139151022861975e-16, 1.1257723908165675e-16, 1.1376320696616847e-16,
+    1.1494954230590093e-16, 1.1613637118402183e-16, 1.1732381750590458e-16, 1.1851200315326694e-16,
+    1.1970104813034652e-16, 1.2089107070273855e-16, 1.2208218752947062e-16, 1.2327451378884152e-16,
+    1.2446816329851125e-16, 1.2566324863028985e-16, 1.2685988122003975e-16, 1.2805817147307494e-16,
+    1.2925822886541196e-16, 1.3046016204120288e-16, 1.3166407890665726e-16, 1.328700867207381e-16,
+    1.3407829218289994e-16, 1.3528880151811755e-16, 1.3650172055943978e-16, 1.377171548282881e-16,
+    1.389352096127064e-16, 1.4015599004375715e-16, 1.4137960117024852e-16, 1.4260614803196654e-16,
+    1.4383573573157902e-16, 1.4506846950536877e-16, 1.4630445479294757e-16, 1.4754379730609516e-16,
+    1.487866030968626e-16, 1.500329786250737e-16, 1.5128303082535394e-16, 1.5253686717381255e-16,
+    1.537945957544997e-16, 1.5505632532575771e-16, 1.5632216538658375e-16, 1.5759222624311761e-16,
+    1.5886661907536842e-16, 1.6014545600429167e-16, 1.6142885015932787e-16, 1.6271691574651305e-16,
+    1.640097681172718e-16, 1.653075238380037e-16, 1.666103007605742e-16, 1.6791821809382289e-16,
+    1.6923139647620223e-16, 1.7054995804966298e-16, 1.7187402653490317e-16, 1.7320372730810084e-16,
+    1.745391874792534e-16, 1.7588053597224914e-16, 1.7722790360680065e-16, 1.7858142318237326e-16,
+    1.7994122956424637e-16, 1.8130745977185016e-16, 1.8268025306952523e-16, 1.8405975105985878e-16,
+    1.8544609777975695e-16, 1.8683943979941927e-16, 1.882399263243892e-16, 1.8964770930086167e-16,
+    1.9106294352443765e-16, 1.9248578675252438e-16, 1.9391639982058994e-16, 1.9535494676249091e-16,
+    1.9680159493510374e-16, 1.982565151475019e-16, 1.997198817949342e-16, 2.0119187299787347e-16,
+    2.0267267074641983e-16, 2.0416246105035888e-16, 2.0566143409519179e-16, 2.071697844044737e-16,
+    2.0868771100881597e-16, 2.1021541762192928e-16, 2.117531128241076e-16, 2.133010102535779e-16,
+    2.1485932880616633e-16, 2.1642829284376047e-16, 2.180081324120784e-16, 2.1959908346828707e-16,
+    2.212013881190496e-16, 2.2281529486961805e-16, 2.2444105888463086e-16, 2.2607894226131737e-16,
+    2.277292143158621e-16, 2.2939215188373114e-16, 2.3106803963482133e-16, 2.3275717040435346e-16,
+    2.344598455404958e-16, 2.361763752697774e-16, 2.3790707908142767e-16, 2.3965228613186235e-16,
+    2.4141233567062933e-16, 2.431875774892256e-16, 2.44978372394307e-16, 2.4678509270692887e-16,
+    2.4860812278958517e-16, 2.504478596029557e-16, 2.523047132944217e-16, 2.541791078205812e-16,
+    2.560714816061771e-16, 2.579822882420531e-16, 2.599119972249747e-16, 2.618610947423924e-16,
+    2.638300845054943e-16, 2.658194886341845e-16, 2.678298485979525e-16, 2.698617262169489e-16,
+    2.7191570472798185e-16, 2.739923899205815e-16, 2.760924113487617e-16, 2.782164236246436e-16,
+    2.8036510780069835e-16, 2.825391728480253e-16, 2.847393572388174e-16, 2.8696643064198177e-16,
+    2.8922119574179956e-16, 2.915044901905293e-16, 2.9381718870700286e-16, 2.9616020533454657e-16,
+    2.9853449587300453e-16, 3.009410605012618e-16, 3.0338094660850034e-16, 3.058552518544861e-16,
+    3.08365127481531e-16, 3.1091178190342663e-16, 3.134964845996663e-16, 3.1612057034671057e-16,
+    3.187854438219713e-16, 3.2149258462067974e-16, 3.2424355273094516e-16, 3.2703999451822404e-16,
+    3.298836492772283e-16, 3.3277635641716714e-16, 3.357200633553244e-16, 3.387168342045505e-16,
+    3.417688593525637e-16, 3.448784660453424e-16, 3.4804813010374423e-16, 3.5128048892229794e-16,
+    3.545783559224792e-16, 3.5794473666042765e-16, 3.6138284682190606e-16, 3.6489613237645425e-16,
+    3.6848829220956213e-16, 3.7216330360802073e-16, 3.7592545104162565e-16, 3.7977935876688744e-16,
+    3.8373002787892137e-16, 3.8778287856078953e-16, 3.919437984311429e-16, 3.962191980786775e-16,
+    4.0061607510565417e-16, 4.051420882956573e-16, 4.0980564389030625e-16, 4.1461599642909046e-16,
+    4.195833672073399e-16, 4.247190841824385e-16, 4.3003574816674707e-16, 4.355474314693952e-16,
+    4.41269916903607e-16, 4.472209874259932e-16, 4.534207798565834e-16, 4.598922204905932e-16,
+    4.666615664711476e-16, 4.737590853262492e-16, 4.812199172829238e-16, 4.89085182739221e-16,
+    4.97403423619194e-16, 5.06232507214416e-16, 5.156421828878083e-16, 5.257175802022275e-16,
+    5.365640977112022e-16, 5.483144034258704e-16, 5.61138745467516e-16, 5.752606481503332e-16,
+    5.909817641652103e-16, 6.087231416180908e-16, 6.290979034877557e-16, 6.530492053564041e-16,
+    6.821393079028929e-16, 7.192444966089362e-16, 7.706095350032097e-16, 8.545517038584027e-16,
+];
+
+#[rustfmt::skip]
+pub(crate) const FE_DOUBLE: [f64; 256] = [
+    1.0, 0.9381436808621747, 0.9004699299257465, 0.8717043323812036,
+    0.8477855006239896, 0.8269932966430503, 0.8084216515230084, 0.7915276369724956,
+    0.7759568520401156, 0.7614633888498963, 0.7478686219851951, 0.7350380924314235,
+    0.722867659593572, 0.711274760805076, 0.7001926550827882, 0.689566496117078,
+    0.6793505722647654, 0.6695063167319247, 0.6600008410789997, 0.6508058334145711,
+    0.6418967164272661, 0.6332519942143661, 0.624852738703666, 0.6166821809152077,
+    0.608725382079622, 0.6009689663652322, 0.5934009016917334, 0.586010318477268,
+    0.578787358602845, 0.5717230486648258, 0.5648091929124002, 0.5580382822625874,
+    0.5514034165406413, 0.5448982376724396, 0.5385168720028619, 0.5322538802630433,
+    0.5261042139836197, 0.5200631773682336, 0.5141263938147486, 0.5082897764106429,
+    0.5025495018413477, 0.49690198724154955, 0.49134386959403253, 0.4858719873418849,
+    0.4804833639304542, 0.4751751930373774, 0.46994482528396, 0.4647897562504262,
+    0.4597076156421377, 0.4546961574746155, 0.449753251162755, 0.4448768734145485,
+    0.4400651008423539, 0.4353161032156366, 0.43062813728845883, 0.42599954114303434,
+    0.4214287289976166, 0.4169141864330029, 0.4124544659971612, 0.4080481831520324,
+    0.4036940125305303, 0.3993906844752311, 0.39513698183329016, 0.3909317369847971,
+    0.38677382908413765, 0.38266218149600983, 0.3785957594095808, 0.37457356761590216,
+    0.370594648435146, 0.36665807978151416, 0.3627629733548178, 0.3589084729487498,
+    0.35509375286678746, 0.35131801643748334, 0.347580494621637, 0.3438804447045024,
+    0.34021714906678, 0.3365899140286776, 0.332998068761809, 0.3294409642641363,
+    0.3259179723935562, 0.32242848495608917, 0.31897191284495724, 0.31554768522712895,
+    0.31215524877417955, 0.3087940669345602, 0.30546361924459026, 0.3021634006756935,
+    0.2988929210155818, 0.2956517042812612, 0.2924392881618926, 0.28925522348967775,
+    0.2860990737370768, 0.28297041453878075, 0.2798688332369729, 0.27679392844851736,
+    0.27374530965280297, 0.27072259679906, 0.2677254199320448, 0.2647534188350622,
+    0.261806242689363, 0.25888354974901623, 0.2559850070304154, 0.25311029001562946,
+    0.2502590823688623, 0.24743107566532763, 0.2446259691318921, 0.24184346939887721,
+    0.23908329026244918, 0.23634515245705964, 0.23362878343743335, 0.2309339171696274,
+    0.2282602939307167, 0.22560766011668407, 0.2229757680581202, 0.2203643758433595,
+    0.21777324714870053, 0.21520215107537868, 0.21265086199297828, 0.21011915938898826,
+    0.20760682772422204, 0.2051136562938377, 0.20263943909370902, 0.20018397469191127,
+    0.19774706610509887, 0.19532852067956322, 0.19292814997677135, 0.1905457696631954,
+    0.18818119940425432, 0.1858342627621971, 0.18350478709776746, 0.1811926034754963,
+    0.1788975465724783, 0.17661945459049488, 0.1743581691713535, 0.17211353531532006,
+    0.16988540130252766, 0.1676736186172502, 0.165478041874936, 0.16329852875190182,
+    0.16113493991759203, 0.1589871389693142, 0.15685499236936523, 0.15473836938446808,
+    0.15263714202744286, 0.1505511850010399, 0.1484803756438668, 0.14642459387834494,
+    0.14438372216063478, 0.1423576454324722, 0.14034625107486245, 0.1383494288635802,
+    0.13636707092642886, 0.13439907170221363, 0.13244532790138752, 0.13050573846833077,
+    0.12858020454522817, 0.12666862943751067, 0.12477091858083096, 0.12288697950954514,
+    0.12101672182667483, 0.11916005717532768, 0.11731689921155557, 0.11548716357863353,
+    0.11367076788274431, 0.1118676316700563, 0.11007767640518538, 0.1083008254510338,
+    0.10653700405000166, 0.10478613930657017, 0.10304816017125772, 0.10132299742595363,
+    0.09961058367063713, 0.0979108533114922, 0.0962237425504328, 0.09454918937605586,
+    0.09288713355604354, 0.09123751663104016, 0.08960028191003286, 0.08797537446727022,
+    0.08636274114075691, 0.08476233053236812, 0.08317409300963238, 0.08159798070923742,
+    0.0800339475423199, 0.07848194920160642, 0.0769419431704805, 0.07541388873405841,
+    0.07389774699236475, 0.07239348087570874, 0.07090105516237183, 0.06942043649872875,
+    0.0679515934219366, 0.06649449638533977, 0.06504911778675375, 0.06361543199980733,
+    0.062193415408540995, 0.06078304644547963, 0.059384305633420266, 0.05799717563120066,
+    0.05662164128374288, 0.05525768967669704, 0.05390531019604609, 0.05256449459307169,
+    0.05123523705512628, 0.04991753428270637, 0.0486113855733795, 0.04731679291318155,
+    0.04603376107617517, 0.04476229773294328, 0.04350241356888818, 0.042254122413316234,
+    0.04101744138041482, 0.039792391023374125, 0.03857899550307486, 0.03737728277295936,
+    0.03618728478193142, 0.03500903769739741, 0.03384258215087433, 0.032687963508959535,
+    0.03154523217289361, 0.030414443910466604, 0.029295660224637393, 0.028188948763978636,
+    0.0270943837809558, 0.026012046645134217, 0.024942026419731783, 0.02388442051155817,
+    0.02283933540638524, 0.02180688750428358, 0.020787204072578117, 0.019780424338009743,
+    0.01878670074469603, 0.01780620041091136, 0.016839106826039948, 0.015885621839973163,
+    0.014945968011691148, 0.014020391403181938, 0.013109164931254991, 0.012212592426255381,
+    0.011331013597834597, 0.010464810181029979, 0.00961441364250221, 0.008780314985808975,
+    0.00796307743801704, 0.007163353183634984, 0.006381905937319179, 0.005619642207205483,
+    0.004877655983542392, 0.004157295120833795, 0.003460264777836904, 0.002788798793574076,
+    0.0021459677437189063, 0.0015362997803015724, 0.0009672692823271745, 0.00045413435384149677,
+];
+
+#[rustfmt::skip]
+pub(crate) const KE_FLOAT: [u32; 256] = [
+    0x0071_4850, 0x0000_0000, 0x004d_f56f, 0x0061_bbd6, 0x006a_6edc, 0x006f_449f,
+    0x0072_5474, 0x0074_6ff8, 0x0075_f96f, 0x0077_24d3, 0x0078_1027, 0x0078_cded,
+    0x0079_6a2c, 0x0079_ed08, 0x007a_5c36, 0x007a_bbd6, 0x007b_0ef4, 0x007b_57db,
+    0x007b_9852, 0x007b_d1bb, 0x007c_052d, 0x007c_338c, 0x007c_5d8d, 0x007c_83c8,
+    0x007c_a6b8, 0x007c_c6c6, 0x007c_e449, 0x007c_ff8b, 0x007d_18cc, 0x007d_3042,
+    0x007d_461d, 0x007d_5a84, 0x007d_6d9b, 0x007d_7f82, 0x007d_9053, 0x007d_a027,
+    0x007d_af14, 0x007d_bd2c, 0x007d_ca81, 0x007d_d722, 0x007d_e31c, 0x007d_ee7c,
+    0x007d_f94d, 0x007e_0398, 0x007e_0d68, 0x007e_16c5, 0x007e_1fb6, 0x007e_2841,
+    0x007e_306e, 0x007e_3843, 0x007e_3fc4, 0x007e_46f6, 0x007e_4dde, 0x007e_5481,
+    0x007e_5ae1, 0x007e_6104, 0x007e_66eb, 0x007e_6c9a, 0x007e_7215, 0x007e_775d,
+    0x007e_7c75, 0x007e_8160, 0x007e_861f, 0x007e_8ab5, 0x007e_8f24, 0x007e_936d,
+    0x007e_9792, 0x007e_9b95, 0x007e_9f77, 0x007e_a339, 0x007e_a6de, 0x007e_aa65,
+    0x007e_add1, 0x007e_b122, 0x007e_b45a, 0x007e_b778, 0x007e_ba80, 0x007e_bd70,
+    0x007e_c04b, 0x007e_c310, 0x007e_c5c1, 0x007e_c85e, 0x007e_cae8, 0x007e_cd60,
+    0x007e_cfc6, 0x007e_d21b, 0x007e_d45f, 0x007e_d694, 0x007e_d8b8, 0x007e_dace,
+    0x007e_dcd4, 0x007e_decd, 0x007e_e0b8, 0x007e_e295, 0x007e_e466, 0x007e_e62a,
+    0x007e_e7e1, 0x007e_e98c, 0x007e_eb2c, 0x007e_ecc1, 0x007e_ee4a, 0x007e_efc8,
+    0x007e_f13c, 0x007e_f2a6, 0x007e_f406, 0x007e_f55b, 0x007e_f6a8, 0x007e_f7ea,
+    0x007e_f924, 0x007e_fa55, 0x007e_fb7c, 0x007e_fc9b, 0x007e_fdb2, 0x007e_fec0,
+    0x007e_ffc6, 0x007f_00c5, 0x007f_01bb, 0x007f_02a9, 0x007f_0390, 0x007f_046f,
+    0x007f_0547, 0x007f_0618, 0x007f_06e1, 0x007f_07a4, 0x007f_085f, 0x007f_0914,
+    0x007f_09c1, 0x007f_0a68, 0x007f_0b08, 0x007f_0ba2, 0x007f_0c35, 0x007f_0cc2,
+    0x007f_0d48, 0x007f_0dc7, 0x007f_0e41, 0x007f_0eb4, 0x007f_0f21, 0x007f_0f87,
+    0x007f_0fe7, 0x007f_1041, 0x007f_1095, 0x007f_10e3, 0x007f_112b, 0x007f_116c,
+    0x007f_11a7, 0x007f_11dd, 0x007f_120c, 0x007f_1234, 0x007f_1257, 0x007f_1274,
+    0x007f_128a, 0x007f_129a, 0x007f_12a3, 0x007f_12a7, 0x007f_12a4, 0x007f_129a,
+    0x007f_128a, 0x007f_1274, 0x007f_1257, 0x007f_1233, 0x007f_1208, 0x007f_11d7,
+    0x007f_119f, 0x007f_1160, 0x007f_1119, 0x007f_10cc, 0x007f_1077, 0x007f_101a,
+    0x007f_0fb6, 0x007f_0f4b, 0x007f_0ed7, 0x007f_0e5b, 0x007f_0dd8, 0x007f_0d4b,
+    0x007f_0cb7, 0x007f_0c19, 0x007f_0b72, 0x007f_0ac3, 0x007f_0a0a, 0x007f_0947,
+    0x007f_087a, 0x007f_07a3, 0x007f_06c2, 0x007f_05d6, 0x007f_04de, 0x007f_03db,
+    0x007f_02cd, 0x007f_01b2, 0x007f_008a, 0x007e_ff55, 0x007e_fe13, 0x007e_fcc3,
+    0x007e_fb64, 0x007e_f9f6, 0x007e_f878, 0x007e_f6e9, 0x007e_f54a, 0x007e_f399,
+    0x007e_f1d5, 0x007e_effe, 0x007e_ee13, 0x007e_ec13, 0x007e_e9fc, 0x007e_e7ce,
+    0x007e_e588, 0x007e_e328, 0x007e_e0ad, 0x007e_de16, 0x007e_db61, 0x007e_d88b,
+    0x007e_d595, 0x007e_d27a, 0x007e_cf3b, 0x007e_cbd3, 0x007e_c840, 0x007e_c480,
+    0x007e_c090, 0x007e_bc6c, 0x007e_b811, 0x007e_b37a, 0x007e_aea3, 0x007e_a987,
+    0x007e_a421, 0x007e_9e6a, 0x007e_985c, 0x007e_91ef, 0x007e_8b1a, 0x007e_83d3,
+    0x007e_7c10, 0x007e_73c4, 0x007e_6ae1, 0x007e_6155, 0x007e_570e, 0x007e_4bf6,
+    0x007e_3ff3, 0x007e_32e6, 0x007e_24ab, 0x007e_1517, 0x007e_03f7, 0x007d_f109,
+    0x007d_dc02, 0x007d_c480, 0x007d_aa08, 0x007d_8c00, 0x007d_699a, 0x007d_41c9,
+    0x007d_131d, 0x007c_db96, 0x007c_9850, 0x007c_44f8, 0x007b_dabb, 0x007b_4e32,
+    0x007a_8a98, 0x0079_6587, 0x0077_77d8, 0x0073_6d37,
+];
+
+#[rustfmt::skip]
+pub(crate) const WE_FLOAT: [f32; 256] = [
+    1.0367772e-06, 7.611771e-09, 1.2497724e-08, 1.6368029e-08, 1.9684746e-08, 2.264484e-08,
+    2.5352419e-08, 2.7869998e-08, 3.0238432e-08, 3.2486103e-08, 3.4633633e-08, 3.6696548e-08,
+    3.8686885e-08, 4.0614186e-08, 4.2486164e-08, 4.4309157e-08, 4.6088456e-08, 4.7828518e-08,
+    4.953315e-08, 5.1205628e-08, 5.28488e-08, 5.4465154e-08, 5.605689e-08, 5.7625947e-08,
+    5.9174067e-08, 6.0702796e-08, 6.2213545e-08, 6.3707574e-08, 6.5186036e-08, 6.664998e-08,
+    6.810037e-08, 6.953808e-08, 7.096393e-08, 7.237866e-08, 7.378298e-08, 7.517751e-08,
+    7.656288e-08, 7.7939625e-08, 7.9308286e-08, 8.066935e-08, 8.202328e-08, 8.33705e-08,
+    8.471144e-08, 8.604647e-08, 8.737596e-08, 8.870026e-08, 9.0019704e-08, 9.133459e-08,
+    9.2645244e-08, 9.3951925e-08, 9.525492e-08, 9.655449e-08, 9.785087e-08, 9.914432e-08,
+    1.0043506e-07, 1.01723316e-07, 1.030093e-07, 1.04293214e-07, 1.0557526e-07, 1.0685563e-07,
+    1.08134515e-07, 1.094121e-07, 1.1068854e-07, 1.1196403e-07, 1.1323871e-07, 1.14512765e-07,
+    1.1578634e-07, 1.1705959e-07, 1.1833267e-07, 1.1960573e-07, 1.208789e-07, 1.2215231e-07,
+    1.2342613e-07, 1.2470048e-07, 1.2597549e-07, 1.272513e-07, 1.2852801e-07, 1.298058e-07,
+    1.3108475e-07, 1.32365e-07, 1.3364668e-07, 1.3492989e-07, 1.3621477e-07, 1.3750142e-07,
+    1.3878997e-07, 1.4008053e-07, 1.4137323e-07, 1.4266817e-07, 1.4396547e-07, 1.4526525e-07,
+    1.465676e-07, 1.4787267e-07, 1.4918055e-07, 1.5049135e-07, 1.518052e-07, 1.5312219e-07,
+    1.5444245e-07, 1.5576609e-07, 1.5709321e-07, 1.5842394e-07, 1.597584e-07, 1.6109668e-07,
+    1.6243892e-07, 1.6378522e-07, 1.6513569e-07, 1.6649047e-07, 1.6784965e-07, 1.6921337e-07,
+    1.7058173e-07, 1.7195488e-07, 1.7333291e-07, 1.7471596e-07, 1.7610415e-07, 1.774976e-07,
+    1.7889644e-07, 1.8030082e-07, 1.8171083e-07, 1.8312662e-07, 1.8454833e-07, 1.8597609e-07,
+    1.8741002e-07, 1.888503e-07, 1.9029702e-07, 1.9175035e-07, 1.9321043e-07, 1.946774e-07,
+    1.9615143e-07, 1.9763266e-07, 1.9912123e-07, 2.0061732e-07, 2.0212109e-07, 2.0363268e-07,
+    2.0515228e-07, 2.0668004e-07, 2.0821615e-07, 2.0976077e-07, 2.113141e-07, 2.1287632e-07,
+    2.144476e-07, 2.1602813e-07, 2.1761812e-07, 2.1921777e-07, 2.2082729e-07, 2.2244686e-07,
+    2.2407673e-07, 2.2571709e-07, 2.2736818e-07, 2.2903022e-07, 2.3070345e-07, 2.323881e-07,
+    2.3408445e-07, 2.3579273e-07, 2.3751318e-07, 2.392461e-07, 2.4099174e-07, 2.4275042e-07,
+    2.4452237e-07, 2.4630793e-07, 2.4810743e-07, 2.499211e-07, 2.5174936e-07, 2.5359245e-07,
+    2.5545077e-07, 2.5732467e-07, 2.5921452e-07, 2.6112068e-07, 2.6304352e-07, 2.6498347e-07,
+    2.6694093e-07, 2.6891635e-07, 2.7091014e-07, 2.7292273e-07, 2.7495466e-07, 2.7700636e-07,
+    2.790784e-07, 2.811712e-07, 2.832854e-07, 2.854215e-07, 2.8758012e-07, 2.8976183e-07,
+    2.9196727e-07, 2.941971e-07, 2.9645196e-07, 2.987326e-07, 3.0103973e-07, 3.0337412e-07,
+    3.0573656e-07, 3.0812785e-07, 3.105489e-07, 3.1300056e-07, 3.1548382e-07, 3.179996e-07,
+    3.2054896e-07, 3.23133e-07, 3.257528e-07, 3.2840958e-07, 3.3110453e-07, 3.3383898e-07,
+    3.3661428e-07, 3.3943186e-07, 3.4229328e-07, 3.4520002e-07, 3.4815386e-07, 3.5115653e-07,
+    3.5420987e-07, 3.5731588e-07, 3.6047666e-07, 3.6369443e-07, 3.669715e-07, 3.7031043e-07,
+    3.7371385e-07, 3.7718456e-07, 3.8072562e-07, 3.8434024e-07, 3.8803188e-07, 3.9180424e-07,
+    3.956613e-07, 3.996073e-07, 4.0364688e-07, 4.07785e-07, 4.12027e-07, 4.163787e-07,
+    4.2084645e-07, 4.2543712e-07, 4.3015822e-07, 4.35018e-07, 4.4002545e-07, 4.4519052e-07,
+    4.505242e-07, 4.5603863e-07, 4.6174736e-07, 4.676655e-07, 4.7380996e-07, 4.801999e-07,
+    4.8685683e-07, 4.938055e-07, 5.0107406e-07, 5.0869494e-07, 5.1670594e-07, 5.251512e-07,
+    5.3408286e-07, 5.4356303e-07, 5.5366655e-07, 5.6448494e-07, 5.761313e-07, 5.887481e-07,
+    6.025181e-07, 6.176814e-07, 6.3456184e-07, 6.536115e-07, 6.754887e-07, 7.0120626e-07,
+    7.324415e-07, 7.722829e-07, 8.274357e-07, 9.175679e-07,
+];
+
+#[rustfmt::skip]
+pub(crate) const FE_FLOAT: [f32; 256] = [
+    1.0, 0.9381437, 0.90046996, 0.87170434, 0.8477855, 0.8269933,
+    0.8084217, 0.7915276, 0.77595687, 0.7614634, 0.7478686, 0.7350381,
+    0.72286767, 0.71127474, 0.70019263, 0.6895665, 0.67935055, 0.6695063,
+    0.66000086, 0.65080583, 0.6418967, 0.63325197, 0.6248527, 0.6166822,
+    0.60872537, 0.60096896, 0.5934009, 0.58601034, 0.5787874, 0.57172304,
+    0.5648092, 0.5580383, 0.5514034, 0.5448982, 0.5385169, 0.53225386,
+    0.5261042, 0.52006316, 0.5141264, 0.50828975, 0.5025495, 0.496902,
+    0.49134386, 0.485872, 0.48048335, 0.4751752, 0.46994483, 0.46478975,
+    0.45970762, 0.45469615, 0.44975325, 0.44487688, 0.44006512, 0.43531612,
+    0.43062815, 0.42599955, 0.42142874, 0.4169142, 0.41245446, 0.40804818,
+    0.403694, 0.3993907, 0.39513698, 0.39093173, 0.38677382, 0.38266218,
+    0.37859577, 0.37457356, 0.37059465, 0.3666581, 0.362763, 0.35890847,
+    0.35509375, 0.351318, 0.3475805, 0.34388044, 0.34021714, 0.3365899,
+    0.33299807, 0.32944095, 0.32591796, 0.3224285, 0.3189719, 0.31554767,
+    0.31215525, 0.30879408, 0.3054636, 0.3021634, 0.29889292, 0.2956517,
+    0.29243928, 0.28925523, 0.28609908, 0.28297043, 0.27986884, 0.27679393,
+    0.2737453, 0.2707226, 0.2677254, 0.26475343, 0.26180625, 0.25888354,
+    0.25598502, 0.2531103, 0.25025907, 0.24743107, 0.24462597, 0.24184346,
+    0.23908329, 0.23634516, 0.23362878, 0.23093392, 0.2282603, 0.22560766,
+    0.22297576, 0.22036438, 0.21777324, 0.21520215, 0.21265087, 0.21011916,
+    0.20760682, 0.20511365, 0.20263945, 0.20018397, 0.19774707, 0.19532852,
+    0.19292815, 0.19054577, 0.1881812, 0.18583426, 0.18350479, 0.1811926,
+    0.17889754, 0.17661946, 0.17435817, 0.17211354, 0.1698854, 0.16767362,
+    0.16547804, 0.16329853, 0.16113494, 0.15898713, 0.15685499, 0.15473837,
+    0.15263714, 0.15055119, 0.14848037, 0.14642459, 0.14438373, 0.14235765,
+    0.14034624, 0.13834943, 0.13636707, 0.13439907, 0.13244532, 0.13050574,
+    0.1285802, 0.12666863, 0.12477092, 0.12288698, 0.12101672, 0.119160056,
+    0.1173169, 0.115487166, 0.11367077, 0.11186763, 0.11007768, 0.10830083,
+    0.10653701, 0.10478614, 0.10304816, 0.101323, 0.09961058, 0.09791085,
+    0.09622374, 0.09454919, 0.09288713, 0.091237515, 0.08960028, 0.087975375,
+    0.08636274, 0.08476233, 0.083174095, 0.081597984, 0.08003395, 0.07848195,
+    0.076941945, 0.07541389, 0.07389775, 0.072393484, 0.07090106, 0.069420435,
+    0.06795159, 0.066494495, 0.06504912, 0.063615434, 0.062193416, 0.060783047,
+    0.059384305, 0.057997175, 0.05662164, 0.05525769, 0.053905312, 0.052564494,
+    0.051235236, 0.049917534, 0.048611384, 0.047316793, 0.046033762, 0.0447623,
+    0.043502413, 0.042254124, 0.041017443, 0.039792392, 0.038578995, 0.037377283,
+    0.036187284, 0.035009038, 0.033842582, 0.032687962, 0.031545233, 0.030414443,
+    0.02929566, 0.02818895, 0.027094385, 0.026012046, 0.024942026, 0.023884421,
+    0.022839336, 0.021806888, 0.020787204, 0.019780423, 0.0187867, 0.0178062,
+    0.016839107, 0.015885621, 0.014945968, 0.014020392, 0.013109165, 0.012212592,
+    0.011331013, 0.01046481, 0.009614414, 0.008780315, 0.007963077, 0.0071633533,
+    0.006381906, 0.0056196423, 0.0048776558, 0.004157295, 0.0034602648, 0.0027887989,
+    0.0021459677, 0.0015362998, 0.0009672693, 0.00045413437,
+];
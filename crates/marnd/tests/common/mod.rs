@@ -0,0 +1,110 @@
+// Shared by the test binaries; each uses only some of these.
+#![allow(dead_code)]
+
+use marnd::{EntropyChain, EntropyErr, EntropySource, HealthTests, MPCfg, MPRng, MRndErr};
+use std::io;
+
+pub fn build(schema: &str, seed: u64) -> MPRng {
+    try_build(schema, seed, None).expect("build should succeed")
+}
+
+/// `MPRng::build` with an optional `stream`.
+pub fn try_build(schema: &str, seed: u64, stream: Option<u64>) -> Result<MPRng, MRndErr> {
+    let mut cfg = MPCfg::new();
+    cfg.insert("schema", schema);
+    cfg.insert("seed", &seed.to_string());
+    if let Some(s) = stream {
+        cfg.insert("stream", &s.to_string());
+    }
+    MPRng::build(&cfg)
+}
+
+/// `MPRng::build` from raw `key`/`value` pairs, for seed lists and spawn keys.
+pub fn try_build_cfg(schema: &str, pairs: &[(&str, &str)]) -> Result<MPRng, MRndErr> {
+    let mut cfg = MPCfg::new();
+    cfg.insert("schema", schema);
+    for (k, v) in pairs {
+        cfg.insert(k, v);
+    }
+    MPRng::build(&cfg)
+}
+
+/// Upper 0.1% point of chi-square(df), Wilson–Hilferty.
+pub fn chi2_999(df: usize) -> f64 {
+    let d = df as f64;
+    let h = 2.0 / (9.0 * d);
+    d * (1.0 - h + 3.090_232 * h.sqrt()).powi(3)
+}
+
+/// erfc with relative error below 1.2e-7 (Numerical Recipes' Chebyshev fit).
+pub fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let r = t * poly.exp();
+    if x >= 0.0 { r } else { 2.0 - r }
+}
+
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Fake sources for `EntropyChain`.
+#[derive(Debug)]
+pub enum Fake {
+    Missing,
+    Broken,
+    Stuck(u8),
+    /// 0, 1, ..., 250, 0, 1, ...
+    Counter(u64),
+}
+
+impl EntropySource for Fake {
+    fn name(&self) -> &'static str {
+        match self {
+            Fake::Missing => "missing",
+            Fake::Broken => "broken",
+            Fake::Stuck(_) => "stuck",
+            Fake::Counter(_) => "counter",
+        }
+    }
+
+    fn fill_bytes(&mut self, out: &mut [u8]) -> Result<(), EntropyErr> {
+        match self {
+            Fake::Missing => Err(EntropyErr::Unavailable {
+                name: self.name(),
+                reason: "not here".into(),
+            }),
+            Fake::Broken => Err(EntropyErr::Failed {
+                name: self.name(),
+                err: io::Error::other("read error"),
+            }),
+            Fake::Stuck(b) => {
+                out.fill(*b);
+                Ok(())
+            }
+            Fake::Counter(i) => {
+                for x in out {
+                    *x = (*i % 251) as u8;
+                    *i += 1;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn chain(sources: Vec<Fake>) -> EntropyChain {
+    let sources = sources
+        .into_iter()
+        .map(|s| Box::new(s) as Box<dyn EntropySource>)
+        .collect();
+    EntropyChain::new(sources, HealthTests::new(8.0).unwrap())
+}
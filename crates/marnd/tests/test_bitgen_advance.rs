@@ -0,0 +1,154 @@
+mod common;
+
+use common::build;
+use marnd::{Lcg64, MBitGen, MRndErr, Pcg64, Pcg64Dxsm, SplitMix64};
+
+fn dk(seed: u64) -> Lcg64 {
+    Lcg64::from_preset("DK", seed).unwrap()
+}
+
+#[test]
+fn lcg64_advance_matches_stepping() {
+    for delta in [0_u128, 1, 2, 3, 10, 255, 1000] {
+        let mut stepped = dk(42);
+        let mut jumped = dk(42);
+        for _ in 0..delta {
+            stepped.next_u64();
+        }
+        jumped.advance(delta);
+        assert_eq!(jumped.state, stepped.state, "delta {delta}");
+    }
+}
+
+#[test]
+fn lcg64_advance_wraps_at_period() {
+    let mut lcg = dk(7);
+    lcg.advance(1 << 64);
+    assert_eq!(lcg.state, 7);
+
+    let mut lcg = dk(7);
+    lcg.advance(u128::MAX);
+    lcg.next_u64();
+    assert_eq!(lcg.state, 7);
+
+    // advance composes
+    let (mut a, mut b) = (dk(1), dk(1));
+    a.advance(0x1234_5678_9abc);
+    a.advance(0xfedc_ba98_7654_3210);
+    b.advance(0x1234_5678_9abc + 0xfedc_ba98_7654_3210);
+    assert_eq!(a.state, b.state);
+}
+
+#[test]
+fn lcg64_jumped_and_split() {
+    let lcg = dk(99);
+    let jumped = lcg.jumped();
+    assert_eq!(lcg.state, 99, "jumped leaves the original alone");
+    let mut expected = dk(99);
+    expected.advance(1 << 48);
+    assert_eq!(jumped.state, expected.state);
+    assert_eq!(jumped.preset.as_deref(), Some("DK"));
+
+    let streams = lcg.split(4);
+    assert_eq!(streams.len(), 4);
+    for (i, s) in streams.iter().enumerate() {
+        let mut e = dk(99);
+        e.advance(i as u128 * (1 << 62));
+        assert_eq!(s.state, e.state, "stream {i}");
+    }
+    // the last stream ends where the first one started
+    let mut last = streams[3].clone();
+    last.advance(1 << 62);
+    assert_eq!(last.state, 99);
+
+    assert!(lcg.split(0).is_empty());
+    assert_eq!(lcg.split(1)[0].state, 99);
+}
+
+#[test]
+fn pcg64_advance_matches_stepping_and_wraps() {
+    let mut stepped = Pcg64::from_seed(42, 54);
+    let mut jumped = stepped.clone();
+    for _ in 0..1000 {
+        stepped.next_u64();
+    }
+    jumped.advance(1000);
+    assert_eq!(jumped.next_u64(), stepped.next_u64());
+
+    let mut dxsm = Pcg64Dxsm::from_seed(42, 54);
+    let first = dxsm.clone().next_u64();
+    dxsm.advance(u128::MAX);
+    dxsm.next_u64();
+    assert_eq!(dxsm.next_u64(), first);
+}
+
+#[test]
+fn splitmix64_advance_matches_stepping() {
+    let mut stepped = SplitMix64::new(5);
+    let mut jumped = SplitMix64::new(5);
+    for _ in 0..77 {
+        stepped.next_u64();
+    }
+    jumped.advance(77);
+    assert_eq!(jumped.state, stepped.state);
+}
+
+#[test]
+fn mprng_advance_dispatches_to_engine() {
+    let schemas = [
+        "Lcg64::DK",
+        "Pcg64",
+        "Pcg64Dxsm",
+        "SplitMix64",
+        "ChaCha20Rng",
+        "Philox4x64",
+        "Threefry4x64",
+    ];
+    for schema in schemas {
+        let mut stepped = build(schema, 3);
+        let mut jumped = build(schema, 3);
+        for _ in 0..37 {
+            stepped.next_u64();
+        }
+        jumped.advance(37).expect("advance should be supported");
+        assert_eq!(jumped.next_u64(), stepped.next_u64(), "{schema}");
+    }
+}
+
+#[test]
+fn mprng_jump_dispatches_to_engine() {
+    let mut rng = build("Lcg64::DK", 3);
+    rng.jump().unwrap();
+    assert_eq!(rng.next_u64(), dk(3).jumped().next_u64());
+
+    for schema in [
+        "Pcg64",
+        "SplitMix64",
+        "Xoshiro256PlusPlus",
+        "Xoroshiro128Plus",
+        "Xoshiro256PlusPlusX4",
+        "Philox4x64",
+    ] {
+        let mut a = build(schema, 3);
+        let mut b = build(schema, 3);
+        b.jump().expect("jump should be supported");
+        assert_ne!(a.next_u64(), b.next_u64(), "{schema}");
+    }
+}
+
+#[test]
+fn unsupported_engines_report_it() {
+    let mut rng = build("Mt19937", 3);
+    match rng.advance(1).expect_err("Mt19937 has no jump-ahead") {
+        MRndErr::Unsupported(msg) => assert!(msg.contains("Mt19937"), "{msg}"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(matches!(
+        build("Xoshiro256StarStar", 3).advance(5),
+        Err(MRndErr::Unsupported(_))
+    ));
+    assert!(matches!(
+        build("ChaCha20Rng", 3).jump(),
+        Err(MRndErr::Unsupported(_))
+    ));
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use common::try_build;
+use marnd::{MBitGen, MRndErr, Philox4x64, Threefry4x64};
+
+const ONES: u64 = u64::MAX;
+const PI_CTR: [u64; 4] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+];
+
+fn take<G: MBitGen>(g: &mut G, n: usize) -> Vec<u64> {
+    (0..n).map(|_| g.next_u64()).collect()
+}
+
+#[test]
+fn philox4x64_10_known_answers() {
+    // Random123 kat_vectors
+    assert_eq!(
+        Philox4x64::block(&[0; 4], &[0; 2]),
+        [
+            0x1655_4d9e_ca36_314c,
+            0xdb20_fe9d_672d_0fdc,
+            0xd7e7_72ce_e186_176b,
+            0x7e68_b68a_ec7b_a23b,
+        ]
+    );
+    assert_eq!(
+        Philox4x64::block(&[ONES; 4], &[ONES; 2]),
+        [
+            0x87b0_92c3_013f_e90b,
+            0x438c_3c67_be8d_0224,
+            0x9cc7_d7c6_9cd7_77b6,
+            0xa09c_aebf_594f_0ba0,
+        ]
+    );
+    assert_eq!(
+        Philox4x64::block(&PI_CTR, &[0x4528_21e6_38d0_1377, 0xbe54_66cf_34e9_0c6c]),
+        [
+            0xa528_f454_03e6_1d95,
+            0x38c7_2dbd_566e_9788,
+            0xa5a1_610e_72fd_18b5,
+            0x57bd_43b5_e52b_7fe6,
+        ]
+    );
+}
+
+#[test]
+fn threefry4x64_20_known_answers() {
+    // Random123 kat_vectors
+    assert_eq!(
+        Threefry4x64::block(&[0; 4], &[0; 4]),
+        [
+            0x0921_8ebd_e6c8_5537,
+            0x5594_1f52_66d8_6105,
+            0x4bd2_5e16_2824_34dc,
+            0xee29_ec84_6bd2_e40b,
+        ]
+    );
+    assert_eq!(
+        Threefry4x64::block(&[ONES; 4], &[ONES; 4]),
+        [
+            0x29c2_4097_942b_ba1b,
+            0x0371_bbfb_0f6f_4e11,
+            0x3c23_1ffa_33f8_3a1c,
+            0xcd29_113f_de32_d168,
+        ]
+    );
+}
+
+#[test]
+fn philox_increments_counter_before_each_block() {
+    // numpy's philox_next: ctr += 1 (with carry), then generate
+    let key = [0x1234, 0x5678];
+    let mut g = Philox4x64::new([ONES, 0, 0, 0], key);
+    let first = Philox4x64::block(&[0, 1, 0, 0], &key);
+    let second = Philox4x64::block(&[1, 1, 0, 0], &key);
+    assert_eq!(take(&mut g, 8), [first, second].concat());
+    assert_eq!(g.ctr, [1, 1, 0, 0]);
+    assert_eq!(g.buffer_pos, 4);
+}
+
+#[test]
+fn advance_matches_stepping() {
+    for pre in 0..5 {
+        for delta in [0_u128, 1, 2, 3, 4, 5, 7, 8, 9, 31, 100] {
+            let mut stepped = Philox4x64::new([3, 0, 0, 0], [9, 9]);
+            let mut jumped = stepped.clone();
+            take(&mut stepped, pre + delta as usize);
+            take(&mut jumped, pre);
+            jumped.advance(delta);
+            assert_eq!(
+                take(&mut jumped, 9),
+                take(&mut stepped, 9),
+                "pre {pre}, delta {delta}"
+            );
+
+            let mut stepped = Threefry4x64::new([0; 4], [1, 2, 3, 4]);
+            let mut jumped = stepped.clone();
+            take(&mut stepped, pre + delta as usize);
+            take(&mut jumped, pre);
+            jumped.advance(delta);
+            assert_eq!(take(&mut jumped, 9), take(&mut stepped, 9));
+        }
+    }
+}
+
+#[test]
+fn advance_carries_across_counter_words() {
+    let key = [5, 6];
+    let mut g = Philox4x64::new([ONES - 1, ONES, 0, 0], key);
+    g.advance(4 * 3);
+    assert_eq!(g.ctr, [1, 0, 1, 0]);
+    assert_eq!(g.next_u64(), Philox4x64::block(&[2, 0, 1, 0], &key)[0]);
+
+    // numpy's Philox.advance adds to the counter and drops the buffer
+    let mut g = Philox4x64::new([0; 4], key);
+    g.next_u64();
+    g.advance_counter(1 << 64);
+    assert_eq!(g.ctr, [1, 1, 0, 0]);
+    assert_eq!(g.next_u64(), Philox4x64::block(&[2, 1, 0, 0], &key)[0]);
+}
+
+#[test]
+fn fill_u64_matches_next_u64() {
+    for pre in 0..4 {
+        for len in [0, 1, 3, 4, 5, 17, 64] {
+            let mut a = Philox4x64::new([0; 4], [1, 2]);
+            let mut b = a.clone();
+            take(&mut a, pre);
+            take(&mut b, pre);
+            let mut buf = vec![0; len];
+            a.fill_u64(&mut buf);
+            assert_eq!(buf, take(&mut b, len));
+            assert_eq!(a.next_u64(), b.next_u64());
+
+            let mut a = Threefry4x64::new([0; 4], [1, 2, 3, 4]);
+            let mut b = a.clone();
+            take(&mut a, pre);
+            take(&mut b, pre);
+            let mut buf = vec![0; len];
+            a.fill_u64(&mut buf);
+            assert_eq!(buf, take(&mut b, len));
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+}
+
+#[test]
+fn build_counter_based_schemas() {
+    let mut rng = try_build("Philox4x64", 42, Some(7)).expect("build should succeed");
+    assert_eq!(rng.gen_name, "Philox4x64");
+    let block = Philox4x64::block(&[1, 0, 0, 0], &[42, 7]);
+    assert_eq!(rng.next_u64(), block[0]);
+    assert_eq!(rng.next_u64(), block[1]);
+    rng.seed(42);
+    assert_eq!(rng.next_u64(), block[0]);
+
+    let mut rng = try_build("Threefry4x64", 42, None).unwrap();
+    assert_eq!(
+        rng.next_u64(),
+        Threefry4x64::block(&[1, 0, 0, 0], &[42, 0, 0, 0])[0]
+    );
+
+    match try_build("Philox4x64::X", 1, None).expect_err("build should fail") {
+        MRndErr::UnknownPreset { engine, .. } => assert_eq!(engine, "Philox4x64"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
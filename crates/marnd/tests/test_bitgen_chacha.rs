@@ -0,0 +1,145 @@
+mod common;
+
+use common::try_build;
+use marnd::{ChaCha8Rng, ChaCha12Rng, ChaCha20Rng, MBitGen, MPCfg, MPRng, MRndErr};
+use rand_core::{RngCore, SeedableRng};
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn rfc8439_block_function() {
+    // RFC 8439 2.3.2: key 00..1f, nonce 00:00:00:09:00:00:00:4a:00:00:00:00, counter 1.
+    // The 32-bit counter and the first nonce word form our 64-bit block counter, the other
+    // two nonce words the stream id.
+    let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let mut rng = ChaCha20Rng::from_seed(key);
+    rng.set_stream(0x4a00_0000);
+    rng.set_word_pos(((0x0900_0000_u128 << 32) | 1) * 16);
+
+    let mut block = [0u8; 64];
+    rng.fill_bytes(&mut block);
+    assert_eq!(
+        hex(&block),
+        "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+         d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+    );
+}
+
+#[test]
+fn rfc8439_zero_key_keystream() {
+    // RFC 8439 A.1, test vector #1
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let mut block = [0u8; 64];
+    rng.fill_bytes(&mut block);
+    assert_eq!(
+        hex(&block),
+        "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+         da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"
+    );
+    assert_eq!(rng.word_pos(), 16);
+}
+
+#[test]
+fn matches_rand_chacha_seed_from_u64() {
+    for seed in [0, 1, 42, u64::MAX] {
+        let mut ours = ChaCha20Rng::seed_from_u64(seed);
+        let mut theirs = rand_chacha::ChaCha20Rng::seed_from_u64(seed);
+        for _ in 0..200 {
+            assert_eq!(ours.next_u64(), theirs.next_u64(), "seed {seed}");
+        }
+
+        let mut ours = ChaCha8Rng::seed_from_u64(seed);
+        let mut theirs = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        for _ in 0..200 {
+            assert_eq!(ours.next_u64(), theirs.next_u64(), "seed {seed}");
+        }
+
+        let mut ours = ChaCha12Rng::seed_from_u64(seed);
+        let mut theirs = rand_chacha::ChaCha12Rng::seed_from_u64(seed);
+        for _ in 0..200 {
+            assert_eq!(ours.next_u64(), theirs.next_u64(), "seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn matches_rand_chacha_streams_and_seeking() {
+    let key: [u8; 32] = core::array::from_fn(|i| (7 * i + 3) as u8);
+    let mut ours = ChaCha20Rng::from_seed(key);
+    let mut theirs = rand_chacha::ChaCha20Rng::from_seed(key);
+    ours.set_stream(0xdead_beef_0000_0001);
+    theirs.set_stream(0xdead_beef_0000_0001);
+
+    // odd word positions make next_u64 straddle buffer boundaries
+    for pos in [0_u128, 1, 15, 63, 64, 1001, (1 << 68) - 1] {
+        ours.set_word_pos(pos);
+        theirs.set_word_pos(pos);
+        assert_eq!(ours.word_pos(), theirs.get_word_pos());
+        for _ in 0..70 {
+            assert_eq!(ours.next_u64(), theirs.next_u64(), "pos {pos}");
+            assert_eq!(ours.next_u32(), theirs.next_u32(), "pos {pos}");
+        }
+        assert_eq!(ours.word_pos(), theirs.get_word_pos());
+    }
+
+    // switching stream mid-buffer keeps the position
+    ours.set_stream(5);
+    theirs.set_stream(5);
+    assert_eq!(ours.next_u64(), theirs.next_u64());
+
+    let (mut a, mut b) = ([0u8; 37], [0u8; 37]);
+    ours.fill_bytes(&mut a);
+    theirs.fill_bytes(&mut b);
+    assert_eq!(a, b);
+    assert_eq!(ours.word_pos(), theirs.get_word_pos());
+}
+
+#[test]
+fn reseed_keeps_stream_and_restarts() {
+    let mut rng = ChaCha20Rng::seed_from_u64(9);
+    rng.set_stream(3);
+    let first = rng.next_u64();
+    rng.next_u64();
+    rng.reseed(9);
+    assert_eq!(rng.stream(), 3);
+    assert_eq!(rng.word_pos(), 0);
+    assert_eq!(rng.next_u64(), first);
+}
+
+#[test]
+fn key_is_not_printed() {
+    let rng = ChaCha20Rng::from_seed([0xab; 32]);
+    let shown = format!("{rng} {rng:?}");
+    assert!(shown.contains("[REDACTED]"));
+    assert!(!shown.to_lowercase().contains("abab"));
+    assert!(!shown.contains(&0xabab_abab_u32.to_string()));
+}
+
+#[test]
+fn build_chacha_schemas() {
+    let mut rng = try_build("ChaCha20Rng", 42, Some(7)).expect("build should succeed");
+    let mut theirs = rand_chacha::ChaCha20Rng::seed_from_u64(42);
+    theirs.set_stream(7);
+    assert_eq!(rng.gen_name, "ChaCha20Rng");
+    assert_eq!(rng.next_u64(), theirs.next_u64());
+
+    let mut rng = try_build("ChaCha8Rng", 1, None).unwrap();
+    assert_eq!(
+        rng.next_u64(),
+        rand_chacha::ChaCha8Rng::seed_from_u64(1).next_u64()
+    );
+
+    // unseeded: a fresh key per generator
+    let mut cfg = MPCfg::new();
+    cfg.insert("schema", "ChaCha12Rng");
+    let mut a = MPRng::build(&cfg).unwrap();
+    let mut b = MPRng::build(&cfg).unwrap();
+    assert_ne!(a.next_u64(), b.next_u64());
+
+    match try_build("ChaCha20Rng::IETF", 1, None).expect_err("build should fail") {
+        MRndErr::UnknownPreset { engine, .. } => assert_eq!(engine, "ChaCha20Rng"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
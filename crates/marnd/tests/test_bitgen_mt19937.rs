@@ -0,0 +1,147 @@
+mod common;
+
+use common::try_build;
+use marnd::{MRndErr, Mt19937, Mt19937_64, SeedSequence};
+
+#[test]
+fn mt19937_10000th_output() {
+    // C++ [rand.predef]: the 10000th invocation of a default-constructed std::mt19937
+    let mut mt = Mt19937::new(5489);
+    let last = (0..10_000).map(|_| mt.next_u32()).last().unwrap();
+    assert_eq!(last, 4_123_659_995);
+}
+
+#[test]
+fn mt19937_64_10000th_output() {
+    // C++ [rand.predef]: the 10000th invocation of a default-constructed std::mt19937_64
+    let mut mt = Mt19937_64::new(5489);
+    let last = (0..10_000).map(|_| mt.next_u64()).last().unwrap();
+    assert_eq!(last, 9_981_545_732_273_789_042);
+}
+
+#[test]
+fn init_by_array_matches_reference() {
+    // mt19937ar.out
+    let mut mt = Mt19937::from_key(&[0x123, 0x234, 0x345, 0x456]);
+    let out = (0..5).map(|_| mt.next_u32()).collect::<Vec<_>>();
+    assert_eq!(
+        out,
+        [
+            1_067_595_299,
+            955_945_823,
+            477_289_528,
+            4_107_218_783,
+            4_228_976_476
+        ]
+    );
+
+    // mt19937-64.out
+    let mut mt = Mt19937_64::from_key(&[0x12345, 0x23456, 0x34567, 0x45678]);
+    let out = (0..3).map(|_| mt.next_u64()).collect::<Vec<_>>();
+    assert_eq!(
+        out,
+        [
+            7_266_447_313_870_364_031,
+            4_946_485_549_665_804_864,
+            16_945_909_448_695_747_420,
+        ]
+    );
+}
+
+#[test]
+fn legacy_doubles_match_numpy_and_python() {
+    // numpy: np.random.RandomState(42).random(4)
+    let mut mt = Mt19937::new(42);
+    let expected = [
+        0.374_540_118_847_362_5,
+        0.950_714_306_409_916_2,
+        0.731_993_941_811_405_1,
+        0.598_658_484_197_036_6,
+    ];
+    for &e in &expected {
+        assert_eq!(mt.next_f64_legacy(), e);
+    }
+
+    // CPython: random.seed(42); random.random()
+    let mut mt = Mt19937::from_key(&[42]);
+    assert_eq!(mt.next_f64_legacy(), 0.639_426_798_457_883_7);
+}
+
+#[test]
+fn state_roundtrip_resumes_sequence() {
+    let mut mt = Mt19937::new(7);
+    for _ in 0..1000 {
+        mt.next_u32();
+    }
+    let (key, pos) = mt.state();
+    let mut copy = Mt19937::from_state(key, pos);
+    for _ in 0..1000 {
+        assert_eq!(copy.next_u32(), mt.next_u32());
+    }
+
+    let mut mt = Mt19937_64::new(7);
+    mt.next_u64();
+    let (key, pos) = mt.state();
+    assert_eq!(pos, 1);
+    let mut copy = Mt19937_64::from_state(key, pos);
+    assert_eq!(copy.next_u64(), mt.next_u64());
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn from_state_rejects_bad_position() {
+    let _ = Mt19937::from_state([1; 624], 625);
+}
+
+#[test]
+fn build_mt19937_schemas() {
+    // numpy: the MT19937 next_uint64 joins two outputs, the first one in the high half
+    let mut rng = try_build("Mt19937", 1, None).expect("build should succeed");
+    assert_eq!(rng.gen_name, "Mt19937");
+    assert_eq!(rng.next_u64(), 0x6ac1_f425_ff47_80eb);
+
+    let mut rng = try_build("Mt19937::NumpyLegacy", 42, None).unwrap();
+    assert_eq!(rng.next_f64(), 0.374_540_118_847_362_5);
+    assert_eq!(rng.next_f64(), 0.950_714_306_409_916_2);
+    rng.seed(42);
+    assert_eq!(rng.next_f64(), 0.374_540_118_847_362_5);
+
+    // seeds above 32 bits go through init_by_array([lo, hi])
+    let mut rng = try_build("Mt19937", 1 << 40, None).unwrap();
+    let mut mt = Mt19937::from_key(&[0, 1 << 8]);
+    let hi = mt.next_u32() as u64;
+    assert_eq!(rng.next_u64(), (hi << 32) | mt.next_u32() as u64);
+
+    let mut rng = try_build("Mt19937_64", 42, None).unwrap();
+    assert_eq!(rng.next_u64(), 13_930_160_852_258_120_406);
+}
+
+#[test]
+fn build_mt19937_rejects_unknown_preset() {
+    match try_build("Mt19937::Legacy", 1, None).expect_err("build should fail") {
+        MRndErr::UnknownPreset {
+            engine, available, ..
+        } => {
+            assert_eq!(engine, "Mt19937");
+            assert_eq!(available, ["NumpyLegacy"]);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn seed_sequence_seeding_follows_numpy() {
+    // MT19937(seed_seq): key[0] = 0x80000000, key[1..] from generate_state, pos left at 623
+    let seq = SeedSequence::new(&[5]);
+    let words = seq.generate_state(624);
+    let mut mt = Mt19937::from_seed_seq(&seq);
+    let (key, pos) = mt.state();
+    assert_eq!(key[0], 0x8000_0000);
+    assert_eq!(key[1..], words[1..]);
+    assert_eq!(pos, 623);
+    assert_eq!(mt.next_u32(), Mt19937::from_state(key, 623).next_u32());
+
+    let mt64 = Mt19937_64::from_seed_seq(&seq);
+    assert_eq!(mt64.mt[0], 1 << 63);
+    assert_eq!(mt64.mti, 312);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::try_build;
+use marnd::{MBitGen, MRndErr, Pcg64, Pcg64Dxsm};
+
+// Raw (state, inc) as numpy's `bit_generator.state["state"]`.
+const STATE: u128 = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210;
+const INC: u128 = 0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f;
+
+#[test]
+fn pcg64_matches_reference_srandom_42_54() {
+    // pcg-c `check-pcg64` output for pcg64_srandom_r(42, 54)
+    let expected = [
+        0x86b1_da1d_7206_2b68_u64,
+        0x1304_aa46_c985_3d39,
+        0xa367_0e9e_0dd5_0358,
+        0xf909_0e52_9a7d_ae00,
+        0xc85b_9fd8_3799_6f2c,
+    ];
+    let mut pcg = Pcg64::from_seed(42, 54);
+    for &e in &expected {
+        assert_eq!(pcg.next_u64(), e);
+    }
+}
+
+#[test]
+fn pcg64_from_raw_state_matches_numpy_algorithm() {
+    // Values from a Python port of numpy's pcg64.h (step, then XSL-RR).
+    let expected = [
+        0x13c4_9fec_dee3_5f71_u64,
+        0x4ee9_574c_c31f_57d2,
+        0x718b_9867_b2c7_ef05,
+        0xa9b3_8989_9584_6d5c,
+        0x48d6_90c4_35a2_0381,
+    ];
+    let mut pcg = Pcg64::new(STATE, INC);
+    for &e in &expected {
+        assert_eq!(pcg.next_u64(), e);
+    }
+}
+
+#[test]
+fn pcg64dxsm_from_raw_state_matches_numpy_algorithm() {
+    // Values from a Python port of numpy's pcg64.h (DXSM on the pre-step state, cheap multiplier).
+    let expected = [
+        0xa5c2_f459_58c6_44a2_u64,
+        0xd090_3e4c_1d97_f138,
+        0x41d5_d044_52fd_e70e,
+        0xd363_42dd_a726_e612,
+        0x9106_7ada_2341_5da7,
+    ];
+    let mut pcg = Pcg64Dxsm::new(STATE, INC);
+    for &e in &expected {
+        assert_eq!(pcg.next_u64(), e);
+    }
+
+    let expected = [0xf084_7c95_18bd_db90_u64, 0x8e7d_5f55_14ba_8aaa];
+    let mut pcg = Pcg64Dxsm::from_seed(42, 54);
+    for &e in &expected {
+        assert_eq!(pcg.next_u64(), e);
+    }
+}
+
+#[test]
+fn new_forces_odd_increment() {
+    assert_eq!(Pcg64::new(0, 2).inc, 3);
+    assert_eq!(Pcg64Dxsm::new(0, 0).inc, 1);
+}
+
+#[test]
+fn build_pcg64_schemas_default_stream() {
+    let mut rng = try_build("Pcg64", 7, None).expect("build should succeed");
+    assert_eq!(rng.gen_name, "Pcg64");
+    assert_eq!(rng.next_u64(), 0x201d_d179_7e35_3e32);
+    assert_eq!(rng.next_u64(), 0xe168_0ed6_e549_8cd7);
+
+    let mut rng = try_build("Pcg64Dxsm", 7, None).expect("build should succeed");
+    assert_eq!(rng.next_u64(), 0x2529_1b70_1de8_0d2d);
+    assert_eq!(rng.next_u64(), 0x0cc5_9602_c1a6_e295);
+}
+
+#[test]
+fn build_pcg64_with_stream_matches_from_seed() {
+    let mut rng = try_build("Pcg64", 42, Some(54)).expect("build should succeed");
+    assert_eq!(rng.next_u64(), 0x86b1_da1d_7206_2b68);
+
+    let mut a = try_build("Pcg64Dxsm", 42, Some(1)).unwrap();
+    let mut b = try_build("Pcg64Dxsm", 42, Some(2)).unwrap();
+    assert_ne!(a.next_u64(), b.next_u64());
+}
+
+#[test]
+fn reseed_restarts_in_same_stream() {
+    let mut rng = try_build("Pcg64", 42, Some(54)).unwrap();
+    let first = rng.next_u64();
+    rng.next_u64();
+    rng.seed(42);
+    assert_eq!(rng.next_u64(), first);
+
+    let mut dxsm = Pcg64Dxsm::from_seed(42, 54);
+    let first = dxsm.next_u64();
+    dxsm.reseed(42);
+    assert_eq!(dxsm.next_u64(), first);
+}
+
+#[test]
+fn build_pcg64_rejects_preset() {
+    let err = try_build("Pcg64::DK", 1, None).expect_err("build should fail");
+    match err {
+        MRndErr::UnknownPreset {
+            engine, available, ..
+        } => {
+            assert_eq!(engine, "Pcg64");
+            assert!(available.is_empty());
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn unknown_engine_lists_pcg64() {
+    let err = try_build("Pcg32", 1, None).expect_err("build should fail");
+    match err {
+        MRndErr::UnknownEngine { available, .. } => {
+            assert!(available.contains(&"Pcg64"));
+            assert!(available.contains(&"Pcg64Dxsm"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
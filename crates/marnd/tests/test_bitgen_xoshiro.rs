@@ -0,0 +1,318 @@
+mod common;
+
+use common::try_build;
+use marnd::{
+    MBitGen, MPRng, MRndErr, SplitMix64, XOSHIRO_LANES, XOSHIRO_MAX_STREAM, Xoroshiro128Plus,
+    Xoroshiro128PlusX4, Xoshiro256PlusPlus, Xoshiro256PlusPlusX4, Xoshiro256StarStar,
+    Xoshiro256StarStarX4,
+};
+
+fn take<G: MBitGen>(g: &mut G, n: usize) -> Vec<u64> {
+    (0..n).map(|_| g.next_u64()).collect()
+}
+
+#[test]
+fn splitmix64_matches_reference() {
+    // splitmix64.c with x = 0 and x = 1234567
+    let mut sm = SplitMix64::new(0);
+    assert_eq!(
+        take(&mut sm, 3),
+        [
+            0xe220_a839_7b1d_cdaf,
+            0x6e78_9e6a_a1b9_65f4,
+            0x06c4_5d18_8009_454f
+        ]
+    );
+
+    let mut sm = SplitMix64::new(1_234_567);
+    assert_eq!(
+        take(&mut sm, 5),
+        [
+            6_457_827_717_110_365_317,
+            3_203_168_211_198_807_973,
+            9_817_491_932_198_370_423,
+            4_593_380_528_125_082_431,
+            16_408_922_859_458_223_821,
+        ]
+    );
+}
+
+#[test]
+fn xoshiro256starstar_matches_reference() {
+    // xoshiro256starstar.c with s = {1, 2, 3, 4}
+    let mut g = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+    assert_eq!(
+        take(&mut g, 6),
+        [
+            11_520,
+            0,
+            1_509_978_240,
+            1_215_971_899_390_074_240,
+            1_216_172_134_540_287_360,
+            607_988_272_756_665_600,
+        ]
+    );
+}
+
+#[test]
+fn xoshiro256plusplus_matches_reference() {
+    // xoshiro256plusplus.c with s = {1, 2, 3, 4}
+    let mut g = Xoshiro256PlusPlus::from_state([1, 2, 3, 4]);
+    assert_eq!(
+        take(&mut g, 6),
+        [
+            41_943_041,
+            58_720_359,
+            3_588_806_011_781_223,
+            3_591_011_842_654_386,
+            9_228_616_714_210_784_205,
+            9_973_669_472_204_895_162,
+        ]
+    );
+}
+
+#[test]
+fn xoroshiro128plus_matches_reference() {
+    // xoroshiro128plus.c with s = {1, 2}
+    let mut g = Xoroshiro128Plus::from_state([1, 2]);
+    assert_eq!(
+        take(&mut g, 6),
+        [
+            3,
+            412_333_834_243,
+            2_360_170_716_294_286_339,
+            9_295_852_285_959_843_169,
+            2_797_080_929_874_688_578,
+            6_019_711_933_173_041_966,
+        ]
+    );
+}
+
+#[test]
+fn jumps_match_reference() {
+    // jump()/long_jump() of the reference code; the jumped states were also checked against
+    // T^(2^128), T^(2^192), T^(2^64) and T^(2^96) computed as GF(2) matrix powers.
+    let mut g = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+    g.jump();
+    assert_eq!(
+        g.s,
+        [
+            0x8c7a_1539_56b5_f3d1,
+            0x701f_1a71_3401_d85e,
+            0x6527_f66a_6546_9085,
+            0x8386_b786_c440_8050,
+        ]
+    );
+    assert_eq!(g.next_u64(), 0xbbd2_f312_2984_43d8);
+
+    let mut g = Xoshiro256PlusPlus::from_state([1, 2, 3, 4]);
+    g.long_jump();
+    assert_eq!(
+        g.s,
+        [
+            0x096a_8eb7_1295_a400,
+            0xdbf8_4991_e50f_4516,
+            0x534e_e745_810d_2a0e,
+            0x3165_5ca1_a221_5bf1,
+        ]
+    );
+
+    let mut g = Xoroshiro128Plus::from_state([1, 2]);
+    g.jump();
+    assert_eq!(g.s, [0x66fb_d4be_1df0_a7b5, 0x830c_3ddb_b4aa_3172]);
+
+    let mut g = Xoroshiro128Plus::from_state([1, 2]);
+    g.long_jump();
+    assert_eq!(g.s, [0x3ce4_4494_d47d_323a, 0x2aa2_5ca8_d61d_e643]);
+}
+
+#[test]
+fn seeding_expands_with_splitmix64() {
+    let state = [
+        0xbdd7_3226_2feb_6e95,
+        0x28ef_e333_b266_f103,
+        0x4752_6757_130f_9f52,
+        0x581c_e1ff_0e4a_e394,
+    ];
+    assert_eq!(Xoshiro256StarStar::new(42).s, state);
+    assert_eq!(Xoroshiro128Plus::new(42).s, [state[0], state[1]]);
+
+    let mut g = Xoroshiro128Plus::new(1);
+    let first = g.next_u64();
+    g.reseed(1);
+    assert_eq!(g.next_u64(), first);
+}
+
+#[test]
+#[should_panic(expected = "all-zero state")]
+fn zero_state_is_rejected() {
+    let _ = Xoshiro256PlusPlus::from_state([0; 4]);
+}
+
+/// Lane `k` of `lanes` must be `scalars[k]`, interleaved one output at a time.
+fn check_interleaving<G: MBitGen, S: MBitGen>(lanes: &mut G, scalars: &mut [S]) {
+    assert_eq!(scalars.len(), XOSHIRO_LANES);
+    for i in 0..100 {
+        for (k, g) in scalars.iter_mut().enumerate() {
+            assert_eq!(lanes.next_u64(), g.next_u64(), "output {i} of lane {k}");
+        }
+    }
+}
+
+/// `fill_u64` must give the words and the final state of repeated `next_u64` from every lane
+/// position, for lengths around the block size.
+fn check_fill<G: MBitGen + Clone>(g: &G) {
+    for skip in 0..XOSHIRO_LANES {
+        for len in [0, 1, 2, 3, 4, 5, 7, 8, 9, 64, 1001] {
+            let mut a = g.clone();
+            take(&mut a, skip);
+            let mut b = a.clone();
+            let mut words = vec![0; len];
+            a.fill_u64(&mut words);
+            assert_eq!(words, take(&mut b, len), "skip {skip}, len {len}");
+            assert_eq!(a.get_state().unwrap(), b.get_state().unwrap());
+            assert_eq!(take(&mut a, 9), take(&mut b, 9));
+        }
+    }
+}
+
+#[test]
+fn lanes_are_jumped_scalar_streams() {
+    let mut scalars: Vec<_> = (0..XOSHIRO_LANES)
+        .map(|k| {
+            let mut g = Xoshiro256StarStar::new(7);
+            (0..k).for_each(|_| g.jump());
+            g
+        })
+        .collect();
+    check_interleaving(&mut Xoshiro256StarStarX4::new(7), &mut scalars);
+
+    let mut scalars: Vec<_> = (0..XOSHIRO_LANES)
+        .map(|k| {
+            let mut g = Xoshiro256PlusPlus::new(7);
+            (0..k).for_each(|_| g.jump());
+            g
+        })
+        .collect();
+    check_interleaving(&mut Xoshiro256PlusPlusX4::new(7), &mut scalars);
+
+    let mut scalars: Vec<_> = (0..XOSHIRO_LANES)
+        .map(|k| {
+            let mut g = Xoroshiro128Plus::new(7);
+            (0..k).for_each(|_| g.jump());
+            g
+        })
+        .collect();
+    check_interleaving(&mut Xoroshiro128PlusX4::new(7), &mut scalars);
+}
+
+#[test]
+fn lanes_fill_matches_next_u64() {
+    check_fill(&Xoshiro256StarStarX4::new(1));
+    check_fill(&Xoshiro256PlusPlusX4::new(2));
+    check_fill(&Xoroshiro128PlusX4::new(3));
+
+    // and so does the scalar default of the single-stream engines
+    check_fill(&Xoshiro256PlusPlus::new(2));
+}
+
+#[test]
+fn lanes_jump_long_jumps_every_lane() {
+    let mut g = Xoshiro256PlusPlusX4::new(5);
+    take(&mut g, 6);
+    let mut expected: Vec<_> = (0..XOSHIRO_LANES).map(|k| g.lane(k)).collect();
+    expected.iter_mut().for_each(|e| e.long_jump());
+    MBitGen::jump(&mut g).unwrap();
+    assert_eq!(g.lane, 2);
+    for (k, e) in expected.iter().enumerate() {
+        assert_eq!(g.lane(k).s, e.s, "lane {k}");
+    }
+
+    let mut g = Xoroshiro128PlusX4::new(5);
+    let mut first = g.lane(0);
+    first.long_jump();
+    g.jump();
+    assert_eq!(g.next_u64(), first.next_u64());
+
+    let a = Xoshiro256StarStarX4::new(9);
+    let b = Xoshiro256StarStarX4::from_state(a.s, 3);
+    assert_eq!(b.lane, 3);
+    assert_eq!(b.to_string().matches("lane").count(), 1 + 4 * XOSHIRO_LANES);
+}
+
+#[test]
+#[should_panic(expected = "lane 4 out of range")]
+fn lanes_reject_a_bad_lane() {
+    let _ = Xoshiro256PlusPlusX4::from_state(Xoshiro256PlusPlusX4::new(1).s, 4);
+}
+
+#[test]
+fn build_xoshiro_schemas() {
+    let mut rng = try_build("Xoshiro256StarStar", 42, None).expect("build should succeed");
+    assert_eq!(rng.gen_name, "Xoshiro256StarStar");
+    assert_eq!(rng.next_u64(), 0x1578_0b2e_0c2e_c716);
+    assert_eq!(rng.next_u64(), 0x6104_d986_6d11_3a7e);
+
+    let mut rng = try_build("Xoshiro256PlusPlus", 42, None).unwrap();
+    assert_eq!(rng.next_u64(), 0xd076_4d4f_4476_689f);
+
+    let mut rng = try_build("Xoroshiro128Plus", 42, None).unwrap();
+    assert_eq!(rng.next_u64(), 0xe6c7_1559_e252_5f98);
+
+    let mut rng = try_build("SplitMix64", 0, None).unwrap();
+    assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+}
+
+#[test]
+fn build_stream_is_jumped_state() {
+    let mut rng = try_build("Xoshiro256PlusPlus", 42, Some(1)).unwrap();
+    assert_eq!(rng.next_u64(), 0xc0b6_f4be_293b_1ae5);
+
+    let mut g = Xoroshiro128Plus::new(7);
+    g.jump();
+    g.jump();
+    let mut rng = try_build("Xoroshiro128Plus", 7, Some(2)).unwrap();
+    assert_eq!(rng.next_u64(), g.next_u64());
+
+    let mut g = Xoshiro256StarStarX4::new(7);
+    g.jump();
+    let mut rng = try_build("Xoshiro256StarStarX4", 7, Some(1)).unwrap();
+    assert_eq!(rng.gen_name, "Xoshiro256StarStarX4");
+    for x in take(&mut g, 8) {
+        assert_eq!(rng.next_u64(), x);
+    }
+
+    assert!(try_build("Xoshiro256StarStar", 1, Some(XOSHIRO_MAX_STREAM)).is_ok());
+    for schema in [
+        "Xoshiro256StarStar",
+        "Xoshiro256PlusPlus",
+        "Xoroshiro128Plus",
+    ] {
+        assert!(matches!(
+            try_build(schema, 1, Some(XOSHIRO_MAX_STREAM + 1)),
+            Err(MRndErr::BadCfg(_))
+        ));
+        assert!(matches!(
+            try_build(schema, 1, Some(u64::MAX)),
+            Err(MRndErr::BadCfg(_))
+        ));
+    }
+}
+
+#[test]
+fn build_xoshiro_rejects_preset_and_lists_engines() {
+    match try_build("SplitMix64::DK", 1, None).expect_err("build should fail") {
+        MRndErr::UnknownPreset { engine, .. } => assert_eq!(engine, "SplitMix64"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    let engines = MPRng::list_engines();
+    for name in [
+        "SplitMix64",
+        "Xoshiro256StarStar",
+        "Xoshiro256PlusPlus",
+        "Xoroshiro128Plus",
+    ] {
+        assert!(engines.contains(&name), "{name} missing");
+    }
+}
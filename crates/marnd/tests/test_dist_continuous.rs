@@ -0,0 +1,252 @@
+mod common;
+
+use common::{build, erfc, normal_cdf};
+use marnd::MRndErr;
+use std::f64::consts::PI;
+
+const N: usize = 100_000;
+
+/// Kolmogorov-Smirnov test at the 0.1% level of `N` draws of `sample` against `cdf`.
+fn assert_ks(name: &str, mut sample: impl FnMut() -> f64, cdf: impl Fn(f64) -> f64) {
+    let mut xs: Vec<f64> = (0..N).map(|_| sample()).collect();
+    xs.sort_by(f64::total_cmp);
+    let n = N as f64;
+    let d = xs
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0.0, f64::max);
+    assert!(d < 1.95 / n.sqrt(), "{name}: D = {d}");
+}
+
+#[test]
+fn gamma_family_passes_ks() {
+    let mut rng = build("Pcg64", 1);
+    // shape < 1 and shape > 1 take different algorithms
+    assert_ks(
+        "gamma(0.5)",
+        || rng.standard_gamma(0.5).unwrap(),
+        |x| 1.0 - erfc(x.max(0.0).sqrt()),
+    );
+    assert_ks(
+        "gamma(2)",
+        || rng.standard_gamma(2.0).unwrap(),
+        |x| 1.0 - (-x).exp() * (1.0 + x),
+    );
+    assert_ks(
+        "gamma(3, 2)",
+        || rng.gamma(3.0, 2.0).unwrap(),
+        |x| 1.0 - (-x / 2.0).exp() * (1.0 + x / 2.0 + x * x / 8.0),
+    );
+    assert_ks(
+        "chisquare(2)",
+        || rng.chisquare(2.0).unwrap(),
+        |x| 1.0 - (-x / 2.0).exp(),
+    );
+    assert_ks(
+        "exponential(2)",
+        || rng.exponential(2.0).unwrap(),
+        |x| 1.0 - (-x / 2.0).exp(),
+    );
+}
+
+#[test]
+fn beta_t_f_pass_ks() {
+    let mut rng = build("Xoshiro256PlusPlus", 2);
+    // a, b <= 1 uses Johnk's algorithm, otherwise the ratio of gammas
+    assert_ks(
+        "beta(0.5, 0.5)",
+        || rng.beta(0.5, 0.5).unwrap(),
+        |x| 2.0 / PI * x.sqrt().asin(),
+    );
+    assert_ks(
+        "beta(2, 3)",
+        || rng.beta(2.0, 3.0).unwrap(),
+        |x| x * x * (6.0 - 8.0 * x + 3.0 * x * x),
+    );
+    assert_ks(
+        "standard_t(1)",
+        || rng.standard_t(1.0).unwrap(),
+        |x| 0.5 + x.atan() / PI,
+    );
+    assert_ks(
+        "standard_t(2)",
+        || rng.standard_t(2.0).unwrap(),
+        |x| 0.5 + x / (2.0 * (2.0 + x * x).sqrt()),
+    );
+    assert_ks("f(2, 2)", || rng.f(2.0, 2.0).unwrap(), |x| x / (1.0 + x));
+}
+
+#[test]
+fn location_scale_families_pass_ks() {
+    let mut rng = build("ChaCha20Rng", 3);
+    assert_ks(
+        "normal(3, 2)",
+        || rng.normal(3.0, 2.0).unwrap(),
+        |x| normal_cdf((x - 3.0) / 2.0),
+    );
+    assert_ks(
+        "lognormal(0.5, 0.8)",
+        || rng.lognormal(0.5, 0.8).unwrap(),
+        |x| normal_cdf((x.ln() - 0.5) / 0.8),
+    );
+    assert_ks(
+        "standard_cauchy",
+        || rng.standard_cauchy(),
+        |x| 0.5 + x.atan() / PI,
+    );
+    assert_ks(
+        "laplace(1, 2)",
+        || rng.laplace(1.0, 2.0).unwrap(),
+        |x| {
+            if x < 1.0 {
+                0.5 * ((x - 1.0) / 2.0).exp()
+            } else {
+                1.0 - 0.5 * (-(x - 1.0) / 2.0).exp()
+            }
+        },
+    );
+    assert_ks(
+        "logistic(1, 2)",
+        || rng.logistic(1.0, 2.0).unwrap(),
+        |x| 1.0 / (1.0 + (-(x - 1.0) / 2.0).exp()),
+    );
+    assert_ks(
+        "gumbel(1, 2)",
+        || rng.gumbel(1.0, 2.0).unwrap(),
+        |x| (-(-(x - 1.0) / 2.0).exp()).exp(),
+    );
+}
+
+#[test]
+fn shape_families_pass_ks() {
+    let mut rng = build("Lcg64::DK", 4);
+    assert_ks(
+        "weibull(1.5)",
+        || rng.weibull(1.5).unwrap(),
+        |x| 1.0 - (-x.powf(1.5)).exp(),
+    );
+    assert_ks(
+        "pareto(3)",
+        || rng.pareto(3.0).unwrap(),
+        |x| 1.0 - (1.0 + x).powi(-3),
+    );
+    assert_ks(
+        "rayleigh(2)",
+        || rng.rayleigh(2.0).unwrap(),
+        |x| 1.0 - (-x * x / 8.0).exp(),
+    );
+    assert_ks(
+        "triangular(0, 1, 4)",
+        || rng.triangular(0.0, 1.0, 4.0).unwrap(),
+        |x| {
+            if x <= 1.0 {
+                x * x / 4.0
+            } else {
+                1.0 - (4.0 - x) * (4.0 - x) / 12.0
+            }
+        },
+    );
+    assert_ks(
+        "vonmises(0, 0)",
+        || rng.vonmises(0.0, 0.0).unwrap(),
+        |x| (x + PI) / (2.0 * PI),
+    );
+}
+
+/// I1(k) / I0(k), the mean resultant length of von Mises(mu, k).
+fn bessel_ratio(k: f64) -> f64 {
+    let (mut i0, mut i1, mut term) = (0.0, 0.0, 1.0);
+    for m in 0..60 {
+        let m = m as f64;
+        // term = (k/2)^(2m) / (m!)^2
+        i0 += term;
+        i1 += term * (k / 2.0) / (m + 1.0);
+        term *= (k / 2.0) * (k / 2.0) / ((m + 1.0) * (m + 1.0));
+    }
+    i1 / i0
+}
+
+#[test]
+fn vonmises_circular_moments() {
+    let mut rng = build("Pcg64", 5);
+    for (mu, kappa) in [(0.5, 2.0), (3.0, 1.0), (-2.5, 1e-6), (1.0, 20.0)] {
+        let (mut c, mut s) = (0.0, 0.0);
+        for _ in 0..N {
+            let x = rng.vonmises(mu, kappa).unwrap();
+            assert!((-PI..=PI).contains(&x), "{x}");
+            c += (x - mu).cos();
+            s += (x - mu).sin();
+        }
+        let (c, s) = (c / N as f64, s / N as f64);
+        assert!((c - bessel_ratio(kappa)).abs() < 0.01, "{kappa}: {c}");
+        assert!(s.abs() < 0.01, "{kappa}: {s}");
+    }
+
+    // very large kappa switches to a wrapped normal
+    let xs: Vec<f64> = (0..N).map(|_| rng.vonmises(PI, 1e8).unwrap()).collect();
+    assert!(xs.iter().all(|x| (x.abs() - PI).abs() < 1e-3));
+    assert!(xs.iter().any(|&x| x < 0.0) && xs.iter().any(|&x| x > 0.0));
+}
+
+#[test]
+fn degenerate_parameters() {
+    let mut rng = build("Pcg64", 6);
+    assert_eq!(rng.standard_gamma(0.0).unwrap(), 0.0);
+    assert_eq!(rng.gamma(2.0, 0.0).unwrap(), 0.0);
+    assert_eq!(rng.weibull(0.0).unwrap(), 0.0);
+    assert_eq!(rng.normal(1.5, 0.0).unwrap(), 1.5);
+    assert_eq!(rng.laplace(-1.0, 0.0).unwrap(), -1.0);
+    assert_eq!(rng.logistic(2.0, 0.0).unwrap(), 2.0);
+    assert_eq!(rng.gumbel(2.0, 0.0).unwrap(), 2.0);
+    assert_eq!(rng.rayleigh(0.0).unwrap(), 0.0);
+    assert_eq!(rng.lognormal(0.0, 0.0).unwrap(), 1.0);
+    let x = rng.triangular(1.0, 1.0, 2.0).unwrap();
+    assert!((1.0..=2.0).contains(&x));
+
+    // tiny beta parameters underflow both powers and fall back to logarithms
+    for _ in 0..1000 {
+        let x = rng.beta(1e-3, 1e-3).unwrap();
+        assert!((0.0..=1.0).contains(&x), "{x}");
+    }
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let mut rng = build("Pcg64", 7);
+    let before = rng.get_state().unwrap();
+    let results = [
+        rng.normal(0.0, -1.0),
+        rng.exponential(-1.0),
+        rng.standard_gamma(-0.5),
+        rng.gamma(1.0, f64::NAN),
+        rng.beta(0.0, 1.0),
+        rng.beta(1.0, -1.0),
+        rng.chisquare(0.0),
+        rng.standard_t(f64::NAN),
+        rng.f(1.0, 0.0),
+        rng.lognormal(0.0, -0.1),
+        rng.weibull(-1.0),
+        rng.laplace(0.0, -1.0),
+        rng.logistic(0.0, -1.0),
+        rng.pareto(0.0),
+        rng.gumbel(0.0, -1.0),
+        rng.rayleigh(-1.0),
+        rng.vonmises(0.0, -1.0),
+        rng.vonmises(f64::INFINITY, 1.0),
+        rng.triangular(1.0, 0.0, 2.0),
+        rng.triangular(0.0, 3.0, 2.0),
+        rng.triangular(1.0, 1.0, 1.0),
+    ];
+    for (i, r) in results.into_iter().enumerate() {
+        assert!(matches!(r, Err(MRndErr::InvalidArgument(_))), "{i}: {r:?}");
+    }
+    // nothing was drawn
+    assert_eq!(rng.get_state().unwrap(), before);
+
+    let msg = rng.gamma(-2.0, 1.0).unwrap_err().to_string();
+    assert!(msg.contains("gamma: shape must be >= 0"), "{msg}");
+}
@@ -0,0 +1,327 @@
+mod common;
+
+use common::{build, chi2_999, try_build_cfg};
+use marnd::{MRndErr, POISSON_LAM_MAX};
+
+/// `ln(k!)` for `k` in `0..=max`, by summing logarithms.
+fn ln_factorials(max: usize) -> Vec<f64> {
+    let mut out = vec![0.0; max + 1];
+    for k in 1..=max {
+        out[k] = out[k - 1] + (k as f64).ln();
+    }
+    out
+}
+
+const N: usize = 100_000;
+
+/// Pearson chi-square test of `N` draws of `sample` against the exact `pmf` on `0..`, with
+/// adjacent cells pooled until each expects at least 5; the last cell takes the upper tail.
+fn assert_gof(name: &str, mut sample: impl FnMut() -> u64, pmf: impl Fn(u64) -> f64) {
+    let mut counts = std::collections::BTreeMap::new();
+    for _ in 0..N {
+        *counts.entry(sample()).or_insert(0u64) += 1;
+    }
+    let n = N as f64;
+    let mut cells: Vec<(f64, f64)> = Vec::new();
+    let (mut exp, mut obs, mut total_exp) = (0.0, 0.0, 0.0);
+    let mut k = 0;
+    while n * (1.0 - total_exp) - exp >= 10.0 {
+        exp += n * pmf(k);
+        obs += counts.get(&k).copied().unwrap_or(0) as f64;
+        k += 1;
+        if exp >= 5.0 {
+            total_exp += exp / n;
+            cells.push((obs, exp));
+            (exp, obs) = (0.0, 0.0);
+        }
+    }
+    let tail_obs = n - cells.iter().map(|c| c.0).sum::<f64>();
+    let tail_exp = n - cells.iter().map(|c| c.1).sum::<f64>();
+    cells.push((tail_obs, tail_exp));
+
+    let stat: f64 = cells.iter().map(|(o, e)| (o - e) * (o - e) / e).sum();
+    let df = cells.len() - 1;
+    assert!(df >= 2, "{name}: only {} cells", cells.len());
+    assert!(stat < chi2_999(df), "{name}: chi2 = {stat}, df = {df}");
+}
+
+#[test]
+fn binomial_matches_pmf() {
+    let lf = ln_factorials(2000);
+    let mut rng = build("Pcg64", 1);
+    // inversion (n p <= 30), BTPE, and BTPE on the reflected p > 1/2
+    for (n, p) in [
+        (20, 0.3f64),
+        (100, 0.05),
+        (1000, 0.4),
+        (500, 0.9),
+        (2000, 0.02),
+    ] {
+        let pmf = |k: u64| {
+            if k > n {
+                return 0.0;
+            }
+            let (k, n) = (k as usize, n as usize);
+            (lf[n] - lf[k] - lf[n - k] + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp()
+        };
+        assert_gof(
+            &format!("binomial({n}, {p})"),
+            || rng.binomial(n, p).unwrap(),
+            pmf,
+        );
+    }
+}
+
+#[test]
+fn poisson_matches_pmf() {
+    let lf = ln_factorials(2000);
+    let mut rng = build("Xoshiro256PlusPlus", 2);
+    // multiplication below 10, PTRS above
+    for lam in [0.3, 3.5, 9.99, 10.0, 50.0, 600.0] {
+        let pmf = |k: u64| (-lam + k as f64 * f64::ln(lam) - lf[k as usize]).exp();
+        assert_gof(
+            &format!("poisson({lam})"),
+            || rng.poisson(lam).unwrap(),
+            pmf,
+        );
+    }
+}
+
+#[test]
+fn binomial_btpe_matches_numpy() {
+    // numpy.random.default_rng(42).binomial(n, p, 12): BTPE for n * min(p, 1 - p) > 30,
+    // n - BTPE(n, 1 - p) for p > 0.5
+    let cases: [(u64, f64, [u64; 12]); 4] = [
+        (100, 0.4, [46, 31, 34, 39, 40, 34, 38, 31, 45, 46, 35, 38]),
+        (
+            1000,
+            0.3,
+            [295, 276, 293, 289, 304, 312, 322, 306, 291, 329, 296, 319],
+        ),
+        (
+            100_000,
+            0.01,
+            [
+                965, 1013, 945, 982, 973, 1004, 1025, 1045, 967, 1039, 970, 1005,
+            ],
+        ),
+        (
+            500,
+            0.9,
+            [443, 462, 458, 452, 451, 460, 454, 444, 443, 459, 453, 446],
+        ),
+    ];
+    for (n, p, expected) in cases {
+        let mut rng = try_build_cfg("Pcg64", &[("seed", "[42]")]).unwrap();
+        let draws: Vec<u64> = (0..12).map(|_| rng.binomial(n, p).unwrap()).collect();
+        assert_eq!(draws, expected, "binomial({n}, {p})");
+    }
+}
+
+#[test]
+fn geometric_and_negative_binomial_match_pmf() {
+    let mut rng = build("ChaCha20Rng", 3);
+    // search for p >= 1/3, inversion below
+    for p in [0.9f64, 0.5, 0.2, 0.01] {
+        let pmf = |k: u64| {
+            if k == 0 {
+                0.0
+            } else {
+                p * (1.0 - p).powi(k as i32 - 1)
+            }
+        };
+        assert_gof(
+            &format!("geometric({p})"),
+            || rng.geometric(p).unwrap(),
+            pmf,
+        );
+    }
+
+    for (n, p) in [(3.5, 0.4), (1.0, 0.9), (20.0, 0.05)] {
+        // P(k+1) = P(k) (k + n) / (k + 1) (1 - p), P(0) = p^n
+        let pmf = |k: u64| {
+            (0..k).fold(f64::powf(p, n), |acc, i| {
+                acc * (i as f64 + n) / (i as f64 + 1.0) * (1.0 - p)
+            })
+        };
+        assert_gof(
+            &format!("negative_binomial({n}, {p})"),
+            || rng.negative_binomial(n, p).unwrap(),
+            pmf,
+        );
+    }
+}
+
+#[test]
+fn hypergeometric_matches_pmf() {
+    let lf = ln_factorials(2000);
+    let ln_choose = |n: u64, k: u64| lf[n as usize] - lf[k as usize] - lf[(n - k) as usize];
+    let mut rng = build("Lcg64::DK", 4);
+    // the urn for small samples, HRUA otherwise; the last ones take the complement
+    for (good, bad, sample) in [
+        (30, 20, 8),
+        (5, 1000, 3),
+        (300, 200, 100),
+        (20, 480, 450),
+        (900, 100, 995),
+        (1000, 1000, 1000),
+    ] {
+        let pmf = |k: u64| {
+            if k > good || k > sample || sample - k > bad {
+                return 0.0;
+            }
+            (ln_choose(good, k) + ln_choose(bad, sample - k) - ln_choose(good + bad, sample)).exp()
+        };
+        assert_gof(
+            &format!("hypergeometric({good}, {bad}, {sample})"),
+            || rng.hypergeometric(good, bad, sample).unwrap(),
+            pmf,
+        );
+    }
+}
+
+#[test]
+fn logseries_and_zipf_match_pmf() {
+    let mut rng = build("Pcg64", 5);
+    for p in [0.3f64, 0.6, 0.99] {
+        let pmf = |k: u64| {
+            if k == 0 {
+                0.0
+            } else {
+                -p.powi(k as i32) / (k as f64 * (-p).ln_1p())
+            }
+        };
+        assert_gof(
+            &format!("logseries({p})"),
+            || rng.logseries(p).unwrap(),
+            pmf,
+        );
+    }
+
+    for a in [1.5, 2.5, 4.0] {
+        // zeta(a): partial sum plus the Euler-Maclaurin tail
+        let m = 10_000.0f64;
+        let zeta = (1..10_000).map(|k| (k as f64).powf(-a)).sum::<f64>()
+            + m.powf(1.0 - a) / (a - 1.0)
+            + 0.5 * m.powf(-a);
+        let pmf = |k: u64| {
+            if k == 0 {
+                0.0
+            } else {
+                (k as f64).powf(-a) / zeta
+            }
+        };
+        assert_gof(&format!("zipf({a})"), || rng.zipf(a).unwrap(), pmf);
+    }
+}
+
+#[test]
+fn multinomial_counts() {
+    let mut rng = build("Pcg64", 6);
+    let pvals = [0.1, 0.0, 0.25, 0.4, 0.25];
+    let trials = 20_000;
+    let n = 50;
+    let mut sums = [0u64; 5];
+    for _ in 0..trials {
+        let x = rng.multinomial(n, &pvals).unwrap();
+        assert_eq!(x.iter().sum::<u64>(), n);
+        assert_eq!(x[1], 0);
+        for (s, v) in sums.iter_mut().zip(&x) {
+            *s += v;
+        }
+    }
+    // each count is binomial(n, p_i): compare the means
+    for (i, &p) in pvals.iter().enumerate() {
+        let mean = sums[i] as f64 / trials as f64;
+        let sd = (n as f64 * p * (1.0 - p) / trials as f64).sqrt();
+        assert!(
+            (mean - n as f64 * p).abs() <= 4.0 * sd + 1e-12,
+            "{i}: {mean}"
+        );
+    }
+
+    // the first count is exactly binomial(n, p_0)
+    let lf = ln_factorials(50);
+    assert_gof(
+        "multinomial[0]",
+        || rng.multinomial(n, &pvals).unwrap()[0],
+        |k| {
+            if k > n {
+                return 0.0;
+            }
+            let k = k as usize;
+            (lf[50] - lf[k] - lf[50 - k] + k as f64 * 0.1f64.ln() + (50 - k) as f64 * 0.9f64.ln())
+                .exp()
+        },
+    );
+
+    // the last probability is implied, like numpy
+    let mut out = [0; 3];
+    rng.multinomial_into(10, &[0.5, 0.5, 0.7], &mut out)
+        .unwrap();
+    assert_eq!(out[2], 0);
+    assert_eq!(rng.multinomial(7, &[1.0]).unwrap(), [7]);
+}
+
+#[test]
+fn edge_cases() {
+    let mut rng = build("Pcg64", 7);
+    for _ in 0..100 {
+        assert_eq!(rng.binomial(10, 0.0).unwrap(), 0);
+        assert_eq!(rng.binomial(10, 1.0).unwrap(), 10);
+        assert_eq!(rng.binomial(0, 0.5).unwrap(), 0);
+        assert_eq!(rng.poisson(0.0).unwrap(), 0);
+        assert_eq!(rng.geometric(1.0).unwrap(), 1);
+        assert_eq!(rng.negative_binomial(2.0, 1.0).unwrap(), 0);
+        assert_eq!(rng.logseries(0.0).unwrap(), 1);
+        assert_eq!(rng.hypergeometric(5, 7, 0).unwrap(), 0);
+        assert_eq!(rng.hypergeometric(5, 7, 12).unwrap(), 5);
+        assert_eq!(rng.hypergeometric(0, 7, 4).unwrap(), 0);
+        assert_eq!(rng.hypergeometric(7, 0, 4).unwrap(), 4);
+        assert_eq!(rng.multinomial(0, &[0.5, 0.5]).unwrap(), [0, 0]);
+    }
+    // huge parameters stay in range
+    let x = rng.binomial(u64::MAX, 0.5).unwrap();
+    assert!(x > u64::MAX / 4 && x < u64::MAX / 4 * 3);
+    let x = rng.poisson(1e15).unwrap() as f64;
+    assert!((x - 1e15).abs() < 1e9);
+    assert!(rng.poisson(POISSON_LAM_MAX).is_ok());
+    assert!(rng.geometric(1e-300).unwrap() > 1);
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let mut rng = build("Pcg64", 8);
+    let before = rng.get_state().unwrap();
+    let results = [
+        rng.binomial(10, -0.1),
+        rng.binomial(10, 1.5),
+        rng.binomial(10, f64::NAN),
+        rng.poisson(-1.0),
+        rng.poisson(f64::NAN),
+        rng.poisson(1e19),
+        rng.geometric(0.0),
+        rng.geometric(1.5),
+        rng.negative_binomial(0.0, 0.5),
+        rng.negative_binomial(1.0, 0.0),
+        rng.negative_binomial(1e19, 0.5),
+        rng.hypergeometric(3, 4, 8),
+        rng.hypergeometric(u64::MAX, 1, 1),
+        rng.logseries(1.0),
+        rng.logseries(-0.5),
+        rng.zipf(1.0),
+        rng.zipf(f64::NAN),
+    ];
+    for (i, r) in results.into_iter().enumerate() {
+        assert!(matches!(r, Err(MRndErr::InvalidArgument(_))), "{i}: {r:?}");
+    }
+    let bad_pvals: [&[f64]; 4] = [&[], &[0.5, -0.1, 0.6], &[0.7, 0.7, 0.0], &[f64::NAN, 1.0]];
+    for pvals in bad_pvals {
+        assert!(matches!(
+            rng.multinomial(5, pvals),
+            Err(MRndErr::InvalidArgument(_))
+        ));
+    }
+    assert!(rng.multinomial_into(5, &[0.5, 0.5], &mut [0; 3]).is_err());
+    assert_eq!(rng.get_state().unwrap(), before);
+}
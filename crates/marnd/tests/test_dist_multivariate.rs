@@ -0,0 +1,208 @@
+mod common;
+
+use common::build;
+use marnd::{MRndErr, MultivariateNormal};
+
+/// Sample mean and row-major covariance of `n` draws of dimension `d`.
+fn moments(d: usize, n: usize, mut sample: impl FnMut() -> Vec<f64>) -> (Vec<f64>, Vec<f64>) {
+    let draws: Vec<Vec<f64>> = (0..n).map(|_| sample()).collect();
+    let mean: Vec<f64> = (0..d)
+        .map(|i| draws.iter().map(|x| x[i]).sum::<f64>() / n as f64)
+        .collect();
+    let mut cov = vec![0.0; d * d];
+    for x in &draws {
+        for i in 0..d {
+            for j in 0..d {
+                cov[i * d + j] += (x[i] - mean[i]) * (x[j] - mean[j]);
+            }
+        }
+    }
+    cov.iter_mut().for_each(|c| *c /= (n - 1) as f64);
+    (mean, cov)
+}
+
+/// Every sample covariance within `k` standard errors of `expected`, with the normal-theory
+/// `se(c_ij)^2 = (s_ii s_jj + s_ij^2) / n`.
+fn assert_cov_close(name: &str, cov: &[f64], expected: &[f64], d: usize, n: usize, k: f64) {
+    for i in 0..d {
+        for j in 0..d {
+            let e = expected[i * d + j];
+            let se = ((expected[i * d + i] * expected[j * d + j] + e * e) / n as f64).sqrt();
+            let c = cov[i * d + j];
+            assert!(
+                (c - e).abs() <= k * se + 1e-12,
+                "{name}: cov[{i}][{j}] = {c}, expected {e}"
+            );
+        }
+    }
+}
+
+const N: usize = 100_000;
+
+#[test]
+fn multivariate_normal_has_the_given_moments() {
+    let mut rng = build("Pcg64", 1);
+    let mean = [1.0, -2.0, 0.5];
+    let cov = [
+        4.0, 1.2, -0.8, //
+        1.2, 1.0, 0.3, //
+        -0.8, 0.3, 2.0,
+    ];
+    let mvn = MultivariateNormal::new(&mean, &cov).unwrap();
+    assert_eq!(mvn.dim(), 3);
+    let (m, c) = moments(3, N, || mvn.sample(&mut rng));
+    for i in 0..3 {
+        let se = (cov[i * 3 + i] / N as f64).sqrt();
+        assert!((m[i] - mean[i]).abs() < 5.0 * se, "mean[{i}] = {}", m[i]);
+    }
+    assert_cov_close("cholesky", &c, &cov, 3, N, 5.0);
+
+    // the convenience methods draw the same values as the prebuilt sampler
+    let mut a = build("Pcg64", 2);
+    let mut b = build("Pcg64", 2);
+    let mut out = [0.0; 3];
+    a.multivariate_normal_into(&mean, &cov, &mut out).unwrap();
+    assert_eq!(out.to_vec(), mvn.sample(&mut b));
+    assert_eq!(
+        a.multivariate_normal(&mean, &cov).unwrap(),
+        mvn.sample(&mut b)
+    );
+}
+
+#[test]
+fn singular_covariance_uses_the_eigendecomposition() {
+    let mut rng = build("Xoshiro256PlusPlus", 3);
+    // x2 = x0 + x1: positive semi-definite with rank 2, no Cholesky factor
+    let cov = [
+        1.0, 0.5, 1.5, //
+        0.5, 2.0, 2.5, //
+        1.5, 2.5, 4.0,
+    ];
+    let mvn = MultivariateNormal::new(&[0.0; 3], &cov).unwrap();
+    let (_, c) = moments(3, N, || {
+        let x = mvn.sample(&mut rng);
+        assert!((x[2] - x[0] - x[1]).abs() < 1e-9, "{x:?}");
+        x
+    });
+    assert_cov_close("eigen", &c, &cov, 3, N, 5.0);
+
+    // a zero covariance is the point mass at the mean
+    let mvn = MultivariateNormal::new(&[3.0, 4.0], &[0.0; 4]).unwrap();
+    assert_eq!(mvn.sample(&mut rng), [3.0, 4.0]);
+}
+
+#[test]
+fn bad_covariances_are_rejected() {
+    let mut rng = build("Pcg64", 4);
+    let not_psd = [1.0, 2.0, 2.0, 1.0];
+    let not_symmetric = [1.0, 0.5, 0.4, 1.0];
+    for (mean, cov) in [
+        (&[0.0, 0.0][..], &not_psd[..]),
+        (&[0.0, 0.0], &not_symmetric),
+        (&[0.0, 0.0], &[1.0, 0.0, 0.0]),
+        (&[], &[]),
+        (&[0.0, f64::NAN], &[1.0, 0.0, 0.0, 1.0]),
+    ] {
+        assert!(
+            matches!(
+                rng.multivariate_normal(mean, cov),
+                Err(MRndErr::InvalidArgument(_))
+            ),
+            "{mean:?} {cov:?}"
+        );
+    }
+    let mvn = MultivariateNormal::new(&[0.0, 0.0], &[1.0, 0.0, 0.0, 1.0]).unwrap();
+    assert!(mvn.sample_into(&mut rng, &mut [0.0; 3]).is_err());
+}
+
+#[test]
+fn dirichlet_has_the_given_moments() {
+    let mut rng = build("ChaCha20Rng", 5);
+    for alpha in [vec![2.0, 3.0, 5.0], vec![0.5, 1.5, 0.2, 4.0]] {
+        let d = alpha.len();
+        let a0: f64 = alpha.iter().sum();
+        let (m, c) = moments(d, N, || {
+            let x = rng.dirichlet(&alpha).unwrap();
+            assert!((x.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            assert!(x.iter().all(|&v| (0.0..=1.0).contains(&v)));
+            x
+        });
+        // Var = a_i (a0 - a_i) / (a0^2 (a0 + 1)), Cov = -a_i a_j / (a0^2 (a0 + 1))
+        let mut expected = vec![0.0; d * d];
+        for i in 0..d {
+            let se = (alpha[i] * (a0 - alpha[i]) / (a0 * a0 * (a0 + 1.0)) / N as f64).sqrt();
+            assert!(
+                (m[i] - alpha[i] / a0).abs() < 5.0 * se,
+                "{alpha:?}: mean[{i}]"
+            );
+            for j in 0..d {
+                let num = if i == j {
+                    alpha[i] * (a0 - alpha[i])
+                } else {
+                    -alpha[i] * alpha[j]
+                };
+                expected[i * d + j] = num / (a0 * a0 * (a0 + 1.0));
+            }
+        }
+        // Dirichlet coordinates are skewed, so allow more than for normal data
+        assert_cov_close(&format!("dirichlet{alpha:?}"), &c, &expected, d, N, 10.0);
+    }
+}
+
+#[test]
+fn dirichlet_with_small_alpha() {
+    // all alpha < 0.1 breaks a stick with betas; most mass lands on one coordinate
+    let mut rng = build("Pcg64", 6);
+    let alpha = [0.05, 0.02, 0.08];
+    let mut out = [0.0; 3];
+    let mut means = [0.0; 3];
+    for _ in 0..N {
+        rng.dirichlet_into(&alpha, &mut out).unwrap();
+        assert!((out.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(out.iter().all(|&v| (0.0..=1.0).contains(&v)));
+        for (m, x) in means.iter_mut().zip(out) {
+            *m += x / N as f64;
+        }
+    }
+    for (i, &a) in alpha.iter().enumerate() {
+        assert!(
+            (means[i] - a / 0.15).abs() < 0.01,
+            "mean[{i}] = {}",
+            means[i]
+        );
+    }
+
+    for alpha in [&[][..], &[1.0, 0.0], &[1.0, -1.0], &[f64::INFINITY]] {
+        assert!(matches!(
+            rng.dirichlet(alpha),
+            Err(MRndErr::InvalidArgument(_))
+        ));
+    }
+    assert!(rng.dirichlet_into(&[1.0, 1.0], &mut [0.0; 3]).is_err());
+}
+
+#[test]
+fn multinomial_covariance() {
+    // Var(X_i) = n p_i (1 - p_i), Cov(X_i, X_j) = -n p_i p_j
+    let mut rng = build("Lcg64::DK", 7);
+    let (n, pvals) = (30u64, [0.2, 0.5, 0.3]);
+    let (m, c) = moments(3, N, || {
+        let mut out = [0u64; 3];
+        rng.multinomial_into(n, &pvals, &mut out).unwrap();
+        out.iter().map(|&x| x as f64).collect()
+    });
+    let nf = n as f64;
+    let mut expected = [0.0; 9];
+    for i in 0..3 {
+        let se = (nf * pvals[i] * (1.0 - pvals[i]) / N as f64).sqrt();
+        assert!((m[i] - nf * pvals[i]).abs() < 5.0 * se, "mean[{i}]");
+        for j in 0..3 {
+            expected[i * 3 + j] = if i == j {
+                nf * pvals[i] * (1.0 - pvals[i])
+            } else {
+                -nf * pvals[i] * pvals[j]
+            };
+        }
+    }
+    assert_cov_close("multinomial", &c, &expected, 3, N, 5.0);
+}
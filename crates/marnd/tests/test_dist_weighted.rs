@@ -0,0 +1,187 @@
+mod common;
+
+use common::{build, chi2_999};
+use marnd::{AliasTable, FenwickWeightedIndex, MRndErr, WeightedIndex};
+
+/// Pearson chi-square of `n` draws of `sample` against probabilities proportional to
+/// `weights`; zero-weight outcomes must never appear.
+fn assert_fits(name: &str, weights: &[f64], n: usize, mut sample: impl FnMut() -> usize) {
+    let mut counts = vec![0u64; weights.len()];
+    for _ in 0..n {
+        counts[sample()] += 1;
+    }
+    let sum = weights.iter().sum::<f64>();
+    let mut stat = 0.0;
+    let mut df = 0;
+    for (&c, &w) in counts.iter().zip(weights) {
+        if w == 0.0 {
+            assert_eq!(c, 0, "{name}: drew a zero-weight outcome");
+            continue;
+        }
+        let e = n as f64 * w / sum;
+        stat += (c as f64 - e).powi(2) / e;
+        df += 1;
+    }
+    df -= 1;
+    assert!(stat < chi2_999(df), "{name}: chi2 = {stat}, df = {df}");
+}
+
+#[test]
+fn alias_table_matches_weights() {
+    let mut rng = build("Pcg64", 1);
+    let weights = [1.0, 0.0, 3.0, 0.5, 10.0, 2.5, 0.0, 7.0];
+    let table = AliasTable::new(&weights).unwrap();
+    assert_eq!(table.len(), weights.len());
+    assert_fits("alias f64", &weights, 200_000, || table.sample(&mut rng));
+
+    // the table reproduces the normalized weights
+    let sum = weights.iter().sum::<f64>();
+    for (i, &w) in weights.iter().enumerate() {
+        assert!((table.probability(i) - w / sum).abs() < 1e-12, "{i}");
+    }
+    assert_eq!(table.probability(weights.len()), 0.0);
+
+    // a skewed, larger table
+    let weights: Vec<f64> = (1..=200).map(|i| 1.0 / i as f64).collect();
+    let table = WeightedIndex::new(&weights).unwrap();
+    assert_fits("alias zipf-like", &weights, 400_000, || {
+        table.sample(&mut rng)
+    });
+}
+
+#[test]
+fn int_weights_are_exact() {
+    let mut rng = build("Xoshiro256PlusPlus", 2);
+    let weights = [3u64, 0, 1, 7, 5];
+    let table = AliasTable::from_int_weights(&weights).unwrap();
+    // every probability is a multiple of 1 / (n * total)
+    for (i, &w) in weights.iter().enumerate() {
+        assert_eq!(table.probability(i), w as f64 / 16.0, "{i}");
+    }
+    let wf: Vec<f64> = weights.iter().map(|&w| w as f64).collect();
+    assert_fits("alias int", &wf, 200_000, || table.sample(&mut rng));
+
+    // totals up to u64::MAX are fine
+    let table = AliasTable::from_int_weights(&[u64::MAX - 1, 1]).unwrap();
+    assert!(table.probability(1) > 0.0);
+    assert_eq!(table.sample(&mut rng), 0);
+}
+
+#[test]
+fn single_and_degenerate_tables() {
+    let mut rng = build("Pcg64", 3);
+    let table = AliasTable::new(&[0.25]).unwrap();
+    let one_hot = AliasTable::new(&[0.0, 0.0, 5.0, 0.0]).unwrap();
+    let mut out = [9; 64];
+    for _ in 0..100 {
+        assert_eq!(table.sample(&mut rng), 0);
+        assert_eq!(one_hot.sample(&mut rng), 2);
+    }
+    one_hot.fill(&mut rng, &mut out);
+    assert!(out.iter().all(|&i| i == 2));
+
+    // fill gives the same values as repeated sample
+    let table = AliasTable::new(&[1.0, 2.0, 3.0]).unwrap();
+    let mut a = build("Pcg64", 4);
+    let mut b = build("Pcg64", 4);
+    table.fill(&mut a, &mut out);
+    for &x in &out {
+        assert_eq!(x, table.sample(&mut b));
+    }
+}
+
+#[test]
+fn choice_with_a_table() {
+    let mut rng = build("Pcg64", 5);
+    let elements = ["a", "b", "c"];
+    let table = AliasTable::new(&[0.0, 1.0, 0.0]).unwrap();
+    assert_eq!(rng.choice_table(&elements, &table).unwrap(), &"b");
+    assert_eq!(rng.choice_idx_table(&table), 1);
+    assert!(matches!(
+        rng.choice_table(&elements[..2], &table),
+        Err(MRndErr::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn fenwick_index_follows_updates() {
+    let mut rng = build("ChaCha20Rng", 6);
+    let mut weights = vec![1.0, 2.0, 0.0, 4.0, 8.0, 0.5, 3.0];
+    let mut index = FenwickWeightedIndex::new(&weights).unwrap();
+    assert_eq!(index.total(), weights.iter().sum::<f64>());
+    assert_fits("fenwick", &weights, 200_000, || {
+        index.sample(&mut rng).unwrap()
+    });
+
+    for (i, w) in [(4, 0.0), (2, 6.0), (0, 0.25), (6, 0.0)] {
+        index.update(i, w).unwrap();
+        weights[i] = w;
+        assert_eq!(index.weight(i), Some(w));
+    }
+    assert!((index.total() - weights.iter().sum::<f64>()).abs() < 1e-12);
+    assert_fits("fenwick updated", &weights, 200_000, || {
+        index.sample(&mut rng).unwrap()
+    });
+
+    // many updates, including dropping a dominant weight, keep the sums accurate
+    let mut index = FenwickWeightedIndex::new(&[0.0; 100]).unwrap();
+    let mut w = [0.0; 100];
+    for step in 0..10_000 {
+        let i = rng.gen_range_usize(0..100).unwrap();
+        let x = if step % 97 == 0 { 1e15 } else { rng.next_f64() };
+        index.update(i, x).unwrap();
+        w[i] = x;
+        if x > 1.0 {
+            index.update(i, 0.0).unwrap();
+            w[i] = 0.0;
+        }
+    }
+    let total = w.iter().sum::<f64>();
+    assert!(
+        (index.total() - total).abs() < 1e-9 * total,
+        "{}",
+        index.total()
+    );
+    assert_fits("fenwick churn", &w, 200_000, || {
+        index.sample(&mut rng).unwrap()
+    });
+}
+
+#[test]
+fn fenwick_all_zero_is_an_error() {
+    let mut rng = build("Pcg64", 7);
+    let mut index = FenwickWeightedIndex::new(&[0.0, 0.0]).unwrap();
+    assert!(matches!(
+        index.sample(&mut rng),
+        Err(MRndErr::InvalidArgument(_))
+    ));
+    index.update(1, 0.1).unwrap();
+    assert_eq!(index.sample(&mut rng).unwrap(), 1);
+    index.update(1, 0.0).unwrap();
+    assert!(index.sample(&mut rng).is_err());
+    assert!(index.update(2, 1.0).is_err());
+    assert!(index.update(0, -1.0).is_err());
+}
+
+#[test]
+fn invalid_weights_are_rejected() {
+    let bad: [&[f64]; 6] = [
+        &[],
+        &[0.0, 0.0],
+        &[1.0, -0.5],
+        &[1.0, f64::NAN],
+        &[f64::INFINITY],
+        &[f64::MAX, f64::MAX],
+    ];
+    for w in bad {
+        assert!(
+            matches!(AliasTable::new(w), Err(MRndErr::InvalidArgument(_))),
+            "{w:?}"
+        );
+    }
+    assert!(AliasTable::from_int_weights(&[]).is_err());
+    assert!(AliasTable::from_int_weights(&[0, 0]).is_err());
+    assert!(AliasTable::from_int_weights(&[u64::MAX, 1]).is_err());
+    assert!(FenwickWeightedIndex::new(&[]).is_err());
+    assert!(FenwickWeightedIndex::new(&[1.0, f64::NAN]).is_err());
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use common::{Fake, chain};
+use marnd::{CpuRng, DevURandom, EntropyErr, EntropySource, GetRandom, HealthTests, MRndErr};
+
+#[test]
+fn health_cutoffs_match_sp800_90b() {
+    // SP 800-90B table 2 (W = 512, alpha = 2^-20) and the example of 4.4.1
+    for (h, apt) in [(0.5, 410), (1.0, 311), (2.0, 177), (4.0, 62), (8.0, 13)] {
+        assert_eq!(HealthTests::with_alpha(h, 20.0).unwrap().apt_cutoff(), apt);
+    }
+    assert_eq!(HealthTests::with_alpha(2.0, 20.0).unwrap().rct_cutoff(), 11);
+
+    let full = HealthTests::new(8.0).unwrap();
+    assert_eq!((full.rct_cutoff(), full.apt_cutoff()), (6, 19));
+    // 40 / 1e-9 runs would overflow the u32 cutoff
+    assert!(HealthTests::new(1e-8).unwrap().rct_cutoff() >= 4_000_000_000);
+    for h in [0.0, -1.0, 8.5, f64::NAN, 1e-9] {
+        assert!(matches!(
+            HealthTests::new(h),
+            Err(MRndErr::InvalidArgument(_))
+        ));
+    }
+    assert!(HealthTests::with_alpha(8.0, 0.5).is_err());
+}
+
+#[test]
+fn health_tests_catch_stuck_and_biased_output() {
+    let mut t = HealthTests::new(8.0).unwrap();
+    assert!(t.check("t", &[7; 5]).is_ok());
+    // runs continue across calls
+    assert!(matches!(
+        t.check("t", &[7]),
+        Err(EntropyErr::RepetitionCount {
+            run: 6,
+            cutoff: 6,
+            ..
+        })
+    ));
+    assert!(t.check("t", &[7; 5]).is_ok());
+
+    let mut t = HealthTests::new(8.0).unwrap();
+    let alternating: Vec<u8> = (0..100).map(|i| (i % 2) as u8).collect();
+    assert!(matches!(
+        t.check("t", &alternating),
+        Err(EntropyErr::AdaptiveProportion { count: 19, .. })
+    ));
+
+    let mut t = HealthTests::new(8.0).unwrap();
+    let mut bytes = vec![0u8; 1 << 20];
+    GetRandom.fill_bytes(&mut bytes).unwrap();
+    assert!(t.check("getrandom", &bytes).is_ok());
+}
+
+#[test]
+fn chain_falls_back_past_unavailable_sources() {
+    let mut c = chain(vec![Fake::Missing, Fake::Counter(0), Fake::Stuck(0)]);
+    let mut out = [0u8; 10];
+    c.fill_bytes(&mut out).unwrap();
+    // 1024 startup samples went through the health tests first
+    assert_eq!(out[0], (1024 % 251) as u8);
+    assert_eq!(c.active_source(), Some("counter"));
+    c.fill_bytes(&mut out).unwrap();
+    assert_eq!(out[0], (1034 % 251) as u8);
+
+    // a failing source stops the chain
+    let mut c = chain(vec![Fake::Broken, Fake::Counter(0)]);
+    let err = c.fill_bytes(&mut out).unwrap_err();
+    assert!(matches!(
+        err,
+        MRndErr::Entropy(EntropyErr::Failed { name: "broken", .. })
+    ));
+    let mut c = chain(vec![Fake::Stuck(0xff), Fake::Counter(0)]);
+    assert!(matches!(
+        c.fill_bytes(&mut out),
+        Err(MRndErr::Entropy(EntropyErr::RepetitionCount {
+            name: "stuck",
+            ..
+        }))
+    ));
+
+    let mut c = chain(vec![Fake::Missing, Fake::Missing]);
+    for _ in 0..2 {
+        match c.fill_bytes(&mut out) {
+            Err(MRndErr::Entropy(EntropyErr::NoSource { tried })) => assert_eq!(tried.len(), 2),
+            other => panic!("expected NoSource, got {other:?}"),
+        }
+    }
+    assert_eq!(c.active_source(), None);
+}
+
+#[test]
+fn os_sources_produce_healthy_output() {
+    let mut sources: Vec<Box<dyn EntropySource>> =
+        vec![Box::new(GetRandom), Box::new(DevURandom::new())];
+    if CpuRng::is_available() {
+        sources.push(Box::new(CpuRng));
+    } else {
+        assert!(matches!(
+            CpuRng.fill_bytes(&mut [0; 8]),
+            Err(EntropyErr::Unavailable { .. })
+        ));
+    }
+    for mut s in sources {
+        let (mut a, mut b) = ([0u8; 4099], [0u8; 4099]);
+        s.fill_bytes(&mut a).unwrap();
+        s.fill_bytes(&mut b).unwrap();
+        assert_ne!(a, b, "{}", s.name());
+        let mut t = HealthTests::new(8.0).unwrap();
+        assert!(t.check(s.name(), &a).is_ok(), "{}", s.name());
+    }
+}
@@ -0,0 +1,44 @@
+// The process-wide `MOSEntropy` chain, in its own test binary so that the chains installed
+// here never seed generators that other tests build in parallel.
+mod common;
+
+use common::{Fake, chain};
+use marnd::{EntropyChain, EntropyErr, MOSEntropy, MPCfg, MPRng, MRndErr};
+
+/// Reinstalls the OS chain when dropped, also when an assertion fails.
+struct RestoreOsChain;
+
+impl Drop for RestoreOsChain {
+    fn drop(&mut self) {
+        MOSEntropy::install(EntropyChain::os_default());
+    }
+}
+
+#[test]
+fn os_seeding_uses_the_installed_chain() {
+    let _restore = RestoreOsChain;
+    let mut cfg = MPCfg::new();
+    cfg.insert("schema", "Pcg64");
+    assert_ne!(
+        MPRng::build(&cfg).unwrap().next_u64(),
+        MPRng::build(&cfg).unwrap().next_u64()
+    );
+
+    MOSEntropy::install(chain(vec![Fake::Missing, Fake::Stuck(0xff)]));
+    assert!(matches!(
+        MPRng::build(&cfg),
+        Err(MRndErr::Entropy(EntropyErr::RepetitionCount { .. }))
+    ));
+    let mut cfg_seeded = cfg.clone();
+    cfg_seeded.insert("seed", "1");
+    assert!(MPRng::build(&cfg_seeded).is_ok());
+
+    MOSEntropy::install(chain(vec![Fake::Missing]));
+    assert!(matches!(
+        MOSEntropy::seed256(),
+        Err(MRndErr::Entropy(EntropyErr::NoSource { .. }))
+    ));
+
+    MOSEntropy::install(EntropyChain::os_default());
+    assert!(MOSEntropy::next_u64().is_ok());
+}
@@ -33,7 +33,7 @@ fn build_unknown_engine_returns_unknown_engine() {
 
     let err = MPRng::build(&cfg).expect_err("build should fail");
     match err {
-        MRndErr::UnknownEngine(_) => {}
+        MRndErr::UnknownEngine { .. } => {}
         other => panic!("unexpected error: {other:?}"),
     }
 }
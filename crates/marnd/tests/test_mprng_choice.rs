@@ -1,4 +1,4 @@
-use marnd::{MPCfg, MPRng};
+use marnd::{MPCfg, MPRng, MRndErr};
 
 fn seeded_lcg64(seed: u64) -> MPRng {
     let mut cfg = MPCfg::new();
@@ -12,7 +12,7 @@ fn seeded_lcg64(seed: u64) -> MPRng {
 #[test]
 fn choice_idx_empty_probs_returns_zero() {
     let mut rng = seeded_lcg64(1);
-    assert_eq!(rng.choice_idx(&[]), 0);
+    assert_eq!(rng.choice_idx(&[]).unwrap(), 0);
 }
 
 #[test]
@@ -20,7 +20,7 @@ fn choice_idx_always_first_for_one_hot_first() {
     let mut rng = seeded_lcg64(1);
     let probs = [1.0, 0.0, 0.0];
     for _ in 0..8 {
-        assert_eq!(rng.choice_idx(&probs), 0);
+        assert_eq!(rng.choice_idx(&probs).unwrap(), 0);
     }
 }
 
@@ -29,7 +29,7 @@ fn choice_idx_always_last_for_one_hot_last() {
     let mut rng = seeded_lcg64(1);
     let probs = [0.0, 0.0, 1.0];
     for _ in 0..8 {
-        assert_eq!(rng.choice_idx(&probs), 2);
+        assert_eq!(rng.choice_idx(&probs).unwrap(), 2);
     }
 }
 
@@ -38,32 +38,36 @@ fn choice_returns_expected_element_for_one_hot_middle() {
     let mut rng = seeded_lcg64(1);
     let elements = ["a", "b", "c"];
     let probs = [0.0, 1.0, 0.0];
-    assert_eq!(rng.choice(&elements, &probs), &"b");
+    assert_eq!(rng.choice(&elements, &probs).unwrap(), &"b");
+}
+
+fn assert_invalid<T: std::fmt::Debug>(r: Result<T, MRndErr>, expected: &str) {
+    match r {
+        Err(e @ MRndErr::InvalidArgument(_)) => {
+            assert!(e.to_string().contains(expected), "{e}")
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
 }
 
 #[test]
-#[should_panic(expected = "must have same length")]
-fn choice_panics_on_len_mismatch() {
+fn choice_errs_on_len_mismatch() {
     let mut rng = seeded_lcg64(1);
     let elements = ["a", "b"];
     let probs = [0.5, 0.25, 0.25];
-    let _ = rng.choice(&elements, &probs);
+    assert_invalid(rng.choice(&elements, &probs), "must have same length");
 }
 
-#[cfg(debug_assertions)]
 #[test]
-#[should_panic(expected = "Invalid probability at index 1")]
-fn choice_idx_panics_on_negative_prob_in_debug() {
+fn choice_idx_errs_on_negative_prob() {
     let mut rng = seeded_lcg64(1);
     let probs = [0.5, -0.5, 1.0];
-    let _ = rng.choice_idx(&probs);
+    assert_invalid(rng.choice_idx(&probs), "Invalid probability at index 1");
 }
 
-#[cfg(debug_assertions)]
 #[test]
-#[should_panic(expected = "sum of probabilities must be 1.0")]
-fn choice_idx_panics_on_bad_sum_in_debug() {
+fn choice_idx_errs_on_bad_sum() {
     let mut rng = seeded_lcg64(1);
     let probs = [0.2, 0.2, 0.2];
-    let _ = rng.choice_idx(&probs);
+    assert_invalid(rng.choice_idx(&probs), "sum of probabilities must be 1.0");
 }
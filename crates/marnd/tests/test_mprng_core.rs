@@ -57,6 +57,12 @@ fn next_f32_in_half_open_unit_interval() {
         assert!(x >= 0.0);
         assert!(x < 1.0);
     }
+
+    let (mut a, mut b) = (build_lcg64(5, 7, 9), build_lcg64(5, 7, 9));
+    for _ in 0..256 {
+        let top = (b.next_u64() >> 40) as f32;
+        assert_eq!(a.next_f32(), top / (1u32 << 24) as f32);
+    }
 }
 
 #[test]
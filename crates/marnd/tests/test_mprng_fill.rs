@@ -0,0 +1,87 @@
+mod common;
+
+use common::build;
+use marnd::MPRng;
+
+const SCHEMAS: [&str; 6] = [
+    "Lcg64::DK",
+    "Pcg64",
+    "Xoshiro256PlusPlus",
+    "ChaCha8Rng",
+    "Mt19937",
+    "Philox4x64",
+];
+
+const LENGTHS: [usize; 7] = [0, 1, 7, 511, 512, 513, 2000];
+
+/// Bulk and single calls from two generators with the same seed, then the same next word.
+fn check<T: PartialEq + std::fmt::Debug + Default + Clone>(
+    bulk: impl Fn(&mut MPRng, &mut [T]),
+    single: impl Fn(&mut MPRng) -> T,
+) {
+    for schema in SCHEMAS {
+        for n in LENGTHS {
+            let (mut a, mut b) = (build(schema, 3), build(schema, 3));
+            let mut out = vec![T::default(); n];
+            bulk(&mut a, &mut out);
+            let expected: Vec<T> = (0..n).map(|_| single(&mut b)).collect();
+            assert_eq!(out, expected, "{schema} n = {n}");
+            assert_eq!(
+                a.next_u64(),
+                b.next_u64(),
+                "{schema} n = {n}: state differs"
+            );
+        }
+    }
+}
+
+#[test]
+fn fill_u64_matches_next_u64() {
+    check(|r, out: &mut [u64]| r.fill_u64(out), |r| r.next_u64());
+}
+
+#[test]
+fn fill_floats_match_next_floats() {
+    check(|r, out: &mut [f64]| r.fill_f64(out), |r| r.next_f64());
+    check(|r, out: &mut [f32]| r.fill_f32(out), |r| r.next_f32());
+
+    let mut rng = build("Lcg64::DK", 11);
+    let mut xs = vec![0f32; 100_000];
+    rng.fill_f32(&mut xs);
+    assert!(xs.iter().all(|x| (0.0..1.0).contains(x)));
+}
+
+#[test]
+fn fill_normal_matches_standard_normal() {
+    check(
+        |r, out: &mut [f64]| r.fill_normal(out),
+        |r| r.standard_normal(),
+    );
+
+    // enough draws to go through the wedges and the tail
+    let (mut a, mut b) = (build("Pcg64", 8), build("Pcg64", 8));
+    let mut out = vec![0.0; 200_000];
+    a.fill_normal(&mut out);
+    assert!(out.iter().any(|x| x.abs() > 3.7));
+    assert!(out.iter().all(|&x| x == b.standard_normal()));
+    assert_eq!(a.next_u64(), b.next_u64());
+}
+
+#[test]
+fn fill_range_matches_gen_range_with_rejections() {
+    // spans just above a power of two reject almost half of the draws
+    check(
+        |r, out: &mut [u64]| r.fill_range_u64(0..=(1 << 63), out).unwrap(),
+        |r| r.gen_range_u64(0..=(1 << 63)).unwrap(),
+    );
+    check(
+        |r, out: &mut [u32]| r.fill_range_u32(5..(1 << 31) + 7, out).unwrap(),
+        |r| r.gen_range_u32(5..(1 << 31) + 7).unwrap(),
+    );
+    check(
+        |r, out: &mut [i64]| r.fill_range_i64(-3..=3, out).unwrap(),
+        |r| r.gen_range_i64(-3..=3).unwrap(),
+    );
+    let mut rng = build("Pcg64", 1);
+    assert!(rng.fill_range_usize(4..4, &mut [0; 3]).is_err());
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::build;
+use marnd::{Lcg64, MBitGen, MPRng, MRndErr, Mt19937, Pcg64, SplitMix64, Xoshiro256PlusPlus};
+
+/// A mix of draws that use one or several words each.
+fn draws<G: MBitGen>(rng: &mut MPRng<G>) -> Vec<f64> {
+    let mut out = vec![rng.next_u64() as f64, rng.next_f64(), rng.next_f32() as f64];
+    out.extend((0..1000).map(|_| rng.standard_normal()));
+    out.extend((0..100).map(|_| rng.gen_range_u64(0..=(1 << 63)).unwrap() as f64));
+    out.push(rng.norm_box_muller());
+    out.push(rng.norm_box_muller());
+    out.push(rng.gamma(0.5, 2.0).unwrap());
+    let mut xs: Vec<u32> = (0..50).collect();
+    rng.shuffle(&mut xs);
+    out.extend(xs.iter().map(|&x| x as f64));
+    let mut words = [0u64; 700];
+    rng.fill_u64(&mut words);
+    out.extend(words.iter().map(|&x| x as f64));
+    out
+}
+
+#[test]
+fn static_engine_matches_build() {
+    let lcg = Lcg64::from_preset("DK", 5).unwrap();
+    assert_eq!(
+        draws(&mut MPRng::new(lcg, "Lcg64")),
+        draws(&mut build("Lcg64::DK", 5))
+    );
+    assert_eq!(
+        draws(&mut MPRng::new(
+            Xoshiro256PlusPlus::new(5),
+            "Xoshiro256PlusPlus"
+        )),
+        draws(&mut build("Xoshiro256PlusPlus", 5))
+    );
+    assert_eq!(
+        draws(&mut MPRng::new(SplitMix64::new(5), "SplitMix64")),
+        draws(&mut build("SplitMix64", 5))
+    );
+}
+
+#[test]
+fn into_dyn_keeps_the_stream_and_state() {
+    let mut rng = MPRng::new(Pcg64::from_seed(9, 3), "Pcg64");
+    rng.norm_box_muller();
+    let mut a = rng.into_dyn();
+    let mut b = build("Pcg64", 0);
+    b.set_state(&a.get_state().unwrap()).unwrap();
+    assert_eq!(a.norm_box_muller(), b.norm_box_muller());
+    assert_eq!(draws(&mut a), draws(&mut b));
+    assert_eq!(a.to_string(), b.to_string());
+}
+
+#[test]
+fn boxed_engine_forwards_every_method() {
+    let mut boxed: Box<dyn MBitGen> = Box::new(Xoshiro256PlusPlus::new(1));
+    let mut plain = Xoshiro256PlusPlus::new(1);
+    boxed.jump().unwrap();
+    MBitGen::jump(&mut plain).unwrap();
+    assert_eq!(boxed.get_state().unwrap(), plain.get_state().unwrap());
+    boxed.reseed(4);
+    plain.reseed(4);
+    assert_eq!(boxed.next_u64(), plain.next_u64());
+
+    // the boxed engine's own error, not the default of the Box impl
+    let mut rng = MPRng::new(Box::new(Mt19937::new(1)) as Box<dyn MBitGen>, "Mt19937");
+    match rng.jump() {
+        Err(MRndErr::Unsupported(msg)) => assert!(msg.contains("Mt19937"), "{msg}"),
+        other => panic!("expected Unsupported, got {other:?}"),
+    }
+}
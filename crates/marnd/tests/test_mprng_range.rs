@@ -0,0 +1,187 @@
+mod common;
+
+use common::{build, chi2_999};
+use marnd::MRndErr;
+
+/// Pearson's statistic of `counts` against equal expected counts.
+fn chi_square(counts: &[u64]) -> f64 {
+    let n: u64 = counts.iter().sum();
+    let expected = n as f64 / counts.len() as f64;
+    counts
+        .iter()
+        .map(|&c| (c as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+#[test]
+fn values_stay_in_range() {
+    let mut rng = build("Pcg64", 1);
+    for _ in 0..2000 {
+        let x = rng.gen_range_u64(10..13).unwrap();
+        assert!((10..13).contains(&x));
+        let y = rng.gen_range_i64(-3..=3).unwrap();
+        assert!((-3..=3).contains(&y));
+        let z = rng.gen_range_u32(u32::MAX - 1..).unwrap();
+        assert!(z >= u32::MAX - 1);
+        let i = rng.gen_range_usize(..5).unwrap();
+        assert!(i < 5);
+    }
+    assert_eq!(rng.gen_range_u64(7..8).unwrap(), 7);
+    assert_eq!(rng.gen_range_i64(i64::MIN..=i64::MIN).unwrap(), i64::MIN);
+    assert_eq!(rng.gen_range_u64(u64::MAX..=u64::MAX).unwrap(), u64::MAX);
+}
+
+#[test]
+fn inclusive_ranges_reach_both_ends() {
+    let mut rng = build("Xoshiro256PlusPlus", 2);
+    let mut seen = [false; 5];
+    for _ in 0..500 {
+        seen[(rng.gen_range_i64(-2..=2).unwrap() + 2) as usize] = true;
+    }
+    assert_eq!(seen, [true; 5]);
+
+    let (mut lo, mut hi) = (false, false);
+    for _ in 0..200 {
+        match rng.gen_range_u64(u64::MAX - 1..=u64::MAX).unwrap() {
+            x if x == u64::MAX => hi = true,
+            _ => lo = true,
+        }
+    }
+    assert!(lo && hi);
+}
+
+#[test]
+fn full_ranges_are_raw_draws() {
+    let mut a = build("SplitMix64", 3);
+    let mut b = build("SplitMix64", 3);
+    assert_eq!(a.gen_range_u64(..).unwrap(), b.next_u64());
+    assert_eq!(
+        a.gen_range_i64(i64::MIN..=i64::MAX).unwrap(),
+        (b.next_u64() ^ (1 << 63)) as i64
+    );
+    assert_eq!(a.gen_range_u64(0..=u64::MAX).unwrap(), b.next_u64());
+}
+
+#[test]
+fn follows_lemire_multiply_shift() {
+    // spans above 2^32 take the high word of draw * span, rejecting low words below 2^64 mod span
+    let s = (1u64 << 63) + 1;
+    let t = s.wrapping_neg() % s;
+    let mut rng = build("Pcg64", 4);
+    let mut raw = build("Pcg64", 4);
+    for _ in 0..1000 {
+        let expected = loop {
+            let m = raw.next_u64() as u128 * s as u128;
+            if m as u64 >= t {
+                break (m >> 64) as u64;
+            }
+        };
+        assert_eq!(rng.gen_range_u64(0..s).unwrap(), expected);
+    }
+
+    // smaller spans use 32-bit draws
+    let mut rng = build("Pcg64", 5);
+    let mut raw = build("Pcg64", 5);
+    for _ in 0..1000 {
+        let expected = loop {
+            let m = raw.next_u32() as u64 * 10;
+            if m as u32 >= (10u32.wrapping_neg() % 10) {
+                break (m >> 32) as u32;
+            }
+        };
+        assert_eq!(rng.gen_range_u32(100..110).unwrap(), 100 + expected);
+    }
+}
+
+#[test]
+fn no_bias_for_large_awkward_bounds() {
+    // 2^63 + 1 rejects almost half of the draws; every bin must still get its share
+    let n = (1u64 << 63) + 1;
+    let mut rng = build("Pcg64", 6);
+    let mut bins = [0u64; 16];
+    for _ in 0..200_000 {
+        let x = rng.gen_range_u64(0..n).unwrap();
+        bins[(x as u128 * 16 / n as u128) as usize] += 1;
+    }
+    assert!(chi_square(&bins) < chi2_999(15), "{bins:?}");
+
+    // about 2^64 * 2/3: `next_u64() % n` puts 2/3 of the mass below n / 2
+    let n = 0xaaaa_aaaa_aaaa_aaab_u64;
+    let (mut lemire, mut modulo) = ([0u64; 2], [0u64; 2]);
+    for _ in 0..200_000 {
+        lemire[(rng.gen_range_u64(0..n).unwrap() >= n / 2) as usize] += 1;
+        modulo[(rng.next_u64() % n >= n / 2) as usize] += 1;
+    }
+    assert!(chi_square(&lemire) < chi2_999(1), "{lemire:?}");
+    assert!(chi_square(&modulo) > 1000.0, "{modulo:?}");
+}
+
+#[test]
+fn small_awkward_bounds_are_uniform() {
+    let mut rng = build("Lcg64::DK", 7);
+    for n in [3u64, 7, 10, 16] {
+        let mut counts = vec![0u64; n as usize];
+        for _ in 0..70_000 {
+            counts[rng.gen_range_u64(0..n).unwrap() as usize] += 1;
+        }
+        assert!(
+            chi_square(&counts) < chi2_999(n as usize - 1),
+            "n {n}: {counts:?}"
+        );
+    }
+
+    // 3 * 2^62 takes the 64-bit path
+    let n = 3u64 << 62;
+    let mut counts = [0u64; 3];
+    for _ in 0..60_000 {
+        counts[(rng.gen_range_u64(0..n).unwrap() >> 62) as usize] += 1;
+    }
+    assert!(chi_square(&counts) < chi2_999(2), "{counts:?}");
+}
+
+#[test]
+fn fill_range_matches_repeated_draws() {
+    let mut a = build("ChaCha20Rng", 8);
+    let mut b = build("ChaCha20Rng", 8);
+    let mut buf = [0u64; 100];
+    a.fill_range_u64(0..(1 << 63) + 1, &mut buf).unwrap();
+    for &x in &buf {
+        assert_eq!(x, b.gen_range_u64(0..(1 << 63) + 1).unwrap());
+    }
+
+    let mut buf = [0i64; 100];
+    a.fill_range_i64(-50..=50, &mut buf).unwrap();
+    for &x in &buf {
+        assert_eq!(x, b.gen_range_i64(-50..=50).unwrap());
+    }
+
+    let mut buf = [0u32; 33];
+    a.fill_range_u32(.., &mut buf).unwrap();
+    for &x in &buf {
+        assert_eq!(x, b.gen_range_u32(..).unwrap());
+    }
+
+    let mut buf = [0usize; 33];
+    a.fill_range_usize(3..9, &mut buf).unwrap();
+    for &x in &buf {
+        assert_eq!(x, b.gen_range_usize(3..9).unwrap());
+    }
+    assert_eq!(a.next_u64(), b.next_u64());
+}
+
+#[test]
+fn empty_ranges_are_errors() {
+    let mut rng = build("Pcg64", 9);
+    #[allow(clippy::reversed_empty_ranges)]
+    let results = [
+        rng.gen_range_u64(5..5).map(|_| ()),
+        rng.gen_range_u64(6..5).map(|_| ()),
+        rng.gen_range_i64(0..i64::MIN).map(|_| ()),
+        rng.gen_range_u32(0..0).map(|_| ()),
+        rng.gen_range_usize(..0).map(|_| ()),
+        rng.fill_range_u64(1..1, &mut [0; 4]),
+    ];
+    for r in results {
+        assert!(matches!(r, Err(MRndErr::InvalidArgument(_))), "{r:?}");
+    }
+}
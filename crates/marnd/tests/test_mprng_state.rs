@@ -0,0 +1,164 @@
+mod common;
+
+use common::build;
+use marnd::{BitGenState, MPRNG_STATE_VERSION, MPRng, MPRngState, MRndErr};
+
+const SCHEMAS: &[&str] = &[
+    "Lcg64::DK",
+    "Pcg64",
+    "Pcg64Dxsm",
+    "SplitMix64",
+    "Xoshiro256StarStar",
+    "Xoshiro256PlusPlus",
+    "Xoroshiro128Plus",
+    "Xoshiro256StarStarX4",
+    "Xoshiro256PlusPlusX4",
+    "Xoroshiro128PlusX4",
+    "ChaCha8Rng",
+    "ChaCha12Rng",
+    "ChaCha20Rng",
+    "Mt19937",
+    "Mt19937::NumpyLegacy",
+    "Mt19937_64",
+    "Philox4x64",
+    "Threefry4x64",
+];
+
+fn take(rng: &mut MPRng, n: usize) -> Vec<u64> {
+    (0..n).map(|_| rng.next_u64()).collect()
+}
+
+#[test]
+fn every_engine_resumes_from_its_state() {
+    for schema in SCHEMAS {
+        let mut rng = build(schema, 42);
+        // leave the engines mid-buffer / mid-block
+        take(&mut rng, 3);
+        let state = rng.get_state().expect("get_state should be supported");
+        assert_eq!(state.version, MPRNG_STATE_VERSION);
+        assert_eq!(state.gen_name, rng.gen_name);
+
+        let mut restored = MPRng::from_state(&state).unwrap();
+        assert_eq!(take(&mut restored, 700), take(&mut rng, 700), "{schema}");
+        assert_eq!(restored.next_f64(), rng.next_f64(), "{schema}");
+    }
+}
+
+#[test]
+fn bytes_and_json_round_trip() {
+    for schema in SCHEMAS {
+        let mut rng = build(schema, 7);
+        take(&mut rng, 5);
+        let state = rng.get_state().unwrap();
+
+        let from_bytes = MPRngState::from_bytes(&state.to_bytes()).unwrap();
+        assert_eq!(from_bytes, state, "{schema}");
+        let from_json = MPRngState::from_json(&state.to_json()).unwrap();
+        assert_eq!(from_json, state, "{schema}");
+
+        let mut a = MPRng::from_state(&from_bytes).unwrap();
+        let mut b = MPRng::from_state(&from_json).unwrap();
+        let expected = take(&mut rng, 10);
+        assert_eq!(take(&mut a, 10), expected, "{schema}");
+        assert_eq!(take(&mut b, 10), expected, "{schema}");
+    }
+}
+
+#[test]
+fn spare_norm_is_part_of_the_state() {
+    let mut rng = build("Pcg64", 3);
+    rng.norm_box_muller();
+    assert!(rng.spare_norm.is_some());
+    let state = rng.get_state().unwrap();
+    assert_eq!(state.spare_norm, rng.spare_norm);
+
+    let mut restored = build("SplitMix64", 0);
+    restored.set_state(&state).unwrap();
+    assert_eq!(restored.gen_name, "Pcg64");
+    assert_eq!(restored.norm_box_muller(), rng.norm_box_muller());
+    assert_eq!(restored.norm_box_muller(), rng.norm_box_muller());
+
+    let bytes = MPRngState::from_bytes(&state.to_bytes()).unwrap();
+    assert_eq!(
+        bytes.spare_norm.map(f64::to_bits),
+        state.spare_norm.map(f64::to_bits)
+    );
+
+    // the shortest decimal of a double must parse back to the same bits
+    let mut state = state;
+    for bits in [
+        0x3ffd_c62c_1747_5586_u64,
+        0x3fb9_9999_9999_999a,
+        0x7fef_ffff_ffff_ffff,
+        1,
+    ] {
+        state.spare_norm = Some(f64::from_bits(bits));
+        let json = MPRngState::from_json(&state.to_json()).unwrap();
+        assert_eq!(json.spare_norm.map(f64::to_bits), Some(bits));
+    }
+}
+
+#[test]
+fn json_is_engine_tagged() {
+    let state = build("Lcg64::DK", 1).get_state().unwrap();
+    let json = state.to_json();
+    assert!(json.contains(r#""bitgen":{"Lcg64":{"#), "{json}");
+    assert!(json.contains(r#""preset":"DK""#), "{json}");
+    assert!(json.contains(r#""version":1"#), "{json}");
+}
+
+#[test]
+fn rejects_other_versions_and_garbage() {
+    let mut state = build("SplitMix64", 1).get_state().unwrap();
+    state.version = MPRNG_STATE_VERSION + 1;
+    assert!(matches!(
+        MPRngState::from_bytes(&state.to_bytes()),
+        Err(MRndErr::ParseErr(_))
+    ));
+    assert!(matches!(
+        MPRngState::from_json(&state.to_json()),
+        Err(MRndErr::ParseErr(_))
+    ));
+    assert!(MPRng::from_state(&state).is_err());
+
+    state.version = MPRNG_STATE_VERSION;
+    let bytes = state.to_bytes();
+    assert!(MPRngState::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(MPRngState::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+    assert!(MPRngState::from_bytes(b"not a state").is_err());
+    assert!(MPRngState::from_json("{}").is_err());
+}
+
+#[test]
+fn rejects_invalid_engine_states() {
+    let invalid = [
+        BitGenState::Xoshiro256PlusPlus { s: [0; 4] },
+        BitGenState::Mt19937 {
+            mt: vec![1; 10],
+            mti: 0,
+            legacy_doubles: false,
+        },
+        BitGenState::Mt19937_64 {
+            mt: vec![1; 312],
+            mti: 313,
+        },
+        BitGenState::ChaCha {
+            rounds: 7,
+            key: [0; 32],
+            stream: 0,
+            word_pos: 0,
+        },
+        BitGenState::Philox4x64 {
+            ctr: [0; 4],
+            key: [0; 2],
+            buffer: [0; 4],
+            buffer_pos: 5,
+        },
+    ];
+    for bitgen in invalid {
+        assert!(
+            matches!(bitgen.to_bitgen(), Err(MRndErr::InvalidArgument(_))),
+            "{bitgen:?}"
+        );
+    }
+}
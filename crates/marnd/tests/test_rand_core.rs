@@ -0,0 +1,165 @@
+#![cfg(feature = "rand_core")]
+
+mod common;
+
+use common::build;
+use marnd::{
+    ChaCha8Rng, ChaCha12Rng, Lcg64, MBitGen, MPRng, MRndErr, Mt19937, Mt19937_64, Pcg64, Pcg64Dxsm,
+    Philox4x64, RandCoreBitGen, SplitMix64, Threefry4x64, Xoroshiro128Plus, Xoroshiro128PlusX4,
+    Xoshiro256PlusPlus, Xoshiro256PlusPlusX4, Xoshiro256StarStar, Xoshiro256StarStarX4,
+};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rand_core::{RngCore, SeedableRng};
+
+type Words = fn(u64) -> Vec<u64>;
+
+fn first_words<R: RngCore + SeedableRng>(seed: u64) -> Vec<u64> {
+    let mut rng = R::seed_from_u64(seed);
+    (0..8).map(|_| rng.next_u64()).collect()
+}
+
+#[test]
+fn seed_from_u64_matches_build() {
+    let cases: [(&str, Words); 16] = [
+        ("Lcg64::DK", first_words::<Lcg64>),
+        ("Pcg64", first_words::<Pcg64>),
+        ("Pcg64Dxsm", first_words::<Pcg64Dxsm>),
+        ("SplitMix64", first_words::<SplitMix64>),
+        ("Xoshiro256StarStar", first_words::<Xoshiro256StarStar>),
+        ("Xoshiro256PlusPlus", first_words::<Xoshiro256PlusPlus>),
+        ("Xoroshiro128Plus", first_words::<Xoroshiro128Plus>),
+        ("Xoshiro256StarStarX4", first_words::<Xoshiro256StarStarX4>),
+        ("Xoshiro256PlusPlusX4", first_words::<Xoshiro256PlusPlusX4>),
+        ("Xoroshiro128PlusX4", first_words::<Xoroshiro128PlusX4>),
+        ("ChaCha8Rng", first_words::<ChaCha8Rng>),
+        ("ChaCha12Rng", first_words::<ChaCha12Rng>),
+        ("Mt19937", first_words::<Mt19937>),
+        ("Mt19937_64", first_words::<Mt19937_64>),
+        ("Philox4x64", first_words::<Philox4x64>),
+        ("Threefry4x64", first_words::<Threefry4x64>),
+    ];
+    for (schema, words) in cases {
+        for seed in [0, 42, u64::MAX] {
+            let mut rng = build(schema, seed);
+            let expected: Vec<u64> = (0..8).map(|_| rng.next_u64()).collect();
+            assert_eq!(words(seed), expected, "{schema} seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn chacha_matches_rand_chacha_through_rng_core() {
+    let seed: [u8; 32] = core::array::from_fn(|i| (7 * i + 3) as u8);
+    let mut ours = ChaCha8Rng::from_seed(seed);
+    let mut theirs = rand_chacha::ChaCha8Rng::from_seed(seed);
+
+    let (mut a, mut b) = ([0u8; 37], [0u8; 37]);
+    RngCore::fill_bytes(&mut ours, &mut a);
+    theirs.fill_bytes(&mut b);
+    assert_eq!(a, b);
+    for _ in 0..50 {
+        assert_eq!(RngCore::next_u32(&mut ours), theirs.next_u32());
+        assert_eq!(RngCore::next_u64(&mut ours), theirs.next_u64());
+    }
+}
+
+#[test]
+fn next_u32_and_fill_bytes_follow_next_u64() {
+    let mut a = Xoshiro256PlusPlus::seed_from_u64(5);
+    let mut b = a.clone();
+    assert_eq!(
+        RngCore::next_u32(&mut a),
+        (MBitGen::next_u64(&mut b) >> 32) as u32
+    );
+
+    let mut bytes = [0u8; 20];
+    RngCore::fill_bytes(&mut a, &mut bytes);
+    assert_eq!(bytes[..8], MBitGen::next_u64(&mut b).to_le_bytes());
+    assert_eq!(bytes[8..16], MBitGen::next_u64(&mut b).to_le_bytes());
+    assert_eq!(
+        bytes[16..],
+        ((MBitGen::next_u64(&mut b) >> 32) as u32).to_le_bytes()
+    );
+
+    // Mt19937 keeps its native words
+    let mut mt = Mt19937::from_seed(5489u32.to_le_bytes());
+    assert_eq!(RngCore::next_u32(&mut mt), 3_499_211_612);
+
+    // MPRng fills bytes exactly like MPRng::fill
+    let (mut r1, mut r2) = (build("Pcg64", 9), build("Pcg64", 9));
+    let (mut x, mut y) = ([0u8; 29], [0u8; 29]);
+    RngCore::fill_bytes(&mut r1, &mut x);
+    r2.fill(&mut y);
+    assert_eq!(x, y);
+}
+
+#[test]
+fn xoshiro_zero_seed_falls_back_to_seed_from_u64() {
+    let mut a = Xoshiro256StarStar::from_seed([0; 32]);
+    let mut b = Xoshiro256StarStar::seed_from_u64(0);
+    assert_eq!(RngCore::next_u64(&mut a), RngCore::next_u64(&mut b));
+    let mut c = Xoroshiro128Plus::from_seed([0; 16]);
+    assert_ne!(RngCore::next_u64(&mut c), 0);
+}
+
+#[test]
+fn rand_api_on_mprng() {
+    let mut rng = build("Lcg64::DK", 42);
+    let mut again = build("Lcg64::DK", 42);
+
+    let xs: Vec<u32> = (0..1000).map(|_| rng.gen_range(10..20)).collect();
+    assert!(xs.iter().all(|x| (10..20).contains(x)));
+    assert!((10..20).all(|v| xs.contains(&v)));
+    let ys: Vec<u32> = (0..1000).map(|_| again.gen_range(10..20)).collect();
+    assert_eq!(xs, ys);
+
+    let u: f64 = rng.r#gen();
+    assert!((0.0..1.0).contains(&u));
+
+    let mut v: Vec<u32> = (0..50).collect();
+    v.shuffle(&mut rng);
+    assert_ne!(v, (0..50).collect::<Vec<_>>());
+    v.sort_unstable();
+    assert_eq!(v, (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn rand_core_bitgen_drives_mprng() {
+    let inner = rand_chacha::ChaCha12Rng::seed_from_u64(17);
+    let mut rng = MPRng::new(
+        Box::new(RandCoreBitGen::new(inner)),
+        "rand_chacha::ChaCha12Rng",
+    );
+    let mut ours = build("ChaCha12Rng", 17);
+    for _ in 0..100 {
+        assert_eq!(rng.next_u64(), ours.next_u64());
+    }
+
+    // reseed goes through SeedableRng::seed_from_u64
+    rng.seed(3);
+    let mut ours = build("ChaCha12Rng", 3);
+    assert_eq!(rng.next_u64(), ours.next_u64());
+    let x = rng.next_f64();
+    assert!((0.0..1.0).contains(&x));
+
+    let bitgen = RandCoreBitGen::new(rand_chacha::ChaCha8Rng::seed_from_u64(1));
+    assert!(bitgen.to_string().contains("ChaCha8Rng"));
+    assert!(matches!(bitgen.get_state(), Err(MRndErr::Unsupported(_))));
+    let mut back = bitgen.into_inner();
+    assert_eq!(
+        back.next_u64(),
+        rand_chacha::ChaCha8Rng::seed_from_u64(1).next_u64()
+    );
+}
+
+#[test]
+fn rand_core_bitgen_wraps_unseeded_sources() {
+    let mut rng = MPRng::new(RandCoreBitGen::unseeded(rand::rngs::OsRng), "OsRng");
+    assert_ne!(rng.next_u64(), rng.next_u64());
+    // reseed keeps the OS source
+    rng.seed(3);
+    let mut again = MPRng::new(RandCoreBitGen::unseeded(rand::rngs::OsRng), "OsRng");
+    again.seed(3);
+    assert_ne!(rng.next_u64(), again.next_u64());
+}
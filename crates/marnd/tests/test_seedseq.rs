@@ -0,0 +1,134 @@
+mod common;
+
+use common::try_build_cfg;
+use marnd::{MBitGen, MPRng, MRndErr, Pcg64, Philox4x64, SeedSequence};
+
+#[test]
+fn generate_state_matches_numpy() {
+    // numpy.random.SeedSequence(0).generate_state(4)
+    let seq = SeedSequence::new(&[0]);
+    assert_eq!(
+        seq.generate_state(4),
+        [0xb0f4_78be, 0xdb2c_d7e7, 0x2c71_ba49, 0xabf4_641a]
+    );
+    // generate_state(2, np.uint64) is the same words, pairwise little-endian
+    assert_eq!(
+        seq.generate_state_u64(2),
+        [0xdb2c_d7e7_b0f4_78be, 0xabf4_641a_2c71_ba49]
+    );
+
+    // entropy longer than the pool; 2^40 contributes two words
+    let seq = SeedSequence::new(&[1, 1 << 40, 3]);
+    assert_eq!(
+        seq.generate_state(5),
+        [3277810977, 1471572032, 1650839300, 283948039, 3208283188]
+    );
+
+    let seq = SeedSequence::from_u128((1 << 100) + 7, &[]);
+    assert_eq!(
+        seq.generate_state_u64(2),
+        [33175750552016506, 12324894697821659760]
+    );
+}
+
+#[test]
+fn default_rng_values_match_numpy() {
+    // numpy.random.default_rng(seed).random(), i.e. PCG64(SeedSequence(seed))
+    for (seed, expected) in [
+        (0, 0.6369616873214543),
+        (42, 0.7739560485559633),
+        (12345, 0.22733602246716966),
+    ] {
+        let mut rng = try_build_cfg("Pcg64", &[("seed", &format!("[{seed}]"))]).unwrap();
+        assert_eq!(rng.next_f64(), expected, "seed {seed}");
+
+        let mut pcg = Pcg64::from_seed_seq(&SeedSequence::new(&[seed]));
+        assert_eq!(((pcg.next_u64() >> 11) as f64) * 2f64.powi(-53), expected);
+    }
+}
+
+#[test]
+fn spawn_extends_the_spawn_key() {
+    let mut root = SeedSequence::new(&[42]);
+    let children = root.spawn(2);
+    assert_eq!(root.n_children_spawned(), 2);
+    assert_eq!(children[0], SeedSequence::with_spawn_key(&[42], &[0]));
+    assert_eq!(children[1].spawn_key(), [1]);
+    assert_eq!(
+        children[0].generate_state(4),
+        [2684470948, 3757501821, 1691896351, 1126406280]
+    );
+
+    // later calls continue the numbering, grandchildren extend the key
+    let mut third = root.spawn(1).remove(0);
+    assert_eq!(third.spawn_key(), [2]);
+    let grandchild = third.spawn(1).remove(0);
+    assert_eq!(grandchild.spawn_key(), [2, 0]);
+    assert_eq!(grandchild.entropy(), root.entropy());
+    assert_eq!(
+        SeedSequence::with_spawn_key(&[42], &[1, 2]).generate_state(4),
+        [4007147622, 3772432517, 3162052391, 3078089812]
+    );
+
+    // the zero padding keeps a spawned child apart from a longer seed
+    assert_ne!(
+        SeedSequence::with_spawn_key(&[42], &[0]).generate_state(4),
+        SeedSequence::new(&[42, 0]).generate_state(4)
+    );
+}
+
+#[test]
+fn build_with_seed_list_and_spawn_key() {
+    let seq = SeedSequence::with_spawn_key(&[7, 8], &[3]);
+    let mut rng = try_build_cfg("Philox4x64", &[("seed", "[7, 8]"), ("spawn_key", "3")]).unwrap();
+    let mut philox = Philox4x64::from_seed_seq(&seq);
+    assert_eq!(rng.next_u64(), philox.next_u64());
+
+    // the list syntax is forgiving, a single seed with a spawn_key uses the sequence too
+    let a = try_build_cfg("Pcg64", &[("seed", "7,0x8")])
+        .unwrap()
+        .next_u64();
+    let b = try_build_cfg("Pcg64", &[("seed", "(7, 8)")])
+        .unwrap()
+        .next_u64();
+    assert_eq!(a, b);
+    let c = try_build_cfg("Lcg64::DK", &[("seed", "5"), ("spawn_key", "[0]")]).unwrap();
+    let d = try_build_cfg("Lcg64::DK", &[("seed", "5"), ("spawn_key", "[1]")]).unwrap();
+    assert_ne!(c.get_state().unwrap(), d.get_state().unwrap());
+
+    // every engine accepts a seed sequence, and sibling streams differ
+    for schema in MPRng::list_engines() {
+        let schema = if schema == "Lcg64" {
+            "Lcg64::SV"
+        } else {
+            schema
+        };
+        let mut s0 = try_build_cfg(schema, &[("seed", "[1]"), ("spawn_key", "0")]).unwrap();
+        let mut s1 = try_build_cfg(schema, &[("seed", "[1]"), ("spawn_key", "1")]).unwrap();
+        assert_ne!(s0.next_u64(), s1.next_u64(), "{schema}");
+    }
+}
+
+#[test]
+fn spawn_key_without_seed_uses_os_entropy() {
+    let mut a = try_build_cfg("Pcg64", &[("spawn_key", "0")]).unwrap();
+    let mut b = try_build_cfg("Pcg64", &[("spawn_key", "0")]).unwrap();
+    assert_ne!(a.next_u64(), b.next_u64());
+    assert!(SeedSequence::from_entropy().unwrap().entropy().len() <= 4);
+}
+
+#[test]
+fn bad_seed_sequence_configs() {
+    assert!(matches!(
+        try_build_cfg("Pcg64", &[("seed", "[1, 2]"), ("stream", "3")]),
+        Err(MRndErr::BadCfg(_))
+    ));
+    assert!(matches!(
+        try_build_cfg("Pcg64", &[("seed", "[1, x]")]),
+        Err(MRndErr::ParseErr(_))
+    ));
+    assert!(matches!(
+        try_build_cfg("Pcg64", &[("seed", "1"), ("spawn_key", "-1")]),
+        Err(MRndErr::ParseErr(_))
+    ));
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use common::{build, chi2_999};
+use marnd::MRndErr;
+use std::collections::HashMap;
+
+/// Pearson chi-square of observed counts against expected counts.
+fn assert_chi2(name: &str, observed: &[u64], expected: &[f64]) {
+    let stat: f64 = observed
+        .iter()
+        .zip(expected)
+        .map(|(&o, &e)| (o as f64 - e).powi(2) / e)
+        .sum();
+    let df = observed.len() - 1;
+    assert!(stat < chi2_999(df), "{name}: chi2 = {stat}, df = {df}");
+}
+
+fn is_permutation(xs: &[usize], n: usize) -> bool {
+    let mut seen = vec![false; n];
+    xs.len() == n
+        && xs
+            .iter()
+            .all(|&x| x < n && !std::mem::replace(&mut seen[x], true))
+}
+
+#[test]
+fn shuffle_is_uniform_over_permutations() {
+    let mut rng = build("Pcg64", 1);
+    let trials = 120_000;
+    let mut counts: HashMap<[u8; 4], u64> = HashMap::new();
+    for _ in 0..trials {
+        let mut xs = [0u8, 1, 2, 3];
+        rng.shuffle(&mut xs);
+        *counts.entry(xs).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 24);
+    let observed: Vec<u64> = counts.values().copied().collect();
+    assert_chi2("shuffle", &observed, &[trials as f64 / 24.0; 24]);
+
+    // trivial slices are fine
+    rng.shuffle::<u8>(&mut []);
+    let mut one = ["x"];
+    rng.shuffle(&mut one);
+    assert_eq!(one, ["x"]);
+}
+
+#[test]
+fn permutation_is_a_permutation() {
+    let mut rng = build("Xoshiro256PlusPlus", 2);
+    for n in [0, 1, 2, 10, 1000] {
+        assert!(is_permutation(&rng.permutation(n), n), "{n}");
+    }
+    // every value visits every position equally often
+    let n = 8;
+    let trials = 40_000;
+    let mut at = vec![0u64; n * n];
+    for _ in 0..trials {
+        for (pos, &x) in rng.permutation(n).iter().enumerate() {
+            at[pos * n + x] += 1;
+        }
+    }
+    for pos in 0..n {
+        assert_chi2(
+            "permutation",
+            &at[pos * n..(pos + 1) * n],
+            &vec![trials as f64 / n as f64; n],
+        );
+    }
+}
+
+#[test]
+fn sample_indices_is_uniform() {
+    let mut rng = build("ChaCha20Rng", 3);
+    // k / n small uses Floyd's algorithm, large a partial shuffle
+    for (n, k) in [(50, 3), (1000, 10), (10, 7), (12, 12)] {
+        let trials = 30_000;
+        let mut hits = vec![0u64; n];
+        let mut first = vec![0u64; n];
+        for _ in 0..trials {
+            let xs = rng.sample_indices(n, k).unwrap();
+            assert_eq!(xs.len(), k);
+            let mut sorted = xs.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), k, "duplicates in {xs:?}");
+            for &x in &xs {
+                hits[x] += 1;
+            }
+            first[xs[0]] += 1;
+        }
+        let name = format!("sample_indices({n}, {k})");
+        if k < n {
+            assert_chi2(&name, &hits, &vec![(trials * k) as f64 / n as f64; n]);
+        }
+        // the order is random too
+        assert_chi2(&name, &first, &vec![trials as f64 / n as f64; n]);
+    }
+
+    assert!(rng.sample_indices(5, 0).unwrap().is_empty());
+    assert!(rng.sample_indices(0, 0).unwrap().is_empty());
+    assert!(matches!(
+        rng.sample_indices(3, 4),
+        Err(MRndErr::InvalidArgument(_))
+    ));
+    let xs = rng.sample_indices(usize::MAX, 3).unwrap();
+    assert_eq!(xs.len(), 3);
+}
+
+#[test]
+fn sample_weighted_follows_successive_draws() {
+    let mut rng = build("Pcg64", 4);
+    let weights = [1.0, 2.0, 0.0, 3.0, 4.0];
+    let total = 10.0;
+    let trials = 100_000;
+    // ordered pairs: P(i, j) = w_i / W * w_j / (W - w_i)
+    let mut counts: HashMap<(usize, usize), u64> = HashMap::new();
+    for _ in 0..trials {
+        let xs = rng.sample_weighted(&weights, 2).unwrap();
+        *counts.entry((xs[0], xs[1])).or_default() += 1;
+    }
+    let mut observed = Vec::new();
+    let mut expected = Vec::new();
+    for i in [0, 1, 3, 4] {
+        for j in [0, 1, 3, 4] {
+            if i != j {
+                observed.push(counts.remove(&(i, j)).unwrap_or(0));
+                expected
+                    .push(trials as f64 * weights[i] / total * weights[j] / (total - weights[i]));
+            }
+        }
+    }
+    assert!(
+        counts.is_empty(),
+        "zero weight or repeated index: {counts:?}"
+    );
+    assert_chi2("sample_weighted", &observed, &expected);
+
+    // all positive weights: a permutation of them
+    let xs = rng.sample_weighted(&weights, 4).unwrap();
+    let mut sorted = xs.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, [0, 1, 3, 4]);
+    assert!(rng.sample_weighted(&weights, 0).unwrap().is_empty());
+}
+
+#[test]
+fn sample_weighted_rejects_bad_input() {
+    let mut rng = build("Pcg64", 5);
+    for (w, k) in [
+        (&[1.0, 0.0, 2.0][..], 3),
+        (&[1.0, -1.0], 1),
+        (&[f64::NAN], 1),
+        (&[f64::INFINITY, 1.0], 1),
+        (&[], 1),
+    ] {
+        assert!(
+            matches!(rng.sample_weighted(w, k), Err(MRndErr::InvalidArgument(_))),
+            "{w:?} {k}"
+        );
+    }
+}
+
+#[test]
+fn reservoir_sample_is_uniform() {
+    let mut rng = build("Lcg64::DK", 6);
+    for (n, k) in [(20, 5), (200, 3), (1000, 1)] {
+        let trials = 30_000;
+        let mut hits = vec![0u64; n];
+        let mut first = vec![0u64; n];
+        for _ in 0..trials {
+            let xs = rng.reservoir_sample(0..n, k);
+            assert_eq!(xs.len(), k);
+            for &x in &xs {
+                hits[x] += 1;
+            }
+            first[xs[0]] += 1;
+        }
+        let name = format!("reservoir_sample({n}, {k})");
+        assert_chi2(&name, &hits, &vec![(trials * k) as f64 / n as f64; n]);
+        assert_chi2(&name, &first, &vec![trials as f64 / n as f64; n]);
+    }
+
+    // short streams give everything back, in random order
+    let mut xs = rng.reservoir_sample("abc".chars(), 5);
+    xs.sort_unstable();
+    assert_eq!(xs, ['a', 'b', 'c']);
+    assert!(rng.reservoir_sample(0..10, 0).is_empty());
+
+    // one pass over a long iterator, consuming all of it
+    let mut consumed = 0u64;
+    let xs = rng.reservoir_sample((0..1_000_000u64).inspect(|_| consumed += 1), 10);
+    assert_eq!(xs.len(), 10);
+    assert_eq!(consumed, 1_000_000);
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use common::build;
+use marnd::stattest::{
+    BitStream, battery, chi_square, chi_square_p_value, erfc, kolmogorov_q, ks_uniform, monobit,
+    poker, runs,
+};
+use marnd::{MBitGen, MRndErr};
+use std::fmt;
+
+/// Replays fixed words, then zeros.
+#[derive(Debug)]
+struct Replay {
+    words: Vec<u64>,
+    pos: usize,
+}
+
+impl MBitGen for Replay {
+    fn next_u64(&mut self) -> u64 {
+        self.pos += 1;
+        self.words.get(self.pos - 1).copied().unwrap_or(0)
+    }
+
+    fn reseed(&mut self, _seed: u64) {
+        self.pos = 0;
+    }
+}
+
+impl fmt::Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Replay")
+    }
+}
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+#[test]
+fn distribution_functions() {
+    assert!(close(erfc(1.0), 0.157_299_207_050_285_13, 1e-14));
+    assert!(close(erfc(-0.5), 1.520_499_877_813_046_5, 1e-14));
+    assert!(close(erfc(6.0) / 2.151_973_671_249_891_3e-17, 1.0, 1e-10));
+    assert!(close(
+        chi_square_p_value(3.841_458_820_694_124, 1.0),
+        0.05,
+        1e-12
+    ));
+    assert!(close(
+        chi_square_p_value(18.307_038_053_275_146, 10.0),
+        0.05,
+        1e-12
+    ));
+    assert!(close(kolmogorov_q(1.0), 0.269_999_671_677_155_3, 1e-12));
+    assert_eq!(kolmogorov_q(0.1), 1.0);
+}
+
+#[test]
+fn toolbox_checks_arguments() {
+    let r = chi_square(&[10, 20, 30], &[20.0, 20.0, 20.0]).unwrap();
+    assert_eq!(r.statistic, 10.0);
+    assert!(close(r.p_value, (-5.0f64).exp(), 1e-14));
+    assert!(matches!(
+        chi_square(&[1, 2], &[1.0]),
+        Err(MRndErr::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        chi_square(&[1, 2], &[1.0, 0.0]),
+        Err(MRndErr::InvalidArgument(_))
+    ));
+
+    let grid: Vec<f64> = (0..1000).map(|i| (i as f64 + 0.5) / 1000.0).collect();
+    let r = ks_uniform(&grid).unwrap();
+    assert!(close(r.statistic, 0.0005, 1e-15) && r.p_value == 1.0);
+    let squeezed: Vec<f64> = grid.iter().map(|x| x * x).collect();
+    assert!(ks_uniform(&squeezed).unwrap().p_value < 1e-30);
+    assert!(ks_uniform(&[0.5, 1.5]).is_err());
+}
+
+#[test]
+fn nist_examples() {
+    // SP 800-22 2.1.8 and 2.3.8: the first 100 bits of the binary expansion of pi.
+    let pi = || Replay {
+        words: vec![0xc90f_daa2_2168_c234, 0xc4c6_628b_80dc_1cd1],
+        pos: 0,
+    };
+    let mut g = pi();
+    let r = monobit(&mut BitStream::new(&mut g), 100).unwrap();
+    assert!(close(r.p_value, 0.109_599, 1e-6), "{r}");
+    let mut g = pi();
+    let r = runs(&mut BitStream::new(&mut g), 100).unwrap();
+    assert!(close(r.p_value, 0.500_798, 1e-6), "{r}");
+}
+
+#[test]
+fn bit_stream_slices_outputs() {
+    let mut g = Replay {
+        words: vec![0xa000_0000_0000_0005, 0x3000_0000_0000_000c],
+        pos: 0,
+    };
+    let mut top = BitStream::with_slice(&mut g, 60, 4).unwrap();
+    assert_eq!(top.next_bits(8), 0xa3);
+    g.reseed(0);
+    let mut low = BitStream::with_slice(&mut g, 0, 4).unwrap();
+    assert_eq!(low.next_bits(3), 0b010);
+    assert_eq!(low.next_bits(5), 0b1_1100);
+    assert!(BitStream::with_slice(&mut g, 60, 8).is_err());
+    assert!(BitStream::with_slice(&mut g, 0, 0).is_err());
+    assert!(BitStream::with_slice(&mut g, u32::MAX, 1).is_err());
+
+    let mut g = build("Pcg64", 5);
+    let mut bits = BitStream::new(g.bitgen.as_mut());
+    assert!(poker(&mut bits, 8, 100).is_err());
+}
+
+#[test]
+fn good_generators_pass_battery() {
+    for (schema, seed) in [
+        ("Pcg64", 1),
+        ("Xoshiro256PlusPlus", 7),
+        ("ChaCha8Rng", 3),
+        ("Philox4x64", 11),
+        ("Lcg64::DK", 42),
+    ] {
+        let mut rng = build(schema, seed);
+        let results = battery(&mut BitStream::new(rng.bitgen.as_mut()), 1).unwrap();
+        assert_eq!(results.len(), 9);
+        for r in results {
+            assert!(r.passed(1e-3), "{schema}: {r}");
+        }
+    }
+}
+
+#[test]
+fn lcg64_low_bits_fail_battery() {
+    // the lowest bit of a power-of-two LCG alternates, bit k has period 2^(k+1)
+    let mut rng = build("Lcg64::DK", 42);
+    let results = battery(
+        &mut BitStream::with_slice(rng.bitgen.as_mut(), 0, 1).unwrap(),
+        1,
+    );
+    assert!(results.unwrap().iter().all(|r| !r.passed(1e-3)));
+
+    let mut rng = build("Lcg64::SV", 42);
+    let results = battery(
+        &mut BitStream::with_slice(rng.bitgen.as_mut(), 0, 16).unwrap(),
+        1,
+    );
+    let failed: Vec<&str> = results
+        .unwrap()
+        .iter()
+        .filter(|r| r.p_value < 1e-10)
+        .map(|r| r.name)
+        .collect();
+    for name in ["birthday_spacings", "gap", "matrix_rank"] {
+        assert!(failed.contains(&name), "{name} not in {failed:?}");
+    }
+}
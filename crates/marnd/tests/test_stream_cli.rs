@@ -0,0 +1,68 @@
+mod common;
+
+use common::build;
+use marnd::{MPCfg, MPRng};
+use std::io::Read;
+use std::process::{Command, Stdio};
+
+const STREAM: &str = env!("CARGO_BIN_EXE_marnd-stream");
+
+#[test]
+fn writes_le_words_up_to_the_byte_limit() {
+    let out = Command::new(STREAM)
+        .args(["--schema", "Lcg64::DK", "--seed", "42", "--bytes", "1M"])
+        .output()
+        .unwrap();
+    assert!(out.status.success());
+    let mut rng = build("Lcg64::DK", 42);
+    let expected: Vec<u8> = (0..1 << 17)
+        .flat_map(|_| rng.next_u64().to_le_bytes())
+        .collect();
+    assert_eq!(out.stdout, expected);
+
+    // a partial word at the end, and engine options passed through to MPCfg
+    let out = Command::new(STREAM)
+        .args([
+            "--schema", "Pcg64", "--seed", "1", "--stream", "3", "--bytes", "13",
+        ])
+        .output()
+        .unwrap();
+    let mut cfg = MPCfg::new();
+    cfg.insert("schema", "Pcg64");
+    cfg.insert("seed", "1");
+    cfg.insert("stream", "3");
+    let mut rng = MPRng::build(&cfg).unwrap();
+    let mut expected = [0u8; 13];
+    rng.fill(&mut expected);
+    assert_eq!(out.stdout, expected);
+}
+
+#[test]
+fn stops_cleanly_when_the_reader_closes() {
+    let mut child = Command::new(STREAM)
+        .args(["--schema", "Xoshiro256PlusPlus", "--seed", "7"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut head = vec![0u8; 3 << 20];
+    child.stdout.take().unwrap().read_exact(&mut head).unwrap();
+    // stdout is dropped here, the next write fails with a broken pipe
+    assert!(child.wait().unwrap().success());
+
+    let mut rng = build("Xoshiro256PlusPlus", 7);
+    assert_eq!(head[..8], rng.next_u64().to_le_bytes());
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    for args in [
+        &["--seed", "1"][..],
+        &["--schema", "Pcg64", "--bytes", "12X"],
+        &["--schema", "Pcg64", "--seed"],
+        &["--schema", "NoSuchEngine", "--seed", "1"],
+    ] {
+        let out = Command::new(STREAM).args(args).output().unwrap();
+        assert_eq!(out.status.code(), Some(2), "{args:?}");
+        assert!(out.stdout.is_empty());
+    }
+}
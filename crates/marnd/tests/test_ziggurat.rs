@@ -0,0 +1,130 @@
+mod common;
+
+use common::{build, normal_cdf};
+use marnd::{ZIGGURAT_EXP_R, ZIGGURAT_NOR_R};
+
+fn exponential_cdf(x: f64) -> f64 {
+    if x <= 0.0 { 0.0 } else { -(-x).exp_m1() }
+}
+
+/// Kolmogorov-Smirnov statistic of `xs` against `cdf`.
+fn ks_statistic(mut xs: Vec<f64>, cdf: impl Fn(f64) -> f64) -> f64 {
+    xs.sort_by(f64::total_cmp);
+    let n = xs.len() as f64;
+    xs.iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0.0, f64::max)
+}
+
+const N: usize = 200_000;
+
+/// KS critical value at the 0.1% level.
+fn ks_critical(n: usize) -> f64 {
+    1.95 / (n as f64).sqrt()
+}
+
+#[test]
+fn standard_normal_passes_ks() {
+    for schema in ["Pcg64", "Xoshiro256PlusPlus", "Lcg64::DK"] {
+        let mut rng = build(schema, 11);
+        let xs: Vec<f64> = (0..N).map(|_| rng.standard_normal()).collect();
+        let d = ks_statistic(xs, normal_cdf);
+        assert!(d < ks_critical(N), "{schema}: D = {d}");
+
+        let xs: Vec<f64> = (0..N).map(|_| rng.standard_normal_f32() as f64).collect();
+        let d = ks_statistic(xs, normal_cdf);
+        assert!(d < ks_critical(N), "{schema} f32: D = {d}");
+    }
+}
+
+#[test]
+fn standard_exponential_passes_ks() {
+    for schema in ["Pcg64", "Xoshiro256PlusPlus", "Lcg64::DK"] {
+        let mut rng = build(schema, 12);
+        let xs: Vec<f64> = (0..N).map(|_| rng.standard_exponential()).collect();
+        assert!(xs.iter().all(|&x| x >= 0.0));
+        let d = ks_statistic(xs, exponential_cdf);
+        assert!(d < ks_critical(N), "{schema}: D = {d}");
+
+        let xs: Vec<f64> = (0..N)
+            .map(|_| rng.standard_exponential_f32() as f64)
+            .collect();
+        let d = ks_statistic(xs, exponential_cdf);
+        assert!(d < ks_critical(N), "{schema} f32: D = {d}");
+    }
+}
+
+#[test]
+fn ks_detects_a_wrong_distribution() {
+    let mut rng = build("Pcg64", 13);
+    let xs: Vec<f64> = (0..N).map(|_| 1.1 * rng.standard_normal()).collect();
+    assert!(ks_statistic(xs, normal_cdf) > ks_critical(N));
+    let xs: Vec<f64> = (0..N).map(|_| rng.standard_normal().abs()).collect();
+    assert!(ks_statistic(xs, exponential_cdf) > ks_critical(N));
+}
+
+#[test]
+fn tails_have_the_right_mass() {
+    // the tail beyond r comes from a separate sampler: check its mass and reach
+    let n = 2_000_000;
+    let mut rng = build("Pcg64", 14);
+    let (mut count, mut max) = (0u64, 0.0f64);
+    for _ in 0..n {
+        let x = rng.standard_normal().abs();
+        if x > ZIGGURAT_NOR_R {
+            count += 1;
+        }
+        max = max.max(x);
+    }
+    let expected = n as f64 * 2.0 * normal_cdf(-ZIGGURAT_NOR_R);
+    assert!(
+        (count as f64 - expected).abs() < 5.0 * expected.sqrt(),
+        "{count} vs {expected}"
+    );
+    assert!(max > ZIGGURAT_NOR_R + 1.0, "{max}");
+
+    let mut count = 0u64;
+    for _ in 0..n {
+        if rng.standard_exponential() > ZIGGURAT_EXP_R {
+            count += 1;
+        }
+    }
+    let expected = n as f64 * (-ZIGGURAT_EXP_R).exp();
+    assert!(
+        (count as f64 - expected).abs() < 5.0 * expected.sqrt(),
+        "{count} vs {expected}"
+    );
+}
+
+#[test]
+fn moments_and_symmetry() {
+    let mut rng = build("ChaCha8Rng", 15);
+    let xs: Vec<f64> = (0..N).map(|_| rng.standard_normal()).collect();
+    let mean = xs.iter().sum::<f64>() / N as f64;
+    let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / N as f64;
+    let neg = xs.iter().filter(|&&x| x < 0.0).count();
+    assert!(mean.abs() < 0.01, "{mean}");
+    assert!((var - 1.0).abs() < 0.015, "{var}");
+    assert!((neg as f64 / N as f64 - 0.5).abs() < 0.005);
+
+    let ys: Vec<f64> = (0..N).map(|_| rng.standard_exponential()).collect();
+    let mean = ys.iter().sum::<f64>() / N as f64;
+    assert!((mean - 1.0).abs() < 0.01, "{mean}");
+}
+
+#[test]
+fn same_seed_same_samples() {
+    let mut a = build("Pcg64", 16);
+    let mut b = build("Pcg64", 16);
+    for _ in 0..1000 {
+        assert_eq!(a.standard_normal().to_bits(), b.standard_normal().to_bits());
+        assert_eq!(
+            a.standard_exponential_f32().to_bits(),
+            b.standard_exponential_f32().to_bits()
+        );
+    }
+}
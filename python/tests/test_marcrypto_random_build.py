@@ -58,14 +58,20 @@ def test_rng_variants_construct():
     r_dk = marcrypto.random.rng_dk(1)
     r_sv = marcrypto.random.rng_sv(1)
     r_pcg = marcrypto.random.rng_pcg64(1)
+    r_dxsm = marcrypto.random.rng_pcg64dxsm(1)
+    r_chacha = marcrypto.random.rng_chacha20(1)
 
     x_dk = r_dk.next_u64()
     x_sv = r_sv.next_u64()
     x_pcg = r_pcg.next_u64()
+    x_dxsm = r_dxsm.next_u64()
+    x_chacha = r_chacha.next_u64()
 
     print("dk =", x_dk)
     print("sv =", x_sv)
     print("pcg64 =", x_pcg)
+    print("pcg64dxsm =", x_dxsm)
+    print("chacha20 =", x_chacha)
 
     # Usually these presets differ; if your DK and SV are identical presets,
     # this may fail and you can weaken/remove it.
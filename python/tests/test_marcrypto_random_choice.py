@@ -105,6 +105,25 @@ def test_choice_matches_choice_idx():
     assert x == elems[idx], "choice should return elements[choice_idx(p)] for same RNG state"
 
 
+def test_choice_with_alias_table():
+    table = marcrypto.random.AliasTable([1, 0, 3])
+    assert len(table) == 3
+    assert table.probability(2) == 0.75
+
+    rng = marcrypto.random.default_rng(7)
+    elems = ["a", "b", "c"]
+    xs = [rng.choice(elems, table) for _ in range(2000)]
+    assert "b" not in xs
+    assert 1300 < xs.count("c") < 1700
+
+    # the same table serves choice_idx, a float table works the same way
+    assert rng.choice_idx(marcrypto.random.AliasTable([0.0, 2.5])) == 1
+    e = assert_raises(ValueError, rng.choice, ["a", "b"], table)
+    print("expected table length mismatch error:", e)
+    e = assert_raises(ValueError, marcrypto.random.AliasTable, [0.5, -0.5])
+    print("expected negative weight error:", e)
+
+
 def run_all():
     tests = [
         test_choice_idx_basic,
@@ -117,6 +136,7 @@ def run_all():
         test_choice_idx_negative_prob,
         test_choice_elements_not_sequence,
         test_choice_matches_choice_idx,
+        test_choice_with_alias_table,
     ]
 
     ok = 0
@@ -0,0 +1,101 @@
+import array
+import sys
+import traceback
+import types
+
+import marcrypto
+
+
+def assert_raises(exc_type, fn, *args, **kwargs):
+    try:
+        fn(*args, **kwargs)
+    except exc_type as e:
+        return e
+    except Exception as e:
+        raise AssertionError(f"Expected {exc_type.__name__}, got {type(e).__name__}: {e}") from e
+    else:
+        raise AssertionError(f"Expected {exc_type.__name__}, but no exception was raised")
+
+
+def test_fill_matches_single_calls():
+    for n in (0, 1, 513, 2000):
+        a = marcrypto.random.default_rng(5)
+        b = marcrypto.random.default_rng(5)
+        assert list(a.fill_u64(n)) == [b.next_u64() for _ in range(n)]
+        assert list(a.fill_f64(n)) == [b.next_f64() for _ in range(n)]
+        # next_f32 goes through a Python float, which holds an f32 exactly
+        assert list(a.fill_f32(n)) == [b.next_f32() for _ in range(n)]
+        assert a.next_u64() == b.next_u64()
+
+
+def test_fill_range_and_normal():
+    rng = marcrypto.random.default_rng(6)
+    xs = list(rng.fill_range(-3, 4, 5000))
+    assert len(xs) == 5000
+    assert set(xs) == set(range(-3, 4))
+    assert_raises(ValueError, rng.fill_range, 4, 4, 3)
+
+    zs = list(marcrypto.random.default_rng(7).fill_normal(20000))
+    assert zs == list(marcrypto.random.default_rng(7).fill_normal(20000))
+    mean = sum(zs) / len(zs)
+    var = sum((z - mean) ** 2 for z in zs) / len(zs)
+    assert abs(mean) < 0.05 and abs(var - 1.0) < 0.05
+
+
+def test_numpy_arrays():
+    # without numpy the arrays come back as array.array; a stub records what numpy would get
+    calls = []
+
+    def frombuffer(buf, dtype):
+        calls.append(dtype)
+        return array.array({"<u8": "Q", "<f8": "d", "<f4": "f", "<i8": "q"}[dtype], bytes(buf))
+
+    real = sys.modules.get("numpy")
+    if real is None:
+        sys.modules["numpy"] = types.SimpleNamespace(frombuffer=frombuffer)
+    try:
+        rng = marcrypto.random.default_rng(8)
+        arrays = [rng.fill_u64(3), rng.fill_f64(3), rng.fill_f32(3), rng.fill_normal(3), rng.fill_range(0, 9, 3)]
+        if real is None:
+            assert calls == ["<u8", "<f8", "<f4", "<f8", "<i8"]
+        else:
+            assert [str(x.dtype) for x in arrays] == ["uint64", "float64", "float32", "float64", "int64"]
+    finally:
+        if real is None:
+            del sys.modules["numpy"]
+
+    b = marcrypto.random.default_rng(8)
+    assert list(arrays[0]) == [b.next_u64() for _ in range(3)]
+
+
+def test_array_fallback_without_numpy():
+    if "numpy" in sys.modules:
+        return
+    xs = marcrypto.random.default_rng(9).fill_u64(4)
+    assert isinstance(xs, array.array) and xs.typecode == "Q"
+    assert marcrypto.random.default_rng(9).fill_f32(4).typecode == "f"
+
+
+def run_all():
+    tests = [
+        test_fill_matches_single_calls,
+        test_fill_range_and_normal,
+        test_numpy_arrays,
+        test_array_fallback_without_numpy,
+    ]
+
+    ok = 0
+    for t in tests:
+        try:
+            t()
+            ok += 1
+        except Exception:
+            print(f"\nFAILED: {t.__name__}")
+            traceback.print_exc()
+            sys.exit(1)
+
+    print(f"\nAll tests passed ({ok}/{len(tests)}).")
+
+
+if __name__ == "__main__":
+    run_all()
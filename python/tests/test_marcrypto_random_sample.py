@@ -0,0 +1,77 @@
+import sys
+import traceback
+
+import marcrypto
+
+
+def assert_raises(exc_type, fn, *args, **kwargs):
+    try:
+        fn(*args, **kwargs)
+    except exc_type as e:
+        return e
+    except Exception as e:
+        raise AssertionError(f"Expected {exc_type.__name__}, got {type(e).__name__}: {e}") from e
+    else:
+        raise AssertionError(f"Expected {exc_type.__name__}, but no exception was raised")
+
+
+def test_shuffle_in_place():
+    rng = marcrypto.random.default_rng(1)
+    xs = list(range(20))
+    rng.shuffle(xs)
+    assert sorted(xs) == list(range(20))
+    assert xs != list(range(20))
+
+
+def test_permutation_and_sample_indices():
+    rng = marcrypto.random.default_rng(2)
+    assert sorted(rng.permutation(10)) == list(range(10))
+    idx = rng.sample_indices(1000, 5)
+    assert len(set(idx)) == 5 and all(0 <= i < 1000 for i in idx)
+    assert_raises(ValueError, rng.sample_indices, 3, 4)
+
+
+def test_sample_without_replacement():
+    rng = marcrypto.random.default_rng(3)
+    elems = ["a", "b", "c", "d"]
+    xs = rng.sample(elems, 4)
+    assert sorted(xs) == elems
+    for _ in range(100):
+        xs = rng.sample(elems, 2, weights=[0.0, 1.0, 0.0, 3.0])
+        assert sorted(xs) == ["b", "d"]
+    assert_raises(ValueError, rng.sample, elems, 5)
+    assert_raises(ValueError, rng.sample, elems, 1, weights=[1.0])
+
+
+def test_same_seed_same_shuffle():
+    r1 = marcrypto.random.default_rng(42)
+    r2 = marcrypto.random.default_rng(42)
+    a, b = list("abcdefgh"), list("abcdefgh")
+    r1.shuffle(a)
+    r2.shuffle(b)
+    assert a == b
+
+
+def run_all():
+    tests = [
+        test_shuffle_in_place,
+        test_permutation_and_sample_indices,
+        test_sample_without_replacement,
+        test_same_seed_same_shuffle,
+    ]
+
+    ok = 0
+    for t in tests:
+        try:
+            t()
+            ok += 1
+        except Exception:
+            print(f"\nFAILED: {t.__name__}")
+            traceback.print_exc()
+            sys.exit(1)
+
+    print(f"\nAll tests passed ({ok}/{len(tests)}).")
+
+
+if __name__ == "__main__":
+    run_all()
@@ -0,0 +1,90 @@
+# python/tests/test_marcrypto_random_state.py
+import copy
+import pickle
+import sys
+import traceback
+
+import marcrypto
+
+
+def assert_eq(a, b, msg=""):
+    if a != b:
+        raise AssertionError(msg or f"Assertion failed: {a!r} != {b!r}")
+
+
+SCHEMAS = ["Lcg64::DK", "Pcg64", "Xoshiro256PlusPlus", "ChaCha20Rng", "Mt19937", "Philox4x64"]
+
+
+def test_state_dict_round_trip():
+    print("== test_state_dict_round_trip ==")
+    for schema in SCHEMAS:
+        rng = marcrypto.random.PyMPRng(schema, 42)
+        rng.next_u64()
+        state = rng.state
+        print(schema, "->", state["gen_name"], list(state["bitgen"]))
+        assert_eq(state["version"], 1)
+        expected = [rng.next_u64() for _ in range(5)]
+
+        other = marcrypto.random.PyMPRng("SplitMix64", 0)
+        other.state = state
+        assert_eq([other.next_u64() for _ in range(5)], expected, schema)
+
+
+def test_state_keeps_spare_norm():
+    print("== test_state_keeps_spare_norm ==")
+    rng = marcrypto.random.rng_pcg64(3)
+    rng.norm()
+    state = rng.state
+    assert state["spare_norm"] is not None
+    other = marcrypto.random.default_rng(0)
+    other.state = state
+    assert_eq(other.norm(), rng.norm())
+
+
+def test_pickle_and_copy():
+    print("== test_pickle_and_copy ==")
+    for schema in SCHEMAS:
+        rng = marcrypto.random.PyMPRng(schema, 7)
+        rng.norm()
+        restored = pickle.loads(pickle.dumps(rng))
+        cloned = copy.deepcopy(rng)
+        expected = [rng.next_u64() for _ in range(5)]
+        assert_eq([restored.next_u64() for _ in range(5)], expected, schema)
+        assert_eq([cloned.next_u64() for _ in range(5)], expected, schema)
+
+
+def test_bad_state_rejected():
+    print("== test_bad_state_rejected ==")
+    rng = marcrypto.random.default_rng(1)
+    for bad in [{"version": 1}, b"garbage", 42]:
+        try:
+            rng.state = bad
+        except ValueError as e:
+            print("expected error:", e)
+        else:
+            raise AssertionError(f"Expected state={bad!r} to fail")
+
+
+def run_all():
+    tests = [
+        test_state_dict_round_trip,
+        test_state_keeps_spare_norm,
+        test_pickle_and_copy,
+        test_bad_state_rejected,
+    ]
+
+    ok = 0
+    for t in tests:
+        try:
+            t()
+            ok += 1
+        except Exception:
+            print(f"\nFAILED: {t.__name__}")
+            traceback.print_exc()
+            sys.exit(1)
+
+    print(f"\nAll tests passed ({ok}/{len(tests)}).")
+
+
+if __name__ == "__main__":
+    run_all()